@@ -1,32 +1,65 @@
 use futures_util::StreamExt;
 use serde_json::de::Read;
 use lapin::{
+    message::Delivery,
     options::{BasicAckOptions, BasicConsumeOptions, BasicNackOptions, BasicPublishOptions, QueueDeclareOptions},
-    types::FieldTable,
+    types::{AMQPValue, FieldTable, ShortString},
     BasicProperties, Channel, Connection, ConnectionProperties, Consumer,
 };
 use serde::{Deserialize, Serialize};
-use std::{time::Duration};
-use tokio::{task, time};
+use std::{net::IpAddr, sync::Arc, time::Duration};
+use chrono::Utc;
+use tokio::time;
 use tokio_amqp::LapinTokioExt;
 use reqwest::Client;
 use anyhow::{Context, Result};
-use tracing::{info, error};
+use sea_orm::{DatabaseConnection, EntityTrait};
+use tracing::{info, error, warn};
+use crate::api::alert_rule::{evaluate_alert_rules, AlertNotification};
+use crate::api::notification_channel::find_notification_channel;
+use crate::api::notification_delivery::{create_delivery, record_attempt};
+use crate::api::event::{process_event, spike_key};
+use crate::api::usage::IngestLimiter;
+use crate::entity::notification_delivery::{self, DeliveryStatus, Entity as NotificationDeliveryEntity};
+use crate::model::alert_rule::AlertAction;
+use crate::model::event::EventReportRequest;
+use crate::model::global_error::AppError;
+use crate::model::notification_channel::ChannelConfig;
+use crate::notification::{channel_for, Notification, NotificationChannel, SlackChannel};
+use crate::util::geoip::GeoIp;
+use crate::util::sourcemap::SourceMapCache;
+use crate::util::spike::{SpikeConfig, SpikeDetector};
 
+// 이벤트 처리 재시도 횟수 / 재시도 대기 시간
+const EVENT_MAX_RETRIES: i64 = 5;
+const EVENT_RETRY_DELAY_MS: i64 = 10_000;
+const RETRY_COUNT_HEADER: &str = "x-retry-count";
+const ERROR_HEADER: &str = "x-error";
+const CLIENT_IP_HEADER: &str = "x-client-ip";
+
+// 알림 발송 최대 시도 횟수 / 첫 재시도 대기 시간 (재시도할 때마다 두 배로 늘어난다)
+const NOTIFICATION_MAX_ATTEMPTS: i32 = 5;
+const NOTIFICATION_RETRY_BASE_DELAY_MS: i64 = 5_000;
+
+/// 알림 큐 메시지. 알림 내용과 대상은 발송 이력(`notification_deliveries`)에서 읽는다.
 #[derive(Debug, Deserialize, Serialize)]
-pub struct SlackMessage {
-    text: String,
+#[serde(rename_all = "camelCase")]
+pub struct NotificationMessage {
+    pub delivery_id: i32,
 }
 
 pub struct AmqpConfig {
     pub uri: String,
     pub queue_name: String,
+    pub event_queue_name: String,
     pub slack_webhook: String,
 }
 
 pub struct AmqpClient {
     channel: Channel,
-    slack_http: Client,
+    http: Client,
+    spike: SpikeDetector,
+    geoip: GeoIp,
     cfg: AmqpConfig,
 }
 
@@ -42,98 +75,93 @@ impl AmqpClient {
 
         let channel = conn.create_channel().await.context("채널 생성 실패")?;
 
-        // queue
-        channel
-            .queue_declare(
-                &cfg.queue_name,
-                QueueDeclareOptions {
-                    durable: true,
-                    ..Default::default()
-                },
-                FieldTable::default(),
-            )
-            .await
-            .context("큐 선언 실패")?;
-        info!("큐 '{}' 선언됨", &cfg.queue_name);
+        declare_notification_queues(&channel, &cfg.queue_name).await?;
+        info!("알림 큐 '{}' 선언됨", &cfg.queue_name);
 
-        let slack_http = Client::new();
+        declare_event_queues(&channel, &cfg.event_queue_name).await?;
+        info!("이벤트 큐 '{}' 선언됨", &cfg.event_queue_name);
+
+        let http = Client::new();
 
         Ok(Self {
             channel,
-            slack_http,
+            http,
+            spike: SpikeDetector::new(SpikeConfig::from_env()),
+            geoip: GeoIp::from_env(),
             cfg,
         })
     }
 
-    pub async fn publish_slack_message(&self, msg: &SlackMessage) -> Result<()> {
-        let payload = serde_json::to_vec(msg)?;
+    /// 발송 이력을 만들고 알림 큐에 발행한다. 실제 발송은 `start_notification_consumer`가 처리한다.
+    pub async fn enqueue_notification(
+        &self,
+        db: &DatabaseConnection,
+        project_id: i32,
+        channel_id: Option<i32>,
+        notification: &Notification,
+    ) -> Result<()> {
+        let delivery = create_delivery(db, project_id, channel_id, notification).await?;
+        self.publish_notification(delivery.id).await
+    }
+
+    pub async fn publish_notification(&self, delivery_id: i32) -> Result<()> {
+        let payload = serde_json::to_vec(&NotificationMessage { delivery_id })?;
+        self.publish(&self.cfg.queue_name, &payload, FieldTable::default()).await?;
+        info!("알림 발행 완료: delivery {}", delivery_id);
+        Ok(())
+    }
+
+    /// 수집된 이벤트를 이벤트 큐에 발행한다. 그룹핑과 저장은 `start_event_consumer`가 처리한다.
+    pub async fn publish_event(&self, event: &EventReportRequest, client_ip: Option<IpAddr>) -> Result<()> {
+        let payload = serde_json::to_vec(event)?;
+        let mut headers = FieldTable::default();
+        // SDK가 본문에 넣어 위조하지 못하도록 클라이언트 IP는 헤더로 넘긴다.
+        if let Some(ip) = client_ip {
+            headers.insert(ShortString::from(CLIENT_IP_HEADER), AMQPValue::LongString(ip.to_string().into()));
+        }
+        self.publish(&self.cfg.event_queue_name, &payload, headers).await
+    }
+
+    async fn publish(&self, queue: &str, payload: &[u8], headers: FieldTable) -> Result<()> {
         self.channel
             .basic_publish(
                 "",
-                &self.cfg.queue_name,
+                queue,
                 BasicPublishOptions::default(),
-                &payload,
-                BasicProperties::default().with_delivery_mode(2),
+                payload,
+                BasicProperties::default()
+                    .with_delivery_mode(2)
+                    .with_headers(headers),
             )
             .await?
             .await?;
-        info!("메시지 발행 완료: {:?}", msg);
         Ok(())
     }
 
-    pub async fn start_consumer(&self) -> Result<()> {
+    /// 알림 큐 컨슈머. 발송에 실패하면 시도 횟수에 따라 대기 시간이 두 배씩 늘어나는 retry 큐를 거쳐
+    /// 다시 알림 큐로 돌아오고, 최대 시도 횟수를 넘기면 DLQ로 보낸다.
+    pub async fn start_notification_consumer(&self, db: DatabaseConnection) -> Result<()> {
         let mut consumer = self
             .channel
             .basic_consume(
                 &self.cfg.queue_name,
-                "slack_consumer",
+                "notification_consumer",
                 BasicConsumeOptions::default(),
                 FieldTable::default(),
             )
             .await
-            .context("컨슈머 등록 실패")?;
-        info!("Consumer 등록됨, 대기 중...");
+            .context("알림 컨슈머 등록 실패")?;
+        info!("Notification consumer 등록됨, 대기 중...");
 
         while let Some(delivery) = consumer.next().await {
             match delivery {
                 Ok(delivery) => {
-                    let channel = self.channel.clone();
-                    let slack_http = self.slack_http.clone();
-                    let webhook = self.cfg.slack_webhook.clone();
-                    task::spawn(async move {
-                        let data = delivery.data.clone();
-                        let delivery_tag = delivery.delivery_tag;
-
-                        match serde_json::from_slice::<SlackMessage>(&data) {
-                            Ok(msg) => {
-                                if let Err(err) = send_to_slack(&slack_http, &webhook, &msg).await {
-                                    error!("Slack 전송 실패: {:?}", err);
-                                    if let Err(e) = channel
-                                        .basic_nack(delivery_tag, BasicNackOptions { requeue: true, ..Default::default() })
-                                        .await
-                                    {
-                                        error!("Nack 실패: {:?}", e);
-                                    }
-                                } else {
-                                    if let Err(e) = channel
-                                        .basic_ack(delivery_tag, BasicAckOptions::default())
-                                        .await
-                                    {
-                                        error!("Ack 실패: {:?}", e);
-                                    }
-                                }
-                            }
-                            Err(e) => {
-                                error!("메시지 파싱 실패: {:?}", e);
-                                let _ = channel
-                                    .basic_nack(delivery_tag, BasicNackOptions { requeue: false, ..Default::default() })
-                                    .await;
-                            }
-                        }
-                    });
+                    if let Err(e) = self.handle_notification_delivery(&db, delivery).await {
+                        error!("알림 메시지 처리 실패: {:?}", e);
+                    }
                 }
                 Err(e) => {
-                    error!("Consumer 오류: {:?}", e);
+                    error!("Notification consumer 오류: {:?}", e);
                     time::sleep(Duration::from_secs(5)).await;
                 }
             }
@@ -141,38 +169,401 @@ impl AmqpClient {
 
         Ok(())
     }
-}
 
-async fn send_to_slack(client: &Client, webhook: &str, msg: &SlackMessage) -> Result<()> {
-    let res = client
-        .post(webhook)
-        .json(msg)
-        .timeout(Duration::from_secs(5))
-        .send()
-        .await
-        .context("Slack API 요청 실패")?;
+    async fn handle_notification_delivery(&self, db: &DatabaseConnection, delivery: Delivery) -> Result<()> {
+        let routed = self.route_notification(db, &delivery).await;
+        self.ack_or_requeue(&delivery, routed).await
+    }
+
+    // 발송 결과를 기록하고 재시도/DLQ로 보낸다. 조회, 기록, 발행 중 하나라도 실패하면
+    // 에러를 돌려주고, 호출한 쪽에서 메시지를 다시 큐에 넣는다.
+    async fn route_notification(&self, db: &DatabaseConnection, delivery: &Delivery) -> Result<()> {
+        let queue = &self.cfg.queue_name;
+
+        let message = match serde_json::from_slice::<NotificationMessage>(&delivery.data) {
+            Ok(message) => message,
+            Err(e) => {
+                error!("알림 메시지 파싱 실패: {:?}", e);
+                return self.dead_letter(queue, delivery, 0, &e.to_string()).await;
+            }
+        };
+
+        let Some(record) = NotificationDeliveryEntity::find_by_id(message.delivery_id).one(db).await? else {
+            warn!("발송 이력이 없는 알림 메시지 무시: delivery {}", message.delivery_id);
+            return Ok(());
+        };
+        if record.status == DeliveryStatus::Delivered {
+            return Ok(());
+        }
+
+        let attempt = record.attempts + 1;
+        match self.send_notification(db, &record).await {
+            Ok(()) => {
+                record_attempt(db, record, DeliveryStatus::Delivered, None).await?;
+            }
+            Err(SendFailure::Retryable(e)) if attempt < NOTIFICATION_MAX_ATTEMPTS => {
+                warn!("알림 발송 실패, 재시도 예정 ({}/{}): {:?}", attempt, NOTIFICATION_MAX_ATTEMPTS, e);
+                record_attempt(db, record, DeliveryStatus::Retrying, Some(e.to_string())).await?;
+                let mut headers = FieldTable::default();
+                headers.insert(ShortString::from(RETRY_COUNT_HEADER), AMQPValue::LongLongInt(attempt as i64));
+                self.publish(&notification_retry_queue_name(queue, attempt), &delivery.data, headers).await?;
+            }
+            Err(SendFailure::Retryable(e) | SendFailure::Permanent(e)) => {
+                error!("알림 발송 실패, DLQ로 이동 (delivery {}): {:?}", record.id, e);
+                let reason = e.to_string();
+                record_attempt(db, record, DeliveryStatus::Failed, Some(reason.clone())).await?;
+                self.dead_letter(queue, delivery, attempt as i64, &reason).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn send_notification(
+        &self,
+        db: &DatabaseConnection,
+        record: &notification_delivery::Model,
+    ) -> Result<(), SendFailure> {
+        let channel = self.resolve_channel(db, record).await?;
+        let notification = Notification::new(record.title.clone(), record.text.clone());
+        channel.send(&notification).await.map_err(SendFailure::Retryable)
+    }
+
+    // 채널이 삭제/비활성화됐거나 설정이 깨진 경우는 재시도해도 성공하지 않는다.
+    async fn resolve_channel(
+        &self,
+        db: &DatabaseConnection,
+        record: &notification_delivery::Model,
+    ) -> Result<Box<dyn NotificationChannel>, SendFailure> {
+        let Some(channel_id) = record.channel_id else {
+            return Ok(Box::new(SlackChannel::new(self.http.clone(), self.cfg.slack_webhook.clone())));
+        };
+
+        let channel = match find_notification_channel(db, record.project_id, channel_id).await {
+            Ok(channel) => channel,
+            Err(e @ AppError::InternalServerError(_)) => return Err(SendFailure::Retryable(e.into())),
+            Err(e) => return Err(SendFailure::Permanent(e.into())),
+        };
+        if !channel.enabled {
+            return Err(SendFailure::Permanent(anyhow::anyhow!("비활성화된 알림 채널입니다: {}", channel_id)));
+        }
+
+        let config = serde_json::from_value::<ChannelConfig>(channel.config)
+            .map_err(|e| SendFailure::Permanent(e.into()))?;
+        Ok(channel_for(config, self.http.clone()))
+    }
+
+    /// 이벤트 큐 컨슈머. 처리에 실패한 메시지는 retry 큐(TTL)를 거쳐 다시 이벤트 큐로 돌아오고,
+    /// 재시도 횟수를 넘기거나 재시도해도 의미가 없는 메시지는 DLQ로 보낸다.
+    pub async fn start_event_consumer(
+        &self,
+        db: DatabaseConnection,
+        limiter: Arc<IngestLimiter>,
+        source_maps: Arc<SourceMapCache>,
+    ) -> Result<()> {
+        let mut consumer = self
+            .channel
+            .basic_consume(
+                &self.cfg.event_queue_name,
+                "event_consumer",
+                BasicConsumeOptions::default(),
+                FieldTable::default(),
+            )
+            .await
+            .context("이벤트 컨슈머 등록 실패")?;
+        info!("Event consumer 등록됨, 대기 중...");
+
+        while let Some(delivery) = consumer.next().await {
+            match delivery {
+                Ok(delivery) => {
+                    if let Err(e) = self.handle_event_delivery(&db, &limiter, &source_maps, delivery).await {
+                        error!("이벤트 메시지 처리 실패: {:?}", e);
+                    }
+                }
+                Err(e) => {
+                    error!("Event consumer 오류: {:?}", e);
+                    time::sleep(Duration::from_secs(5)).await;
+                }
+            }
+        }
 
-    if res.status().is_success() {
-        info!("Slack 전송 성공: {:?}", msg);
         Ok(())
+    }
+
+    async fn handle_event_delivery(
+        &self,
+        db: &DatabaseConnection,
+        limiter: &IngestLimiter,
+        source_maps: &SourceMapCache,
+        delivery: Delivery,
+    ) -> Result<()> {
+        let retry_count = retry_count(delivery.properties.headers().as_ref());
+
+        let event = match serde_json::from_slice::<EventReportRequest>(&delivery.data) {
+            Ok(event) => event,
+            Err(e) => {
+                error!("이벤트 메시지 파싱 실패: {:?}", e);
+                let routed = self.dead_letter(&self.cfg.event_queue_name, &delivery, retry_count, &e.to_string()).await;
+                return self.ack_or_requeue(&delivery, routed).await;
+            }
+        };
+
+        // 재시도 메시지는 이미 한 번 통과했으므로 다시 세지 않는다.
+        if retry_count == 0 {
+            let decision = self.spike.observe(&event.api_key, &spike_key(&event), Utc::now());
+            if decision.spike_started {
+                warn!("이벤트 스파이크 감지, 이슈별 샘플링 시작 (api key {})", event.api_key);
+            }
+            if !decision.keep {
+                // 수집할 때 받은 것으로 셌으므로 버린 수로 옮긴다.
+                limiter.record_spike_dropped(db, &event.api_key, 1).await;
+                return self.ack(&delivery).await;
+            }
+        }
+
+        let routed = match process_event(db, &event, client_ip(&delivery), &self.geoip, source_maps).await {
+            Ok(processed) => {
+                // 이벤트는 이미 저장됐으므로 알림 평가에 실패해도 이벤트를 재시도하지 않는다.
+                match evaluate_alert_rules(db, &processed).await {
+                    Ok(notifications) => {
+                        for alert in notifications {
+                            self.dispatch_alert(db, processed.event.project_id, alert).await;
+                        }
+                    }
+                    Err(e) => error!("알림 규칙 평가 실패: {}", e),
+                }
+                Ok(())
+            }
+            Err(e) => match failure_route(is_retryable(&e), retry_count) {
+                FailureRoute::Retry { next_count } => {
+                    warn!("이벤트 처리 실패, 재시도 예정 ({}/{}): {}", next_count, EVENT_MAX_RETRIES, e);
+                    let mut headers = forwarded_headers(&delivery);
+                    headers.insert(ShortString::from(RETRY_COUNT_HEADER), AMQPValue::LongLongInt(next_count));
+                    self.publish(&retry_queue_name(&self.cfg.event_queue_name), &delivery.data, headers).await
+                }
+                FailureRoute::DeadLetter => {
+                    error!("이벤트 처리 실패, DLQ로 이동: {}", e);
+                    self.dead_letter(&self.cfg.event_queue_name, &delivery, retry_count, &e.to_string()).await
+                }
+            },
+        };
+
+        self.ack_or_requeue(&delivery, routed).await
+    }
+
+    // 처리 결과를 기록하지 못했거나 retry 큐/DLQ로 옮기지 못했으면 메시지를 잃지 않도록 원래 큐로 되돌린다.
+    // 여기서 `?`로 빠져나가면 ack도 nack도 하지 않은 메시지가 채널에 묶여 컨슈머가 멈춘다.
+    async fn ack_or_requeue(&self, delivery: &Delivery, routed: Result<()>) -> Result<()> {
+        match routed {
+            Ok(()) => self.ack(delivery).await,
+            Err(e) => {
+                error!("메시지를 처리하지 못해 다시 큐에 넣습니다: {:?}", e);
+                self.channel
+                    .basic_nack(delivery.delivery_tag, BasicNackOptions { requeue: true, ..Default::default() })
+                    .await
+                    .context("Nack 실패")
+            }
+        }
+    }
+
+    async fn dispatch_alert(&self, db: &DatabaseConnection, project_id: i32, alert: AlertNotification) {
+        let channel_id = match alert.action {
+            AlertAction::Slack => None,
+            AlertAction::Channel { channel_id } => Some(channel_id),
+        };
+        if let Err(e) = self.enqueue_notification(db, project_id, channel_id, &alert.notification).await {
+            error!("알림 발행 실패 (project {}): {:?}", project_id, e);
+        }
+    }
+
+    async fn dead_letter(&self, queue: &str, delivery: &Delivery, retry_count: i64, reason: &str) -> Result<()> {
+        let mut headers = forwarded_headers(delivery);
+        headers.insert(ShortString::from(RETRY_COUNT_HEADER), AMQPValue::LongLongInt(retry_count));
+        headers.insert(ShortString::from(ERROR_HEADER), AMQPValue::LongString(reason.into()));
+        self.publish(&dead_letter_queue_name(queue), &delivery.data, headers).await
+    }
+
+    async fn ack(&self, delivery: &Delivery) -> Result<()> {
+        self.channel
+            .basic_ack(delivery.delivery_tag, BasicAckOptions::default())
+            .await
+            .context("Ack 실패")
+    }
+}
+
+enum SendFailure {
+    Retryable(anyhow::Error),
+    Permanent(anyhow::Error),
+}
+
+fn retry_queue_name(queue: &str) -> String {
+    format!("{}.retry", queue)
+}
+
+// n번째 시도가 실패한 알림이 대기하는 retry 큐
+fn notification_retry_queue_name(queue: &str, attempt: i32) -> String {
+    format!("{}.retry.{}", queue, attempt)
+}
+
+fn notification_retry_delay_ms(attempt: i32) -> i64 {
+    NOTIFICATION_RETRY_BASE_DELAY_MS << (attempt - 1)
+}
+
+fn dead_letter_queue_name(queue: &str) -> String {
+    format!("{}.dlq", queue)
+}
+
+// 재시도나 DLQ로 다시 발행할 때 원래 헤더(클라이언트 IP 등)를 그대로 넘긴다.
+fn forwarded_headers(delivery: &Delivery) -> FieldTable {
+    delivery.properties.headers().clone().unwrap_or_default()
+}
+
+fn client_ip(delivery: &Delivery) -> Option<IpAddr> {
+    match delivery.properties.headers().as_ref()?.inner().get(CLIENT_IP_HEADER)? {
+        AMQPValue::LongString(ip) => ip.to_string().parse().ok(),
+        _ => None,
+    }
+}
+
+fn retry_count(headers: Option<&FieldTable>) -> i64 {
+    headers
+        .and_then(|headers| headers.inner().get(RETRY_COUNT_HEADER))
+        .and_then(|value| value.as_long_long_int())
+        .unwrap_or(0)
+}
+
+/// 처리에 실패한 이벤트 메시지를 보낼 곳
+#[derive(Debug, PartialEq, Eq)]
+enum FailureRoute {
+    Retry { next_count: i64 },
+    DeadLetter,
+}
+
+fn failure_route(retryable: bool, retry_count: i64) -> FailureRoute {
+    if retryable && retry_count < EVENT_MAX_RETRIES {
+        FailureRoute::Retry { next_count: retry_count + 1 }
     } else {
-        Err(anyhow::anyhow!(
-            "Slack 전송 실패, 상태 코드: {}",
-            res.status()
-        ))
+        FailureRoute::DeadLetter
+    }
+}
+
+// 잘못된 API 키 같은 요청 자체의 문제는 재시도해도 성공하지 않는다.
+fn is_retryable(err: &AppError) -> bool {
+    matches!(err, AppError::InternalServerError(_))
+}
+
+async fn declare_event_queues(channel: &Channel, queue: &str) -> Result<()> {
+    let durable = QueueDeclareOptions {
+        durable: true,
+        ..Default::default()
+    };
+
+    channel
+        .queue_declare(queue, durable, FieldTable::default())
+        .await
+        .context("이벤트 큐 선언 실패")?;
+
+    declare_retry_queue(channel, &retry_queue_name(queue), queue, EVENT_RETRY_DELAY_MS)
+        .await
+        .context("이벤트 retry 큐 선언 실패")?;
+
+    channel
+        .queue_declare(&dead_letter_queue_name(queue), durable, FieldTable::default())
+        .await
+        .context("이벤트 DLQ 선언 실패")?;
+
+    Ok(())
+}
+
+async fn declare_notification_queues(channel: &Channel, queue: &str) -> Result<()> {
+    let durable = QueueDeclareOptions {
+        durable: true,
+        ..Default::default()
+    };
+
+    channel
+        .queue_declare(queue, durable, FieldTable::default())
+        .await
+        .context("알림 큐 선언 실패")?;
+
+    for attempt in 1..NOTIFICATION_MAX_ATTEMPTS {
+        declare_retry_queue(
+            channel,
+            &notification_retry_queue_name(queue, attempt),
+            queue,
+            notification_retry_delay_ms(attempt),
+        )
+            .await
+            .context("알림 retry 큐 선언 실패")?;
     }
+
+    channel
+        .queue_declare(&dead_letter_queue_name(queue), durable, FieldTable::default())
+        .await
+        .context("알림 DLQ 선언 실패")?;
+
+    Ok(())
+}
+
+// retry 큐는 TTL이 지나면 기본 exchange를 통해 원래 큐로 되돌아간다.
+async fn declare_retry_queue(channel: &Channel, name: &str, target: &str, delay_ms: i64) -> Result<()> {
+    let mut args = FieldTable::default();
+    args.insert(ShortString::from("x-message-ttl"), AMQPValue::LongLongInt(delay_ms));
+    args.insert(ShortString::from("x-dead-letter-exchange"), AMQPValue::LongString("".into()));
+    args.insert(ShortString::from("x-dead-letter-routing-key"), AMQPValue::LongString(target.into()));
+    channel
+        .queue_declare(
+            name,
+            QueueDeclareOptions {
+                durable: true,
+                ..Default::default()
+            },
+            args,
+        )
+        .await?;
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::SlackMessage;
+    use super::*;
     use serde_json;
 
     #[test]
-    fn slack_message_serde_round_trip() {
-        let orig = SlackMessage { text: "hello".into() };
+    fn notification_message_serde_round_trip() {
+        let orig = NotificationMessage { delivery_id: 42 };
         let json = serde_json::to_string(&orig).unwrap();
-        let de: SlackMessage = serde_json::from_str(&json).unwrap();
-        assert_eq!(de.text, orig.text);
+        assert_eq!(json, r#"{"deliveryId":42}"#);
+        let de: NotificationMessage = serde_json::from_str(&json).unwrap();
+        assert_eq!(de.delivery_id, orig.delivery_id);
+    }
+
+    #[test]
+    fn reads_retry_count_header() {
+        assert_eq!(retry_count(None), 0);
+        assert_eq!(retry_count(Some(&FieldTable::default())), 0);
+
+        let mut headers = FieldTable::default();
+        headers.insert(ShortString::from(RETRY_COUNT_HEADER), AMQPValue::LongLongInt(3));
+        assert_eq!(retry_count(Some(&headers)), 3);
+
+        // 다른 타입으로 들어온 헤더는 처음 시도로 본다.
+        let mut headers = FieldTable::default();
+        headers.insert(ShortString::from(RETRY_COUNT_HEADER), AMQPValue::LongString("3".into()));
+        assert_eq!(retry_count(Some(&headers)), 0);
+    }
+
+    #[test]
+    fn retries_until_max_then_dead_letters() {
+        assert_eq!(failure_route(true, 0), FailureRoute::Retry { next_count: 1 });
+        assert_eq!(failure_route(true, EVENT_MAX_RETRIES - 1), FailureRoute::Retry { next_count: EVENT_MAX_RETRIES });
+        assert_eq!(failure_route(true, EVENT_MAX_RETRIES), FailureRoute::DeadLetter);
+        assert_eq!(failure_route(false, 0), FailureRoute::DeadLetter);
+    }
+
+    #[test]
+    fn notification_retry_delay_doubles_per_attempt() {
+        let delays: Vec<i64> = (1..NOTIFICATION_MAX_ATTEMPTS).map(notification_retry_delay_ms).collect();
+        assert_eq!(delays, vec![5_000, 10_000, 20_000, 40_000]);
     }
 }
@@ -0,0 +1,464 @@
+use actix_web::{delete, get, post, put, web, HttpResponse};
+use chrono::{Duration, Utc};
+use regex::Regex;
+use sea_orm::{ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, ModelTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select, Set};
+use sea_query::{Expr, Func, SimpleExpr};
+use crate::api::event::ProcessedEvent;
+use crate::api::notification_channel::find_notification_channel;
+use crate::api::project::{check_active_project, check_project_member};
+use crate::entity::alert_rule::{self, ActiveModel as AlertRuleActiveModel, AlertCondition, Entity as AlertRuleEntity};
+use crate::entity::event::{self, Entity as EventEntity};
+use crate::model::alert_rule::{AlertAction, AlertFilters, AlertRuleRequest, AlertRuleResponse};
+use crate::model::global_error::{AppError, ErrorCode, ValidationFieldError};
+use crate::notification::Notification;
+use crate::util::alert::{alert_notification, filters_match};
+
+/// 발동한 규칙이 실행할 동작과 알림
+pub struct AlertNotification {
+    pub action: AlertAction,
+    pub notification: Notification,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/alert-rules",
+    summary = "알림 규칙 생성",
+    request_body = AlertRuleRequest,
+    responses(
+        (status = 201, description = "알림 규칙 생성 성공", body = AlertRuleResponse),
+        (status = 400, description = "잘못된 요청", body = ValidationFieldError),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "AlertRule"
+)]
+#[post("/projects/{project_id}/alert-rules")]
+pub async fn create_alert_rule(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    auth_user: web::ReqData<i32>,
+    body: web::Json<AlertRuleRequest>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let request = body.into_inner();
+
+    validate_alert_rule(&request)?;
+    check_active_project(db.get_ref(), project_id).await?;
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    check_action_channels(db.get_ref(), project_id, &request.actions).await?;
+
+    let mut rule = AlertRuleActiveModel {
+        project_id: Set(project_id),
+        last_triggered_at: Set(None),
+        ..Default::default()
+    };
+    apply_request(&mut rule, request);
+
+    let inserted = rule.insert(db.get_ref()).await?;
+
+    Ok(HttpResponse::Created().json(AlertRuleResponse::from(inserted)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/alert-rules",
+    summary = "알림 규칙 목록 조회",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+    ),
+    responses(
+        (status = 200, description = "알림 규칙 목록 조회 성공", body = [AlertRuleResponse]),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "AlertRule"
+)]
+#[get("/projects/{project_id}/alert-rules")]
+pub async fn list_alert_rules(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let rules = AlertRuleEntity::find()
+        .filter(alert_rule::Column::ProjectId.eq(project_id))
+        .order_by_asc(alert_rule::Column::Id)
+        .all(db.get_ref())
+        .await?;
+
+    let responses: Vec<AlertRuleResponse> = rules
+        .into_iter()
+        .map(AlertRuleResponse::from)
+        .collect();
+
+    Ok(HttpResponse::Ok().json(responses))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/alert-rules/{id}",
+    summary = "알림 규칙 조회",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("id" = i32, Path, description = "알림 규칙 ID"),
+    ),
+    responses(
+        (status = 200, description = "알림 규칙 조회 성공", body = AlertRuleResponse),
+        (status = 404, description = "알림 규칙 없음"),
+    ),
+    tag = "AlertRule"
+)]
+#[get("/projects/{project_id}/alert-rules/{id}")]
+pub async fn get_alert_rule(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, rule_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    let rule = find_alert_rule(db.get_ref(), project_id, rule_id).await?;
+
+    Ok(HttpResponse::Ok().json(AlertRuleResponse::from(rule)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/alert-rules/{id}",
+    summary = "알림 규칙 수정",
+    request_body = AlertRuleRequest,
+    responses(
+        (status = 200, description = "알림 규칙 수정 성공", body = AlertRuleResponse),
+        (status = 400, description = "잘못된 요청", body = ValidationFieldError),
+        (status = 404, description = "알림 규칙 없음"),
+    ),
+    tag = "AlertRule"
+)]
+#[put("/projects/{project_id}/alert-rules/{id}")]
+pub async fn update_alert_rule(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+    body: web::Json<AlertRuleRequest>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, rule_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let request = body.into_inner();
+
+    validate_alert_rule(&request)?;
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    check_action_channels(db.get_ref(), project_id, &request.actions).await?;
+
+    let mut rule: AlertRuleActiveModel = find_alert_rule(db.get_ref(), project_id, rule_id).await?.into();
+    apply_request(&mut rule, request);
+
+    let updated = rule.update(db.get_ref()).await?;
+
+    Ok(HttpResponse::Ok().json(AlertRuleResponse::from(updated)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/projects/{project_id}/alert-rules/{id}",
+    summary = "알림 규칙 삭제",
+    responses(
+        (status = 204, description = "알림 규칙 삭제 성공"),
+        (status = 404, description = "알림 규칙 없음"),
+    ),
+    tag = "AlertRule"
+)]
+#[delete("/projects/{project_id}/alert-rules/{id}")]
+pub async fn delete_alert_rule(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, rule_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let rule = find_alert_rule(db.get_ref(), project_id, rule_id).await?;
+    rule.delete(db.get_ref()).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// 저장된 이벤트에 프로젝트의 알림 규칙을 적용하고, 발동한 규칙의 알림을 돌려준다.
+pub async fn evaluate_alert_rules(
+    db: &DatabaseConnection,
+    processed: &ProcessedEvent,
+) -> Result<Vec<AlertNotification>, AppError> {
+    let rules = AlertRuleEntity::find()
+        .filter(alert_rule::Column::ProjectId.eq(processed.event.project_id))
+        .filter(alert_rule::Column::Enabled.eq(true))
+        .all(db)
+        .await?;
+
+    let mut notifications = Vec::new();
+    for rule in rules {
+        let filters: AlertFilters = rule
+            .filters
+            .clone()
+            .and_then(|value| serde_json::from_value(value).ok())
+            .unwrap_or_default();
+
+        if !filters_match(&filters, &processed.event) {
+            continue;
+        }
+        if !condition_met(db, &rule, processed).await? {
+            continue;
+        }
+        if !acquire_throttle(db, &rule).await? {
+            continue;
+        }
+
+        let notification = alert_notification(&rule, &processed.issue, &processed.event);
+        let actions: Vec<AlertAction> = serde_json::from_value(rule.actions.clone()).unwrap_or_default();
+        notifications.extend(actions.into_iter().map(|action| AlertNotification {
+            action,
+            notification: notification.clone(),
+        }));
+    }
+
+    Ok(notifications)
+}
+
+async fn condition_met(
+    db: &DatabaseConnection,
+    rule: &alert_rule::Model,
+    processed: &ProcessedEvent,
+) -> Result<bool, AppError> {
+    match rule.condition {
+        AlertCondition::NewIssue => Ok(processed.is_new_issue),
+        AlertCondition::Regression => Ok(processed.is_regression),
+        AlertCondition::EventFrequency => {
+            let since = Utc::now() - Duration::minutes(rule.interval_minutes.unwrap_or_default() as i64);
+            let count = EventEntity::find()
+                .filter(event::Column::IssueId.eq(processed.issue.id))
+                .filter(event::Column::CreatedAt.gte(since))
+                .count(db)
+                .await?;
+            Ok(exceeds_threshold(count as i64, rule.threshold))
+        }
+        AlertCondition::UniqueUsers => {
+            let users: Option<i64> = unique_users_query(processed.issue.id, rule.interval_minutes)
+                .into_tuple()
+                .one(db)
+                .await?;
+            Ok(exceeds_threshold(users.unwrap_or_default(), rule.threshold))
+        }
+    }
+}
+
+/// 집계값이 기준값을 넘어야 발동한다. 기준값과 같으면 발동하지 않는다.
+fn exceeds_threshold(count: i64, threshold: Option<i32>) -> bool {
+    count > threshold.unwrap_or_default() as i64
+}
+
+fn unique_users_query(issue_id: i32, interval_minutes: Option<i32>) -> Select<EventEntity> {
+    let mut select = EventEntity::find()
+        .select_only()
+        .expr(Func::count_distinct(affected_user_key()))
+        .filter(event::Column::IssueId.eq(issue_id));
+
+    if let Some(interval) = interval_minutes {
+        select = select.filter(event::Column::CreatedAt.gte(Utc::now() - Duration::minutes(interval as i64)));
+    }
+
+    select
+}
+
+// 영향받은 사용자는 스크러빙 전에 구한 사용자 키 해시로 구분한다. 해시가 없는 이전 이벤트는 SDK가 보낸
+// user.id, user.email 순으로 구분하고, 모두 없으면 보고한 멤버로 센다.
+// 값이 없는 필드는 JSON null로 저장되므로 'null' 문자열도, 가려진 값도 없는 값으로 본다.
+pub fn affected_user_key() -> SimpleExpr {
+    Expr::cust(
+        "COALESCE(\
+            CONCAT('hash:', NULLIF(NULLIF(JSON_UNQUOTE(JSON_EXTRACT(`user_context`, '$.keyHash')), 'null'), '')), \
+            CONCAT('id:', NULLIF(NULLIF(NULLIF(JSON_UNQUOTE(JSON_EXTRACT(`user_context`, '$.id')), 'null'), ''), '[Filtered]')), \
+            CONCAT('email:', NULLIF(NULLIF(NULLIF(JSON_UNQUOTE(JSON_EXTRACT(`user_context`, '$.email')), 'null'), ''), '[Filtered]')), \
+            CONCAT('member:', `reported_by`))",
+    )
+}
+
+// 마지막 발송 시각을 조건부로 갱신해서, 여러 컨슈머가 동시에 평가해도 한 번만 발송되게 한다.
+async fn acquire_throttle(db: &DatabaseConnection, rule: &alert_rule::Model) -> Result<bool, AppError> {
+    let now = Utc::now();
+    let since = now - Duration::minutes(rule.throttle_minutes as i64);
+
+    let result = AlertRuleEntity::update_many()
+        .col_expr(alert_rule::Column::LastTriggeredAt, Expr::value(now))
+        .filter(alert_rule::Column::Id.eq(rule.id))
+        .filter(
+            Condition::any()
+                .add(alert_rule::Column::LastTriggeredAt.is_null())
+                .add(alert_rule::Column::LastTriggeredAt.lte(since))
+        )
+        .exec(db)
+        .await?;
+
+    Ok(result.rows_affected == 1)
+}
+
+async fn find_alert_rule(
+    db: &DatabaseConnection,
+    project_id: i32,
+    rule_id: i32,
+) -> Result<alert_rule::Model, AppError> {
+    AlertRuleEntity::find_by_id(rule_id)
+        .filter(alert_rule::Column::ProjectId.eq(project_id))
+        .one(db)
+        .await?
+        .ok_or_else(|| AppError::not_found(ErrorCode::AlertRuleNotFound))
+}
+
+// 규칙의 채널 동작은 같은 프로젝트의 채널만 가리킬 수 있다.
+async fn check_action_channels(
+    db: &DatabaseConnection,
+    project_id: i32,
+    actions: &[AlertAction],
+) -> Result<(), AppError> {
+    for action in actions {
+        if let AlertAction::Channel { channel_id } = action {
+            find_notification_channel(db, project_id, *channel_id).await?;
+        }
+    }
+    Ok(())
+}
+
+fn apply_request(rule: &mut AlertRuleActiveModel, request: AlertRuleRequest) {
+    rule.name = Set(request.name.trim().to_string());
+    rule.enabled = Set(request.enabled);
+    rule.condition = Set(request.condition);
+    rule.threshold = Set(request.threshold);
+    rule.interval_minutes = Set(request.interval_minutes);
+    rule.filters = Set(serde_json::to_value(&request.filters).ok());
+    rule.actions = Set(serde_json::to_value(&request.actions).unwrap_or_default());
+    rule.throttle_minutes = Set(request.throttle_minutes);
+}
+
+fn validate_alert_rule(request: &AlertRuleRequest) -> Result<(), AppError> {
+    let mut errors = Vec::new();
+
+    if request.name.trim().is_empty() {
+        errors.push(ValidationFieldError {
+            field: "name".to_string(),
+            message: "규칙 이름은 필수입니다.".to_string(),
+        });
+    }
+
+    let needs_threshold = matches!(request.condition, AlertCondition::EventFrequency | AlertCondition::UniqueUsers);
+    if needs_threshold && request.threshold.is_none_or(|threshold| threshold <= 0) {
+        errors.push(ValidationFieldError {
+            field: "threshold".to_string(),
+            message: "기준값은 1 이상이어야 합니다.".to_string(),
+        });
+    }
+
+    let needs_interval = request.condition == AlertCondition::EventFrequency;
+    if (needs_interval && request.interval_minutes.is_none())
+        || request.interval_minutes.is_some_and(|interval| interval <= 0)
+    {
+        errors.push(ValidationFieldError {
+            field: "intervalMinutes".to_string(),
+            message: "집계 구간은 1분 이상이어야 합니다.".to_string(),
+        });
+    }
+
+    if request.throttle_minutes < 0 {
+        errors.push(ValidationFieldError {
+            field: "throttleMinutes".to_string(),
+            message: "재발송 제한 시간은 0분 이상이어야 합니다.".to_string(),
+        });
+    }
+
+    if request.actions.is_empty() {
+        errors.push(ValidationFieldError {
+            field: "actions".to_string(),
+            message: "동작을 하나 이상 지정해야 합니다.".to_string(),
+        });
+    }
+
+    if request.filters.message_pattern.as_ref().is_some_and(|pattern| Regex::new(pattern).is_err()) {
+        errors.push(ValidationFieldError {
+            field: "filters.messagePattern".to_string(),
+            message: "올바른 정규식이 아닙니다.".to_string(),
+        });
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(AppError::ValidationError(errors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use sea_orm::{DbBackend, QueryTrait};
+    use serde_json::json;
+    use crate::api::scrubbing::scrub_event;
+    use crate::model::event::EventReportRequest;
+    use crate::model::scrubbing::ScrubbingRules;
+    use crate::util::scrub::{Scrubber, FILTERED};
+
+    #[test]
+    fn threshold_must_be_exceeded() {
+        assert!(!exceeds_threshold(4, Some(5)));
+        assert!(!exceeds_threshold(5, Some(5)));
+        assert!(exceeds_threshold(6, Some(5)));
+        assert!(exceeds_threshold(1, None));
+        assert!(!exceeds_threshold(0, None));
+    }
+
+    #[test]
+    fn counts_users_from_user_context_before_reporter() {
+        let sql = unique_users_query(3, None).build(DbBackend::MySql).to_string();
+
+        let id = sql.find("'$.id'").unwrap();
+        let email = sql.find("'$.email'").unwrap();
+        let member = sql.find("`reported_by`").unwrap();
+        assert!(id < email && email < member, "{sql}");
+        assert!(sql.starts_with("SELECT COUNT(DISTINCT COALESCE("), "{sql}");
+        assert!(sql.ends_with("WHERE `event`.`issue_id` = 3"), "{sql}");
+
+        let sql = unique_users_query(3, Some(60)).build(DbBackend::MySql).to_string();
+        assert!(sql.contains("`event`.`created_at` >="), "{sql}");
+    }
+
+    #[test]
+    fn counts_scrubbed_users_by_key_hash() {
+        let scrubber = Scrubber::new(&ScrubbingRules::default()).unwrap();
+        let stored_users = ["jane@example.com", "JANE@example.com", "joe@example.com"]
+            .into_iter()
+            .map(|email| {
+                let mut event: EventReportRequest = serde_json::from_value(json!({
+                    "message": "TypeError",
+                    "stacktrace": "",
+                    "appVersion": "1.0.0",
+                    "timestamp": "2025-04-20T00:00:00Z",
+                    "apiKey": "proj_test",
+                    "user": { "email": email },
+                }))
+                .unwrap();
+                scrub_event(&scrubber, &mut event);
+                serde_json::to_value(event.user.unwrap()).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // 이메일은 모두 가려지지만, 사용자를 구분하는 키는 남는다.
+        assert!(stored_users.iter().all(|user| user["email"] == FILTERED));
+        let keys = stored_users.iter().map(|user| user["keyHash"].as_str().unwrap()).collect::<HashSet<_>>();
+        assert_eq!(keys.len(), 2);
+
+        let sql = unique_users_query(3, None).build(DbBackend::MySql).to_string();
+        assert!(sql.find("'$.keyHash'").unwrap() < sql.find("'$.email'").unwrap(), "{sql}");
+    }
+}
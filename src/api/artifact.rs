@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use actix_web::{delete, get, post, web, HttpResponse};
+use sea_orm::{ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, ModelTrait, QueryFilter, QueryOrder, Set, TransactionTrait};
+use sourcemap::SourceMap;
+use tracing::warn;
+use crate::api::project::{check_active_project, check_project_member};
+use crate::entity::artifact::{self, ActiveModel as ArtifactActiveModel, Entity as ArtifactEntity};
+use crate::model::artifact::{ArtifactQuery, ArtifactResponse, ArtifactUploadRequest};
+use crate::model::event::SymbolicatedFrame;
+use crate::model::global_error::{AppError, ErrorCode};
+use crate::util::sourcemap::{artifact_name, format_frame, symbolicate_frame, SourceMapCache};
+use crate::util::stacktrace::parse_js_frame;
+
+/// 소스맵으로 복원한 스택트레이스
+pub struct Symbolication {
+    pub stacktrace: String,
+    pub frames: Vec<SymbolicatedFrame>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/artifacts",
+    summary = "소스맵 업로드",
+    request_body = ArtifactUploadRequest,
+    responses(
+        (status = 201, description = "소스맵 업로드 성공", body = ArtifactResponse),
+        (status = 400, description = "잘못된 소스맵"),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Artifact"
+)]
+#[post("/projects/{project_id}/artifacts")]
+pub async fn upload_artifact(
+    db: web::Data<DatabaseConnection>,
+    source_maps: web::Data<SourceMapCache>,
+    path: web::Path<i32>,
+    auth_user: web::ReqData<i32>,
+    body: web::Json<ArtifactUploadRequest>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let ArtifactUploadRequest { app_version, file_name, source_map } = body.into_inner();
+
+    check_active_project(db.get_ref(), project_id).await?;
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let content = serde_json::to_string(&source_map)
+        .map_err(|_| AppError::bad_request(ErrorCode::InvalidSourceMap))?;
+    SourceMap::from_slice(content.as_bytes())
+        .map_err(|_| AppError::bad_request(ErrorCode::InvalidSourceMap))?;
+
+    let name = artifact_name(&file_name);
+    let txn = db.begin().await?;
+
+    // 같은 버전/파일의 소스맵은 새로 올린 것으로 교체한다.
+    ArtifactEntity::delete_many()
+        .filter(
+            Condition::all()
+                .add(artifact::Column::ProjectId.eq(project_id))
+                .add(artifact::Column::AppVersion.eq(&app_version))
+                .add(artifact::Column::Name.eq(&name))
+        )
+        .exec(&txn)
+        .await?;
+
+    let inserted = ArtifactActiveModel {
+        project_id: Set(project_id),
+        app_version: Set(app_version),
+        name: Set(name),
+        size: Set(content.len() as i64),
+        content: Set(content),
+        ..Default::default()
+    }
+        .insert(&txn)
+        .await?;
+
+    txn.commit().await?;
+    source_maps.invalidate(project_id, &inserted.app_version, &inserted.name);
+
+    Ok(HttpResponse::Created().json(ArtifactResponse::from(inserted)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/artifacts",
+    summary = "소스맵 목록 조회",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("appVersion" = Option<String>, Query, description = "앱 버전"),
+    ),
+    responses(
+        (status = 200, description = "소스맵 목록 조회 성공", body = [ArtifactResponse]),
+    ),
+    tag = "Artifact"
+)]
+#[get("/projects/{project_id}/artifacts")]
+pub async fn list_artifacts(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    auth_user: web::ReqData<i32>,
+    query: web::Query<ArtifactQuery>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let mut select = ArtifactEntity::find()
+        .filter(artifact::Column::ProjectId.eq(project_id));
+
+    if let Some(app_version) = &query.app_version {
+        select = select.filter(artifact::Column::AppVersion.eq(app_version));
+    }
+
+    let artifacts = select
+        .order_by_desc(artifact::Column::CreatedAt)
+        .all(db.get_ref())
+        .await?;
+
+    let responses: Vec<ArtifactResponse> = artifacts
+        .into_iter()
+        .map(ArtifactResponse::from)
+        .collect();
+
+    Ok(HttpResponse::Ok().json(responses))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/projects/{project_id}/artifacts/{id}",
+    summary = "소스맵 삭제",
+    responses(
+        (status = 204, description = "소스맵 삭제 성공"),
+        (status = 404, description = "소스맵 없음"),
+    ),
+    tag = "Artifact"
+)]
+#[delete("/projects/{project_id}/artifacts/{id}")]
+pub async fn delete_artifact(
+    db: web::Data<DatabaseConnection>,
+    source_maps: web::Data<SourceMapCache>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, artifact_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let artifact = ArtifactEntity::find_by_id(artifact_id)
+        .filter(artifact::Column::ProjectId.eq(project_id))
+        .one(db.get_ref())
+        .await?
+        .ok_or_else(|| AppError::not_found(ErrorCode::ArtifactNotFound))?;
+
+    let (app_version, name) = (artifact.app_version.clone(), artifact.name.clone());
+    artifact.delete(db.get_ref()).await?;
+    source_maps.invalidate(project_id, &app_version, &name);
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// 업로드된 소스맵으로 스택트레이스를 복원한다. 복원된 프레임이 하나도 없으면 `None`.
+pub async fn symbolicate_stacktrace(
+    db: &DatabaseConnection,
+    cache: &SourceMapCache,
+    project_id: i32,
+    app_version: &str,
+    stacktrace: &str,
+) -> Result<Option<Symbolication>, AppError> {
+    let mut source_maps: HashMap<String, Option<Arc<SourceMap>>> = HashMap::new();
+    let mut lines = Vec::new();
+    let mut frames = Vec::new();
+    let mut symbolicated_any = false;
+
+    for line in stacktrace.lines() {
+        let Some((frame, file)) = parse_js_frame(line)
+            .and_then(|frame| frame.file.clone().map(|file| (frame, file)))
+        else {
+            lines.push(line.to_string());
+            continue;
+        };
+
+        let name = artifact_name(&file);
+        if !source_maps.contains_key(&name) {
+            let source_map = find_source_map(db, cache, project_id, app_version, &name).await?;
+            source_maps.insert(name.clone(), source_map);
+        }
+
+        let symbolicated = source_maps[&name]
+            .as_ref()
+            .and_then(|source_map| symbolicate_frame(source_map, &frame));
+
+        match symbolicated {
+            Some(symbolicated) => {
+                lines.push(format_frame(&symbolicated));
+                frames.push(symbolicated);
+                symbolicated_any = true;
+            }
+            None => {
+                lines.push(line.to_string());
+                frames.push(SymbolicatedFrame {
+                    function: frame.function,
+                    file,
+                    line: frame.line.unwrap_or_default(),
+                    column: frame.column.unwrap_or_default(),
+                    pre_context: Vec::new(),
+                    context_line: None,
+                    post_context: Vec::new(),
+                });
+            }
+        }
+    }
+
+    if !symbolicated_any {
+        return Ok(None);
+    }
+
+    Ok(Some(Symbolication {
+        stacktrace: lines.join("\n"),
+        frames,
+    }))
+}
+
+// 캐시에 없을 때만 DB에서 읽어 파싱한다. 없거나 읽을 수 없는 소스맵도 캐시에 기억한다.
+async fn find_source_map(
+    db: &DatabaseConnection,
+    cache: &SourceMapCache,
+    project_id: i32,
+    app_version: &str,
+    name: &str,
+) -> Result<Option<Arc<SourceMap>>, AppError> {
+    if let Some(source_map) = cache.get(project_id, app_version, name) {
+        return Ok(source_map);
+    }
+
+    let artifact = ArtifactEntity::find()
+        .filter(
+            Condition::all()
+                .add(artifact::Column::ProjectId.eq(project_id))
+                .add(artifact::Column::AppVersion.eq(app_version))
+                .add(artifact::Column::Name.eq(name))
+        )
+        .one(db)
+        .await?;
+
+    let size = artifact.as_ref().map_or(0, |artifact| artifact.content.len());
+    let source_map = artifact.and_then(|artifact| match SourceMap::from_slice(artifact.content.as_bytes()) {
+        Ok(source_map) => Some(source_map),
+        Err(e) => {
+            warn!("소스맵 파싱 실패 (artifact {}): {}", artifact.id, e);
+            None
+        }
+    });
+
+    Ok(cache.insert(project_id, app_version, name, source_map, size))
+}
@@ -1,32 +1,98 @@
-use std::env;
-use actix_web::{get, post, put, web, HttpResponse};
+use actix_web::{get, post, put, web, HttpRequest, HttpResponse};
 use chrono::Utc;
-use sea_orm::{EntityTrait, Set, ActiveModelTrait, QueryOrder, DatabaseConnection, QueryFilter, Condition, ColumnTrait, PaginatorTrait, DbErr, QuerySelect, QueryTrait};
+use sea_orm::{EntityTrait, Set, ActiveModelTrait, QueryOrder, DatabaseConnection, QueryFilter, Condition, ColumnTrait, PaginatorTrait, QuerySelect, Order, Select};
 use crate::entity::event::{self, ActiveModel as EventActiveModel, Entity as EventEntity, Column as EventColumn};
-use crate::entity::issue::{ActiveModel as IssueActiveModel, Entity as IssueEntity};
+use crate::entity::issue::{ActiveModel as IssueActiveModel, Entity as IssueEntity, IssueStatus};
+use crate::entity::issue_activity::{ActiveModel as IssueActivityActiveModel, ActivityKind};
 use crate::entity::project::{Entity as ProjectEntity};
 use crate::entity::project_member::{self, Entity as ProjectMemberEntity};
-use crate::model::event::{BatchEventReportRequest, BatchEventReportResponse, EventAssignee, EventPriority, EventQuery, EventReportListResponse, EventReportRequest, EventReportResponse, EventStatusDto, PaginatedResponse};
+use crate::entity::saved_search::SavedSearchSort;
+use crate::model::common::PaginationMode;
+use crate::model::event::{BatchEventReportRequest, BatchEventReportResponse, EventAcceptedResponse, EventAssignee, EventCountryCount, EventCountryQuery, EventPriority, EventQuery, EventReportListResponse, EventReportRequest, EventReportResponse, EventStatusDto, PaginatedResponse, StackFrame};
 use sha2::{Sha256, Digest};
-use crate::util::slack::send_slack_alert;
 use crate::entity::{issue, project};
-use crate::model::global_error::{AppError, ErrorCode};
+use crate::model::global_error::{AppError, ErrorCode, ValidationFieldError};
+use std::cmp::Ordering;
+use std::env;
+use std::net::IpAddr;
 use std::sync::LazyLock;
+use serde_json::json;
 use sea_query::Expr;
-use tracing::error;
+use tracing::{error, warn};
+use crate::api::artifact::symbolicate_stacktrace;
 use crate::api::project::check_project_member;
+use crate::api::pagination::{fetch_cursor_page, parse_cursor, Keyset};
+use crate::api::saved_search::find_saved_search;
+use crate::api::scrubbing::{project_scrubber, scrub_event};
+use crate::api::search::event_search_condition;
+use crate::api::tag::{index_event_tags, parse_tag_filters, tagged_event_ids};
+use crate::api::usage::IngestLimiter;
+use crate::amqp::AmqpClient;
+use crate::util::stacktrace::{grouping_key, parse_stacktrace};
+use crate::util::version::compare_versions;
+use crate::util::client_ip::{resolve_client_ip, IpRange};
+use crate::util::cursor::SortKey;
+use crate::util::geoip::GeoIp;
+use crate::util::sourcemap::SourceMapCache;
+use crate::util::user_agent::parse_user_agent;
+
+// 서버 앞단 로드밸런서처럼 모든 프로젝트가 믿는 프록시 (`TRUSTED_PROXIES`, 쉼표로 구분)
+static TRUSTED_PROXIES: LazyLock<Vec<IpRange>> = LazyLock::new(|| {
+    env::var("TRUSTED_PROXIES")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|proxy| !proxy.is_empty())
+        .filter_map(|proxy| {
+            proxy
+                .parse()
+                .map_err(|e| warn!("TRUSTED_PROXIES 값을 무시합니다: {}", e))
+                .ok()
+        })
+        .collect()
+});
 
-async fn find_project_by_api_key(db: &DatabaseConnection, api_key: &str) -> Result<i32, AppError> {
+async fn find_project_by_api_key(db: &DatabaseConnection, api_key: &str) -> Result<project::Model, AppError> {
     let project = ProjectEntity::find()
         .filter(project::Column::ApiKey.eq(api_key))
         .one(db)
         .await?
         .ok_or_else(|| AppError::bad_request(ErrorCode::InvalidApiKey))?;
 
-    Ok(project.id)
+    Ok(project)
+}
+
+/// 그룹핑 결과로 만들어지거나 갱신된 이슈
+pub struct IssueOutcome {
+    pub issue: issue::Model,
+    pub is_new: bool,
+    pub is_regression: bool,
+}
+
+/// 이벤트 처리 결과. 컨슈머가 알림 발송 여부를 판단하는 데 사용한다.
+pub struct ProcessedEvent {
+    pub event: event::Model,
+    pub issue: issue::Model,
+    pub is_new_issue: bool,
+    pub is_regression: bool,
 }
 
-async fn create_or_update_issue(db: &DatabaseConnection, project_id: i32, group_hash: &str, message: &str) -> Result<i32, AppError> {
+// 이슈 제목은 메시지 앞 100자까지. 바이트가 아니라 글자 단위로 자른다.
+fn issue_title(message: &str) -> String {
+    if message.chars().count() > 100 {
+        format!("{}...", message.chars().take(97).collect::<String>())
+    } else {
+        message.to_string()
+    }
+}
+
+async fn create_or_update_issue(
+    db: &DatabaseConnection,
+    project_id: i32,
+    group_hash: &str,
+    message: &str,
+    app_version: &str,
+) -> Result<IssueOutcome, AppError> {
     let now = Utc::now();
 
     let existing_issue = IssueEntity::find()
@@ -38,30 +104,58 @@ async fn create_or_update_issue(db: &DatabaseConnection, project_id: i32, group_
         .await?;
 
     if let Some(issue) = existing_issue {
+        let is_regression = issue.is_regression(app_version);
+
         let mut issue_model: issue::ActiveModel = issue.clone().into();
         issue_model.count = Set(issue.count + 1);
         issue_model.last_seen = Set(now.into());
         issue_model.updated_at = Set(now.into());
 
+        let is_newer_release = issue
+            .last_release
+            .as_deref()
+            .is_none_or(|release| compare_versions(app_version, release) == Ordering::Greater);
+        if is_newer_release {
+            issue_model.last_release = Set(Some(app_version.to_string()));
+        }
+
+        if is_regression {
+            issue_model.status = Set(IssueStatus::Regressed);
+        }
+
         let updated_issue = issue_model.update(db).await?;
 
-        Ok(updated_issue.id)
-    } else {
-        let title = if message.len() > 100 {
-            format!("{}...", &message[..97])
-        } else {
-            message.to_string()
-        };
+        if is_regression {
+            IssueActivityActiveModel::new(
+                updated_issue.id,
+                project_id,
+                ActivityKind::Regressed,
+                None,
+                Some(json!({
+                    "appVersion": app_version,
+                    "resolvedInVersion": issue.resolved_in_version,
+                    "resolvedInNextRelease": issue.resolved_in_next_release,
+                })),
+            )
+                .insert(db)
+                .await?;
+        }
 
+        Ok(IssueOutcome { issue: updated_issue, is_new: false, is_regression })
+    } else {
         let new_issue = IssueActiveModel {
-            title: Set(title),
+            title: Set(issue_title(message)),
             group_hash: Set(group_hash.to_string()),
-            status: Set("open".to_string()),
+            status: Set(IssueStatus::Open),
             first_seen: Set(now.into()),
             last_seen: Set(now.into()),
             count: Set(1),
             project_id: Set(project_id),
             assigned_to: Set(None),
+            last_release: Set(Some(app_version.to_string())),
+            resolved_in_version: Set(None),
+            resolved_in_next_release: Set(false),
+            resolved_at: Set(None),
             created_at: Set(now.into()),
             updated_at: Set(now.into()),
             ..Default::default()
@@ -69,7 +163,7 @@ async fn create_or_update_issue(db: &DatabaseConnection, project_id: i32, group_
 
         let inserted_issue = new_issue.insert(db).await?;
 
-        Ok(inserted_issue.id)
+        Ok(IssueOutcome { issue: inserted_issue, is_new: true, is_regression: false })
     }
 }
 
@@ -79,88 +173,146 @@ async fn create_or_update_issue(db: &DatabaseConnection, project_id: i32, group_
     summary = "이벤트 batch report",
     request_body = BatchEventReportRequest,
     responses(
-        (status = 200, description = "이벤트 전송 성공", body = BatchEventReportResponse),
+        (status = 202, description = "이벤트 접수 성공", body = BatchEventReportResponse),
+        (status = 429, description = "수집 제한 초과"),
     ),
     tag = "Event"
 )]
 #[post("/batch-events")]
 pub async fn report_batch_events(
+    req: HttpRequest,
     body: web::Json<BatchEventReportRequest>,
     db: web::Data<DatabaseConnection>,
+    amqp: web::Data<AmqpClient>,
+    limiter: web::Data<IngestLimiter>,
 ) -> Result<HttpResponse, AppError> {
-    println!("Batch event report: {:?}", body);
-
     let mut success_count = 0;
     let mut events = Vec::new();
-    let mut project_id_opt: Option<i32> = None;
-
-    println!("Processing {} events", body.events.len());
 
+    // 유효한 이벤트를 API 키별로 모아 한 번에 제한을 검사한다.
+    let mut by_api_key: Vec<(&str, Vec<(usize, &EventReportRequest)>)> = Vec::new();
     for (index, event) in body.events.iter().enumerate() {
-        match process_event(db.get_ref(), event).await {
-            Ok(pid) => {
-                success_count += 1;
-                project_id_opt = Some(pid);
-            },
-            Err(e) => events.push(format!("이벤트 #{} 처리 중 오류: {}", index, e)),
+        if let Err(e) = validate_event_request(event) {
+            events.push(format!("이벤트 #{} 처리 중 오류: {}", index, e));
+            continue;
+        }
+
+        match by_api_key.iter_mut().find(|(api_key, _)| *api_key == event.api_key) {
+            Some((_, group)) => group.push((index, event)),
+            None => by_api_key.push((&event.api_key, vec![(index, event)])),
         }
     }
 
-    if let Some(project_id) = project_id_opt {
-        let count = EventEntity::find()
-            .filter(event::Column::ProjectId.eq(project_id))
-            .count(db.get_ref())
-            .await
-            .unwrap_or(0);
+    let mut limited = None;
+    let mut admitted = Vec::new();
+    for (api_key, group) in by_api_key {
+        match limiter.admit(db.get_ref(), api_key, group.len() as u32).await {
+            Ok(project) => {
+                let ip = client_ip(&req, &project);
+                admitted.extend(group.into_iter().map(|(index, event)| (index, event, ip)));
+            }
+            Err(e) => {
+                for (index, _) in &group {
+                    events.push(format!("이벤트 #{} 처리 중 오류: {}", index, e));
+                }
+                if let AppError::TooManyRequests(..) = e {
+                    limited = Some(e);
+                }
+            }
+        }
+    }
 
-        if count >= ERROR_THRESHOLD as u64 {
-            let webhook_url = SLACK_WEBHOOK_URL.clone();
-            let alert_message = format!("🚨 Project {} 에 에러가 {}개 이상 발생했습니다.", project_id, count);
+    // 하나도 받지 못했고 제한 때문이라면 클라이언트가 물러나도록 429를 그대로 돌려준다.
+    if let Some(e) = limited.filter(|_| admitted.is_empty()) {
+        return Err(e);
+    }
 
-            tokio::spawn(async move {
-                if let Err(e) = send_slack_alert(&webhook_url, &alert_message).await {
-                    error!("Slack 알림 전송 실패: {:?}", e);
-                }
-            });
+    for (index, event, ip) in admitted {
+        match amqp.publish_event(event, ip).await {
+            Ok(_) => success_count += 1,
+            Err(e) => {
+                error!("이벤트 발행 실패: {:?}", e);
+                events.push(format!("이벤트 #{} 처리 중 오류: {}", index, ErrorCode::InternalError));
+            }
         }
     }
 
-    Ok(HttpResponse::Ok().json(BatchEventReportResponse {
+    Ok(HttpResponse::Accepted().json(BatchEventReportResponse {
         processed: body.events.len(),
         success: success_count,
         events,
     }))
 }
 
-async fn process_event(
+/// 큐에서 꺼낸 이벤트를 그룹핑하고 저장한다. 이벤트 컨슈머에서 호출된다.
+pub async fn process_event(
     db: &DatabaseConnection,
     event: &EventReportRequest,
-) -> Result<i32, AppError> {
-    let project_id = find_project_by_api_key(db, &event.api_key).await?;
-    let group_hash = calculate_group_hash(&event.message, &event.stacktrace);
-    let issue_id = create_or_update_issue(db, project_id, &group_hash, &event.message).await?;
-
-    let new_log = EventActiveModel::from_error_event(event, project_id, issue_id, group_hash);
-    match new_log.insert(db).await {
-        Ok(_) => Ok(project_id),
-        Err(DbErr::Exec(error)) => {
-            println!("DB Exec Error: {}", error);
-            // 예: insert 실행 실패했을 때 (SQL syntax error, constraint error 등)
-            Err(AppError::internal_error(ErrorCode::InternalError))
+    client_ip: Option<IpAddr>,
+    geoip: &GeoIp,
+    source_maps: &SourceMapCache,
+) -> Result<ProcessedEvent, AppError> {
+    let project = find_project_by_api_key(db, &event.api_key).await?;
+    let project_id = project.id;
+
+    // 이슈 제목, 알림, 저장값 모두 가린 값을 쓰도록 가장 먼저 스크러빙한다.
+    let mut event = event.clone();
+    let scrubbed_fields = scrub_event(&project_scrubber(&project), &mut event);
+    let event = &event;
+
+    let symbolication = symbolicate_stacktrace(db, source_maps, project_id, &event.app_version, &event.stacktrace).await?;
+
+    // minified 이름 대신 복원된 스택트레이스로 그룹핑한다.
+    let stacktrace = symbolication
+        .as_ref()
+        .map_or(event.stacktrace.as_str(), |s| s.stacktrace.as_str());
+    let frames = parse_stacktrace(stacktrace);
+    let group_hash = calculate_group_hash(&event.message, &frames);
+    let outcome = create_or_update_issue(db, project_id, &group_hash, &event.message, &event.app_version).await?;
+
+    let mut new_log = EventActiveModel::from_error_event(event, project_id, outcome.issue.id, group_hash);
+    new_log.frames = Set(serde_json::to_value(&frames).ok());
+    // 발행한 뒤에 수집을 끈 프로젝트도 있으므로 저장할 때 한 번 더 확인한다.
+    if project.collect_ip_address {
+        new_log.ip_address = Set(client_ip.map(|ip| ip.to_string()));
+        if let Some(location) = client_ip.and_then(|ip| geoip.lookup(ip)) {
+            new_log.country_code = Set(location.country_code);
+            new_log.country_name = Set(location.country_name);
+            new_log.region = Set(location.region);
+            new_log.city = Set(location.city);
         }
-        Err(DbErr::Query(error)) => {
-            println!("DB Exec Error: {}", error);
-            // 예: query 실패했을 때
-            Err(AppError::internal_error(ErrorCode::InternalError))
+    }
+    if let Some(user_agent) = &event.user_agent {
+        let parsed = parse_user_agent(user_agent);
+        // SDK가 browser/os를 보내지 않았으면 파싱한 이름으로 채운다.
+        if event.browser.is_none() {
+            new_log.browser = Set(parsed.browser_name.clone());
         }
-        Err(other) => {
-            println!("DB Exec Error: {}", other);
-            // 다른 모든 에러
-            Err(AppError::internal_error(ErrorCode::InternalError))
+        if event.os.is_none() {
+            new_log.os = Set(parsed.os_name.clone());
         }
-    }.expect("TODO: panic message");
-
-    Ok(project_id)
+        new_log.browser_name = Set(parsed.browser_name);
+        new_log.browser_version = Set(parsed.browser_version);
+        new_log.os_name = Set(parsed.os_name);
+        new_log.os_version = Set(parsed.os_version);
+        new_log.device_type = Set(Some(parsed.device_type.as_str().to_string()));
+    }
+    if !scrubbed_fields.is_empty() {
+        new_log.scrubbed_fields = Set(serde_json::to_value(&scrubbed_fields).ok());
+    }
+    if let Some(symbolication) = symbolication {
+        new_log.symbolicated_stacktrace = Set(Some(symbolication.stacktrace));
+        new_log.symbolicated_frames = Set(serde_json::to_value(&symbolication.frames).ok());
+    }
+    let inserted = new_log.insert(db).await?;
+    index_event_tags(db, &inserted, &event.tags).await?;
+
+    Ok(ProcessedEvent {
+        event: inserted,
+        issue: outcome.issue,
+        is_new_issue: outcome.is_new,
+        is_regression: outcome.is_regression,
+    })
 }
 
 #[utoipa::path(
@@ -169,28 +321,28 @@ async fn process_event(
     summary = "이벤트 단일 report",
     request_body = EventReportRequest,
     responses(
-        (status = 201, description = "이벤트 전송 성공", body = EventReportListResponse),
+        (status = 202, description = "이벤트 접수 성공", body = EventAcceptedResponse),
+        (status = 429, description = "수집 제한 초과"),
     ),
     tag = "Event"
 )]
 #[post("/events")]
 pub async fn report_event(
+    req: HttpRequest,
     body: web::Json<EventReportRequest>,
     db: web::Data<DatabaseConnection>,
+    amqp: web::Data<AmqpClient>,
+    limiter: web::Data<IngestLimiter>,
 ) -> Result<HttpResponse, AppError> {
-    let project_id = find_project_by_api_key(db.get_ref(), &body.api_key).await?;
-    let group_hash = calculate_group_hash(&body.message, &body.stacktrace);
-    let issue_id = create_or_update_issue(db.get_ref(), project_id, &group_hash, &body.message).await?;
-    let new_log = EventActiveModel::from_error_event(
-        &body,
-        project_id,
-        issue_id,
-        group_hash.clone(),
-    );
-
-    let inserted = new_log.insert(db.get_ref()).await?;
-
-    Ok(HttpResponse::Created().json(EventReportListResponse::from(inserted)))
+    validate_event_request(&body)?;
+    let project = limiter.admit(db.get_ref(), &body.api_key, 1).await?;
+
+    amqp.publish_event(&body, client_ip(&req, &project)).await.map_err(|e| {
+        error!("이벤트 발행 실패: {:?}", e);
+        AppError::internal_error(ErrorCode::InternalError)
+    })?;
+
+    Ok(HttpResponse::Accepted().json(EventAcceptedResponse { accepted: 1 }))
 }
 
 #[utoipa::path(
@@ -200,18 +352,30 @@ pub async fn report_event(
     params(
         ("project_id" = i32, Path, description = "프로젝트 ID"),
         ("search" = Option<String>, Query, description = "검색어"),
-        ("page" = Option<i32>, Query, description = "페이지 번호"),
+        ("query" = Option<String>, Query, description = "구조화된 검색어 (예: is:unresolved browser:Firefox release:>=1.4 !assigned:me age:-24h)"),
+        ("savedSearchId" = Option<i32>, Query, description = "실행할 저장된 검색 ID. `query`와 함께 주면 두 조건을 모두 적용한다"),
+        ("pagination" = Option<PaginationMode>, Query, description = "페이지 방식 (offset, cursor). cursor는 전체 개수를 세지 않는다"),
+        ("cursor" = Option<String>, Query, description = "이전 응답의 nextCursor/prevCursor. 주면 cursor 방식으로 조회한다"),
+        ("page" = Option<i32>, Query, description = "페이지 번호 (offset 방식)"),
         ("page_size" = Option<i32>, Query, description = "페이지 크기"),
         ("start_date" = Option<String>, Query, description = "시작일 (ISO8601)"),
-        ("end_date" = Option<String>, Query, description = "종료일 (ISO8601)")
+        ("end_date" = Option<String>, Query, description = "종료일 (ISO8601)"),
+        ("browserName" = Option<String>, Query, description = "브라우저 이름 (예: Chrome)"),
+        ("osName" = Option<String>, Query, description = "OS 이름 (예: Windows)"),
+        ("deviceType" = Option<String>, Query, description = "기기 종류 (desktop, mobile, tablet, bot)"),
+        ("countryCode" = Option<String>, Query, description = "국가 코드 (ISO 3166-1, 예: KR)"),
+        ("tag" = Option<String>, Query, description = "태그 필터, 쉼표로 구분 (예: browser:Chrome,environment:production)")
     ),
     responses(
         (status = 200, description = "이벤트 목록 조회 성공", body = Vec<EventReportListResponse>),
+        (status = 400, description = "잘못된 커서 또는 검색어", body = ValidationFieldError),
+        (status = 404, description = "저장된 검색 없음"),
     ),
     tag = "Event"
 )]
 #[get("/projects/{project_id}/events")]
 pub async fn list_project_events(
+    req: HttpRequest,
     db: web::Data<DatabaseConnection>,
     path: web::Path<i32>,
     query: web::Query<EventQuery>,
@@ -222,42 +386,37 @@ pub async fn list_project_events(
 
     check_project_member(db.get_ref(), project_id, user_id).await?;
 
-    let EventQuery { search, page, page_size, start_date, end_date } = query.into_inner();
-
-    let page = page.unwrap_or(1).max(1);
-    let page_size = page_size.unwrap_or(20).min(100);
+    let query = query.into_inner();
+    let cursor = parse_cursor(query.cursor.as_deref())?;
+    let pagination = query.pagination;
+    let page = query.page.unwrap_or(1).max(1);
+    let page_size = query.page_size.unwrap_or(20).min(100);
     let offset = (page - 1) * page_size;
 
+    let (query, order) = filtered_events(db.get_ref(), project_id, user_id, &query).await?;
+
+    if pagination == Some(PaginationMode::Cursor) || cursor.is_some() {
+        let page = fetch_cursor_page(
+            db.get_ref(),
+            query,
+            Keyset { sort_column: event::Column::CreatedAt, id_column: event::Column::Id, order },
+            cursor,
+            page_size,
+            |event| (SortKey::Time(event.created_at), event.id),
+        )
+        .await?;
+        return Ok(page.respond(&req, page_size, EventReportListResponse::from));
+    }
+
     let total_elements = EventEntity::find()
         .filter(event::Column::ProjectId.eq(project_id))
         .count(db.get_ref())
         .await?;
-
-    let mut query = EventEntity::find()
-        .filter(event::Column::ProjectId.eq(project_id));
-
-    if let Some(search_term) = search {
-        let pattern = format!("%{}%", search_term);
-        query = query.filter(
-            Condition::any()
-                .add(event::Column::Message.like(&pattern))
-                .add(event::Column::Stacktrace.like(&pattern))
-                .add(event::Column::AppVersion.like(&pattern))
-        );
-    }
-
-    if let Some(start) = start_date {
-        query = query.filter(event::Column::Timestamp.gte(start));
-    }
-    if let Some(end) = end_date {
-        query = query.filter(event::Column::Timestamp.lte(end));
-    }
-
     let filtered_elements = query.clone().count(db.get_ref()).await?;
 
     let logs = query
-        .order_by_desc(event::Column::CreatedAt)
-        .order_by_desc(event::Column::Id)
+        .order_by(event::Column::CreatedAt, order.clone())
+        .order_by(event::Column::Id, order)
         .offset(Some(offset as u64))
         .limit(Some(page_size as u64))
         .all(db.get_ref())
@@ -279,6 +438,119 @@ pub async fn list_project_events(
     Ok(HttpResponse::Ok().json(response))
 }
 
+/// 이벤트 목록과 내보내기가 함께 쓰는 필터. 저장된 검색을 주면 그 정렬 방향도 돌려준다.
+pub async fn filtered_events(
+    db: &DatabaseConnection,
+    project_id: i32,
+    user_id: i32,
+    query: &EventQuery,
+) -> Result<(Select<EventEntity>, Order), AppError> {
+    let tag_filters = query.tag.as_deref().map(parse_tag_filters).transpose()?.unwrap_or_default();
+    let saved_search = match query.saved_search_id {
+        Some(search_id) => Some(find_saved_search(db, project_id, user_id, search_id).await?),
+        None => None,
+    };
+
+    let mut select = EventEntity::find()
+        .filter(event::Column::ProjectId.eq(project_id));
+
+    let search_queries = saved_search.iter().map(|saved| saved.query.as_str()).chain(query.query.as_deref());
+    for search_query in search_queries.filter(|q| !q.trim().is_empty()) {
+        select = select.filter(event_search_condition(db, project_id, user_id, search_query).await?);
+    }
+
+    if let Some(search_term) = &query.search {
+        let pattern = format!("%{}%", search_term);
+        select = select.filter(
+            Condition::any()
+                .add(event::Column::Message.like(&pattern))
+                .add(event::Column::Stacktrace.like(&pattern))
+                .add(event::Column::AppVersion.like(&pattern))
+        );
+    }
+
+    if let Some(start) = query.start_date {
+        select = select.filter(event::Column::Timestamp.gte(start));
+    }
+    if let Some(end) = query.end_date {
+        select = select.filter(event::Column::Timestamp.lte(end));
+    }
+    if let Some(browser_name) = &query.browser_name {
+        select = select.filter(event::Column::BrowserName.eq(browser_name));
+    }
+    if let Some(os_name) = &query.os_name {
+        select = select.filter(event::Column::OsName.eq(os_name));
+    }
+    if let Some(device_type) = query.device_type {
+        select = select.filter(event::Column::DeviceType.eq(device_type.as_str()));
+    }
+    if let Some(country_code) = &query.country_code {
+        select = select.filter(event::Column::CountryCode.eq(country_code.to_uppercase()));
+    }
+    for (key, value) in &tag_filters {
+        select = select.filter(event::Column::Id.in_subquery(tagged_event_ids(project_id, key, value)));
+    }
+
+    let order = match saved_search.map(|saved| saved.sort).unwrap_or_default() {
+        SavedSearchSort::Newest => Order::Desc,
+        SavedSearchSort::Oldest => Order::Asc,
+    };
+
+    Ok((select, order))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/events/countries",
+    summary = "국가별 이벤트 수 조회",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("startDate" = Option<String>, Query, description = "시작일 (ISO8601)"),
+        ("endDate" = Option<String>, Query, description = "종료일 (ISO8601)")
+    ),
+    responses(
+        (status = 200, description = "국가별 이벤트 수 조회 성공", body = Vec<EventCountryCount>),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Event"
+)]
+#[get("/projects/{project_id}/events/countries")]
+pub async fn get_event_countries(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    query: web::Query<EventCountryQuery>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let mut select = EventEntity::find()
+        .select_only()
+        .column(event::Column::CountryCode)
+        .expr(Expr::col(event::Column::CountryName).max())
+        .expr(Expr::col(event::Column::Id).count())
+        .filter(event::Column::ProjectId.eq(project_id))
+        .group_by(event::Column::CountryCode)
+        .order_by_desc(Expr::col(event::Column::Id).count());
+
+    if let Some(start) = query.start_date {
+        select = select.filter(event::Column::Timestamp.gte(start));
+    }
+    if let Some(end) = query.end_date {
+        select = select.filter(event::Column::Timestamp.lte(end));
+    }
+
+    let rows: Vec<(Option<String>, Option<String>, i64)> = select.into_tuple().all(db.get_ref()).await?;
+    let countries = rows
+        .into_iter()
+        .map(|(country_code, country_name, count)| EventCountryCount { country_code, country_name, count })
+        .collect::<Vec<_>>();
+
+    Ok(HttpResponse::Ok().json(countries))
+}
+
 #[utoipa::path(
     get,
     path = "/api/projects/{project_id}/events/{id}",
@@ -480,37 +752,174 @@ pub async fn set_event_status(
     Ok(HttpResponse::Ok().json(responses))
 }
 
-pub static SLACK_WEBHOOK_URL: LazyLock<String> = LazyLock::new(|| {
-    env::var("SLACK_WEBHOOK_URL").expect("SLACK_WEBHOOK_URL 환경 변수가 설정되어야 합니다.")
-});
-const ERROR_THRESHOLD: usize = 1;
+// 이벤트 하나에 받을 수 있는 breadcrumbs / 태그 수와 태그 길이
+const MAX_BREADCRUMBS: usize = 100;
+const MAX_TAGS: usize = 50;
+const MAX_TAG_KEY_LENGTH: usize = 32;
+const MAX_TAG_VALUE_LENGTH: usize = 200;
+const HTTP_METHODS: [&str; 9] = ["GET", "HEAD", "POST", "PUT", "DELETE", "CONNECT", "OPTIONS", "TRACE", "PATCH"];
+
+fn is_valid_tag_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.len() <= MAX_TAG_KEY_LENGTH
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | ':' | '-'))
+}
+
+fn validate_event_request(event: &EventReportRequest) -> Result<(), AppError> {
+    let mut errors = Vec::new();
+
+    if event.api_key.trim().is_empty() {
+        errors.push(ValidationFieldError {
+            field: "apiKey".to_string(),
+            message: "API 키는 필수입니다.".to_string(),
+        });
+    }
+
+    if event.message.trim().is_empty() {
+        errors.push(ValidationFieldError {
+            field: "message".to_string(),
+            message: "메시지는 필수입니다.".to_string(),
+        });
+    }
+
+    if event.app_version.trim().is_empty() {
+        errors.push(ValidationFieldError {
+            field: "appVersion".to_string(),
+            message: "앱 버전은 필수입니다.".to_string(),
+        });
+    }
+
+    if event.breadcrumbs.len() > MAX_BREADCRUMBS {
+        errors.push(ValidationFieldError {
+            field: "breadcrumbs".to_string(),
+            message: format!("breadcrumbs는 최대 {}개까지 보낼 수 있습니다.", MAX_BREADCRUMBS),
+        });
+    }
+    for (index, breadcrumb) in event.breadcrumbs.iter().enumerate() {
+        if breadcrumb.category.trim().is_empty() {
+            errors.push(ValidationFieldError {
+                field: format!("breadcrumbs[{}].category", index),
+                message: "breadcrumb 카테고리는 필수입니다.".to_string(),
+            });
+        }
+    }
+
+    if event.tags.len() > MAX_TAGS {
+        errors.push(ValidationFieldError {
+            field: "tags".to_string(),
+            message: format!("태그는 최대 {}개까지 보낼 수 있습니다.", MAX_TAGS),
+        });
+    }
+    for (key, value) in &event.tags {
+        if !is_valid_tag_key(key) {
+            errors.push(ValidationFieldError {
+                field: format!("tags.{}", key),
+                message: format!("태그 키는 {}자 이하의 영문, 숫자, `_.:-`만 쓸 수 있습니다.", MAX_TAG_KEY_LENGTH),
+            });
+        }
+        if value.is_empty() || value.chars().count() > MAX_TAG_VALUE_LENGTH || value.contains('\n') {
+            errors.push(ValidationFieldError {
+                field: format!("tags.{}", key),
+                message: format!("태그 값은 1~{}자의 한 줄 문자열이어야 합니다.", MAX_TAG_VALUE_LENGTH),
+            });
+        }
+    }
+
+    if event.user.as_ref().and_then(|user| user.email.as_ref()).is_some_and(|email| !email.contains('@')) {
+        errors.push(ValidationFieldError {
+            field: "user.email".to_string(),
+            message: "이메일 형식이 올바르지 않습니다.".to_string(),
+        });
+    }
+
+    if let Some(request) = &event.request {
+        if let Some(method) = request.method.as_ref().filter(|method| !HTTP_METHODS.contains(&method.to_uppercase().as_str())) {
+            errors.push(ValidationFieldError {
+                field: "request.method".to_string(),
+                message: format!("지원하지 않는 HTTP 메서드입니다: {}", method),
+            });
+        }
+        if request.url.as_ref().is_some_and(|url| url.trim().is_empty()) {
+            errors.push(ValidationFieldError {
+                field: "request.url".to_string(),
+                message: "URL은 비워둘 수 없습니다.".to_string(),
+            });
+        }
+    }
+
+    if event.contexts.as_ref().and_then(|contexts| contexts.runtime.as_ref()).is_some_and(|runtime| runtime.name.trim().is_empty()) {
+        errors.push(ValidationFieldError {
+            field: "contexts.runtime.name".to_string(),
+            message: "런타임 이름은 필수입니다.".to_string(),
+        });
+    }
 
-fn calculate_group_hash(message: &str, stack: &str) -> String {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(AppError::ValidationError(errors))
+    }
+}
+
+/// 그룹 해시에 넣을 상위 프레임 수. 그 아래 프레임이 달라도 같은 이슈로 묶는다.
+const GROUPING_FRAME_LIMIT: usize = 5;
+
+/// 이벤트를 보낸 클라이언트 IP. 프로젝트가 수집을 껐으면 `None`.
+fn client_ip(req: &HttpRequest, project: &project::Model) -> Option<IpAddr> {
+    if !project.collect_ip_address {
+        return None;
+    }
+
+    let peer = req.peer_addr()?.ip();
+    let mut trusted = TRUSTED_PROXIES.clone();
+    trusted.extend(trusted_proxies(project).iter().filter_map(|proxy| proxy.parse::<IpRange>().ok()));
+
+    let forwarded_for = req
+        .headers()
+        .get_all("x-forwarded-for")
+        .filter_map(|value| value.to_str().ok())
+        .collect::<Vec<_>>()
+        .join(",");
+    let forwarded_for = (!forwarded_for.is_empty()).then_some(forwarded_for.as_str());
+
+    Some(resolve_client_ip(peer, forwarded_for, &trusted))
+}
+
+/// 프로젝트에 등록된 신뢰 프록시 목록
+fn trusted_proxies(project: &project::Model) -> Vec<String> {
+    project
+        .trusted_proxies
+        .clone()
+        .and_then(|proxies| serde_json::from_value(proxies).ok())
+        .unwrap_or_default()
+}
+
+/// 스파이크 보호에 쓰는 이슈 키. 심볼리케이션 없이 원본 스택트레이스로 계산한다.
+pub fn spike_key(event: &EventReportRequest) -> String {
+    calculate_group_hash(&event.message, &parse_stacktrace(&event.stacktrace))
+}
+
+fn calculate_group_hash(message: &str, frames: &[StackFrame]) -> String {
     // 메시지에서 변수 부분 정규화 (숫자, ID 등 제거)
     let normalized_message = message
         .replace(|c: char| c.is_numeric(), "0")
         .replace(|c: char| c.is_ascii_hexdigit() && !c.is_numeric(), "X");
 
-    // 스택트레이스에서 중요 부분만 추출 (파일 경로, 라인 번호 제외)
-    let stack_lines: Vec<&str> = stack.lines().collect();
-    let mut important_stack = String::new();
-
-    // stack trace 처음 3줄만 사용
-    for i in 0..std::cmp::min(3, stack_lines.len()) {
-        if let Some(func_pos) = stack_lines[i].find("at ") {
-            if let Some(file_pos) = stack_lines[i][func_pos..].find(" (") {
-                important_stack.push_str(&stack_lines[i][func_pos..func_pos+file_pos]);
-                important_stack.push('\n');
-            } else {
-                important_stack.push_str(stack_lines[i]);
-                important_stack.push('\n');
-            }
-        }
-    }
+    // 라이브러리 프레임은 제외하고 앱 코드 프레임으로만 그룹핑한다.
+    // in-app 프레임이 하나도 없으면 전체 프레임을 사용한다.
+    let in_app_frames: Vec<&StackFrame> = frames.iter().filter(|frame| frame.in_app).collect();
+    let grouping_frames = if in_app_frames.is_empty() {
+        frames.iter().collect()
+    } else {
+        in_app_frames
+    };
 
     let mut hasher = Sha256::new();
     hasher.update(normalized_message);
-    hasher.update(important_stack);
+    for frame in grouping_frames.into_iter().take(GROUPING_FRAME_LIMIT) {
+        hasher.update(grouping_key(frame));
+        hasher.update("\n");
+    }
     let result = hasher.finalize();
     format!("{:x}", result)
 }
@@ -550,3 +959,115 @@ pub async fn check_event_in_project(
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn request(extra: Value) -> EventReportRequest {
+        let mut body = json!({
+            "message": "TypeError: x is undefined",
+            "stacktrace": "",
+            "appVersion": "1.0.0",
+            "timestamp": "2025-04-20T00:00:00Z",
+            "apiKey": "proj_test",
+        });
+        body.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(body).unwrap()
+    }
+
+    fn invalid_fields(event: &EventReportRequest) -> Vec<String> {
+        match validate_event_request(event) {
+            Ok(()) => Vec::new(),
+            Err(AppError::ValidationError(errors)) => errors.into_iter().map(|error| error.field).collect(),
+            Err(e) => panic!("unexpected {:?}", e),
+        }
+    }
+
+    fn breadcrumbs(count: usize) -> Value {
+        (0..count)
+            .map(|_| json!({ "timestamp": "2025-04-20T00:00:00Z", "category": "ui.click" }))
+            .collect()
+    }
+
+    fn tags(count: usize) -> Value {
+        (0..count).map(|i| (format!("tag{}", i), json!("value"))).collect::<serde_json::Map<_, _>>().into()
+    }
+
+    #[test]
+    fn limits_breadcrumb_count() {
+        assert!(invalid_fields(&request(json!({ "breadcrumbs": breadcrumbs(MAX_BREADCRUMBS) }))).is_empty());
+        assert_eq!(invalid_fields(&request(json!({ "breadcrumbs": breadcrumbs(MAX_BREADCRUMBS + 1) }))), ["breadcrumbs"]);
+
+        let blank = json!({ "breadcrumbs": [{ "timestamp": "2025-04-20T00:00:00Z", "category": " " }] });
+        assert_eq!(invalid_fields(&request(blank)), ["breadcrumbs[0].category"]);
+    }
+
+    #[test]
+    fn limits_tag_count_and_length() {
+        assert!(invalid_fields(&request(json!({ "tags": tags(MAX_TAGS) }))).is_empty());
+        assert_eq!(invalid_fields(&request(json!({ "tags": tags(MAX_TAGS + 1) }))), ["tags"]);
+
+        let key = "k".repeat(MAX_TAG_KEY_LENGTH);
+        assert!(invalid_fields(&request(json!({ "tags": { key: "v" } }))).is_empty());
+        let key = "k".repeat(MAX_TAG_KEY_LENGTH + 1);
+        assert_eq!(invalid_fields(&request(json!({ "tags": { key.clone(): "v" } }))), [format!("tags.{}", key)]);
+        assert_eq!(invalid_fields(&request(json!({ "tags": { "release version": "v" } }))), ["tags.release version"]);
+
+        // 값 길이는 바이트가 아니라 글자 수로 센다.
+        let value = "가".repeat(MAX_TAG_VALUE_LENGTH);
+        assert!(invalid_fields(&request(json!({ "tags": { "screen": value } }))).is_empty());
+        let value = "가".repeat(MAX_TAG_VALUE_LENGTH + 1);
+        assert_eq!(invalid_fields(&request(json!({ "tags": { "screen": value } }))), ["tags.screen"]);
+        assert_eq!(invalid_fields(&request(json!({ "tags": { "screen": "" } }))), ["tags.screen"]);
+        assert_eq!(invalid_fields(&request(json!({ "tags": { "screen": "a\nb" } }))), ["tags.screen"]);
+    }
+
+    #[test]
+    fn checks_user_email() {
+        assert!(invalid_fields(&request(json!({ "user": { "email": "jane@example.com" } }))).is_empty());
+        assert!(invalid_fields(&request(json!({ "user": { "id": "42" } }))).is_empty());
+        assert_eq!(invalid_fields(&request(json!({ "user": { "email": "jane" } }))), ["user.email"]);
+    }
+
+    #[test]
+    fn checks_request_method_and_url() {
+        for method in HTTP_METHODS {
+            assert!(invalid_fields(&request(json!({ "request": { "method": method } }))).is_empty(), "{method}");
+        }
+        assert!(invalid_fields(&request(json!({ "request": { "method": "post", "url": "/api" } }))).is_empty());
+        assert_eq!(invalid_fields(&request(json!({ "request": { "method": "FETCH" } }))), ["request.method"]);
+        assert_eq!(invalid_fields(&request(json!({ "request": { "url": " " } }))), ["request.url"]);
+    }
+
+    #[test]
+    fn requires_runtime_name() {
+        assert!(invalid_fields(&request(json!({ "contexts": { "runtime": { "name": "node", "version": "22" } } }))).is_empty());
+        assert!(invalid_fields(&request(json!({ "contexts": { "device": { "brand": "Apple" } } }))).is_empty());
+        assert_eq!(invalid_fields(&request(json!({ "contexts": { "runtime": { "name": "" } } }))), ["contexts.runtime.name"]);
+    }
+
+    #[test]
+    fn collects_every_invalid_field() {
+        let event = request(json!({
+            "message": " ",
+            "apiKey": "",
+            "user": { "email": "jane" },
+            "request": { "method": "FETCH" },
+        }));
+        assert_eq!(invalid_fields(&event), ["apiKey", "message", "user.email", "request.method"]);
+    }
+
+    #[test]
+    fn truncates_titles_by_character() {
+        let message = "한".repeat(150);
+        let title = issue_title(&message);
+
+        assert_eq!(title.chars().count(), 100);
+        assert!(title.starts_with(&"한".repeat(97)));
+        assert!(title.ends_with("..."));
+        assert_eq!(issue_title(&"😀".repeat(100)), "😀".repeat(100));
+        assert_eq!(issue_title("TypeError"), "TypeError");
+    }
+}
@@ -0,0 +1,603 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::LazyLock;
+use actix_web::http::header;
+use actix_web::{delete, get, post, web, HttpRequest, HttpResponse};
+use bytes::Bytes;
+use chrono::Utc;
+use futures_util::{stream, Stream, StreamExt};
+use sea_orm::{ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, ModelTrait, QueryFilter, QueryOrder, QuerySelect, Select, Set};
+use sea_query::Query;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tracing::{error, warn};
+use crate::api::event::filtered_events;
+use crate::api::issue::filtered_issues;
+use crate::api::project::{check_active_project, check_project_member};
+use crate::api::search::transaction_search_condition;
+use crate::entity::export_job::{self, ActiveModel as ExportJobActiveModel, Entity as ExportJobEntity, ExportFormat, ExportStatus, ExportTarget};
+use crate::entity::{event, issue, span, transaction};
+use crate::model::event::{EventQuery, EventReportListResponse};
+use crate::model::export::{ExportJobQuery, ExportJobResponse, ExportQuery};
+use crate::model::global_error::{AppError, ErrorCode, ValidationFieldError};
+use crate::model::issue::{IssueQuery, IssueResponse};
+use crate::model::span::{SpanResponse, TransactionListQuery};
+use crate::model::transaction::TransactionResponse;
+use crate::util::export::{csv_cell, csv_line};
+
+// 한 번에 읽어 보내는 행 수. 전체 결과를 메모리에 올리지 않고 ID 순으로 이어서 읽는다.
+const EXPORT_BATCH_SIZE: u64 = 500;
+const DOWNLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+// 백그라운드 내보내기 결과 파일을 둘 디렉터리 (`EXPORT_DIR`, 기본값: exports)
+static EXPORT_DIR: LazyLock<PathBuf> = LazyLock::new(|| {
+    env::var("EXPORT_DIR").unwrap_or_else(|_| "exports".to_string()).into()
+});
+
+// CSV 열. 각 목록 응답의 필드 이름을 그대로 쓴다.
+const EVENT_COLUMNS: [&str; 17] = [
+    "id", "timestamp", "message", "stacktrace", "appVersion", "groupHash", "issueId", "browser", "os",
+    "browserName", "osName", "deviceType", "countryCode", "hasReplay", "priority", "assignedTo", "status",
+];
+const ISSUE_COLUMNS: [&str; 15] = [
+    "id", "projectId", "title", "groupHash", "status", "count", "firstSeen", "lastSeen", "assignedTo",
+    "lastRelease", "resolvedInVersion", "resolvedInNextRelease", "resolvedAt", "createdAt", "updatedAt",
+];
+const TRANSACTION_COLUMNS: [&str; 12] = [
+    "id", "projectId", "traceId", "name", "kind", "startTimestamp", "endTimestamp", "durationMs", "environment",
+    "status", "tags", "createdAt",
+];
+const SPAN_COLUMNS: [&str; 26] = [
+    "id", "transactionId", "spanId", "parentSpanId", "name", "startTimestamp", "endTimestamp", "durationMs",
+    "httpMethod", "httpUrl", "httpStatusCode", "httpStatusText", "httpResponseContentLength", "httpHost",
+    "httpScheme", "httpUserAgent", "attributes", "kind", "statusCode", "statusMessage", "events", "links",
+    "resourceAttributes", "scopeName", "scopeVersion", "scopeAttributes",
+];
+
+/// 내보내기 결과 조각과 그 안의 행 수
+struct ExportChunk {
+    bytes: Bytes,
+    rows: i64,
+}
+
+type ExportStream = Pin<Box<dyn Stream<Item = Result<ExportChunk, AppError>> + Send>>;
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/events/export",
+    summary = "이벤트 내보내기",
+    description = "이벤트 목록 API와 같은 필터를 받아 CSV 또는 NDJSON으로 ID 순으로 스트리밍한다.",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("format" = Option<ExportFormat>, Query, description = "파일 형식 (csv, ndjson). 기본값: csv"),
+    ),
+    responses(
+        (status = 200, description = "내보내기 성공", content_type = "text/csv"),
+        (status = 400, description = "잘못된 필터", body = ValidationFieldError),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Export"
+)]
+#[get("/projects/{project_id}/events/export")]
+pub async fn export_events(
+    req: HttpRequest,
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    query: web::Query<ExportQuery>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    stream_export(&req, db.get_ref(), path.into_inner(), auth_user.into_inner(), ExportTarget::Events, query.format).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/issues/export",
+    summary = "이슈 내보내기",
+    description = "이슈 목록 API와 같은 필터를 받아 CSV 또는 NDJSON으로 ID 순으로 스트리밍한다.",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("format" = Option<ExportFormat>, Query, description = "파일 형식 (csv, ndjson). 기본값: csv"),
+    ),
+    responses(
+        (status = 200, description = "내보내기 성공", content_type = "text/csv"),
+        (status = 400, description = "잘못된 필터", body = ValidationFieldError),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Export"
+)]
+#[get("/projects/{project_id}/issues/export")]
+pub async fn export_issues(
+    req: HttpRequest,
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    query: web::Query<ExportQuery>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    stream_export(&req, db.get_ref(), path.into_inner(), auth_user.into_inner(), ExportTarget::Issues, query.format).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/transactions/export",
+    summary = "트랜잭션 내보내기",
+    description = "트랜잭션 목록 API와 같은 `query` 필터를 받아 CSV 또는 NDJSON으로 ID 순으로 스트리밍한다.",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("format" = Option<ExportFormat>, Query, description = "파일 형식 (csv, ndjson). 기본값: csv"),
+        ("query" = Option<String>, Query, description = "검색어 (예: environment:production duration:>500ms)"),
+    ),
+    responses(
+        (status = 200, description = "내보내기 성공", content_type = "text/csv"),
+        (status = 400, description = "잘못된 필터", body = ValidationFieldError),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Export"
+)]
+#[get("/projects/{project_id}/transactions/export")]
+pub async fn export_transactions(
+    req: HttpRequest,
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    query: web::Query<ExportQuery>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    stream_export(&req, db.get_ref(), path.into_inner(), auth_user.into_inner(), ExportTarget::Transactions, query.format).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/spans/export",
+    summary = "스팬 내보내기",
+    description = "`query` 필터에 맞는 트랜잭션의 스팬을 CSV 또는 NDJSON으로 ID 순으로 스트리밍한다.",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("format" = Option<ExportFormat>, Query, description = "파일 형식 (csv, ndjson). 기본값: csv"),
+        ("query" = Option<String>, Query, description = "트랜잭션 검색어 (예: name:/checkout duration:>2s)"),
+    ),
+    responses(
+        (status = 200, description = "내보내기 성공", content_type = "text/csv"),
+        (status = 400, description = "잘못된 필터", body = ValidationFieldError),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Export"
+)]
+#[get("/projects/{project_id}/spans/export")]
+pub async fn export_spans(
+    req: HttpRequest,
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    query: web::Query<ExportQuery>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    stream_export(&req, db.get_ref(), path.into_inner(), auth_user.into_inner(), ExportTarget::Spans, query.format).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/exports",
+    summary = "백그라운드 내보내기 요청",
+    description = "큰 내보내기를 백그라운드 작업으로 실행한다. `target`, `format` 외의 쿼리 파라미터는 해당 목록 API의 필터로 쓴다. \
+                   작업이 끝나면 `downloadUrl`에서 결과 파일을 받는다.",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("target" = ExportTarget, Query, description = "내보낼 대상 (events, issues, transactions, spans)"),
+        ("format" = Option<ExportFormat>, Query, description = "파일 형식 (csv, ndjson). 기본값: csv"),
+    ),
+    responses(
+        (status = 202, description = "내보내기 작업 등록", body = ExportJobResponse),
+        (status = 400, description = "잘못된 필터", body = ValidationFieldError),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Export"
+)]
+#[post("/projects/{project_id}/exports")]
+pub async fn create_export_job(
+    req: HttpRequest,
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    query: web::Query<ExportJobQuery>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let ExportJobQuery { target, format } = query.into_inner();
+
+    check_active_project(db.get_ref(), project_id).await?;
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let filters = req
+        .query_string()
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .filter(|pair| !matches!(pair.split('=').next().unwrap_or_default(), "target" | "format"))
+        .collect::<Vec<_>>()
+        .join("&");
+    // 필터가 잘못됐으면 작업을 만들기 전에 알려준다.
+    let rows = export_source(db.get_ref(), project_id, user_id, target, format, &filters).await?;
+
+    let job = ExportJobActiveModel {
+        project_id: Set(project_id),
+        user_id: Set(user_id),
+        target: Set(target),
+        format: Set(format),
+        filters: Set(filters),
+        status: Set(ExportStatus::Pending),
+        ..Default::default()
+    }
+        .insert(db.get_ref())
+        .await?;
+
+    tokio::spawn(run_export_job(db.get_ref().clone(), job.clone(), rows));
+
+    Ok(HttpResponse::Accepted().json(ExportJobResponse::from(job)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/exports",
+    summary = "내 내보내기 작업 목록 조회",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+    ),
+    responses(
+        (status = 200, description = "내보내기 작업 목록 조회 성공", body = [ExportJobResponse]),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Export"
+)]
+#[get("/projects/{project_id}/exports")]
+pub async fn list_export_jobs(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let jobs = ExportJobEntity::find()
+        .filter(export_job::Column::ProjectId.eq(project_id))
+        .filter(export_job::Column::UserId.eq(user_id))
+        .order_by_desc(export_job::Column::Id)
+        .all(db.get_ref())
+        .await?;
+
+    let responses: Vec<ExportJobResponse> = jobs
+        .into_iter()
+        .map(ExportJobResponse::from)
+        .collect();
+
+    Ok(HttpResponse::Ok().json(responses))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/exports/{id}",
+    summary = "내보내기 작업 조회",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("id" = i32, Path, description = "내보내기 작업 ID"),
+    ),
+    responses(
+        (status = 200, description = "내보내기 작업 조회 성공", body = ExportJobResponse),
+        (status = 404, description = "내보내기 작업 없음"),
+    ),
+    tag = "Export"
+)]
+#[get("/projects/{project_id}/exports/{id}")]
+pub async fn get_export_job(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, job_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    let job = find_export_job(db.get_ref(), project_id, user_id, job_id).await?;
+
+    Ok(HttpResponse::Ok().json(ExportJobResponse::from(job)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/exports/{id}/download",
+    summary = "내보내기 파일 다운로드",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("id" = i32, Path, description = "내보내기 작업 ID"),
+    ),
+    responses(
+        (status = 200, description = "다운로드 성공", content_type = "text/csv"),
+        (status = 400, description = "아직 끝나지 않은 작업"),
+        (status = 404, description = "내보내기 작업 없음"),
+    ),
+    tag = "Export"
+)]
+#[get("/projects/{project_id}/exports/{id}/download")]
+pub async fn download_export(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, job_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    let job = find_export_job(db.get_ref(), project_id, user_id, job_id).await?;
+
+    let file_path = match (&job.status, &job.file_path) {
+        (ExportStatus::Completed, Some(file_path)) => file_path,
+        _ => return Err(AppError::bad_request(ErrorCode::ExportNotReady)),
+    };
+    let file = tokio::fs::File::open(file_path).await.map_err(|e| {
+        warn!("내보내기 파일을 열 수 없습니다 ({}): {}", file_path, e);
+        AppError::not_found(ErrorCode::ExportJobNotFound)
+    })?;
+
+    let body = stream::try_unfold(file, |mut file| async move {
+        let mut buffer = vec![0; DOWNLOAD_CHUNK_SIZE];
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            return Ok::<_, std::io::Error>(None);
+        }
+        buffer.truncate(read);
+        Ok(Some((Bytes::from(buffer), file)))
+    });
+
+    Ok(HttpResponse::Ok()
+        .content_type(job.format.content_type())
+        .insert_header((header::CONTENT_DISPOSITION, attachment(project_id, job.target, job.format)))
+        .streaming(body))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/projects/{project_id}/exports/{id}",
+    summary = "내보내기 작업 삭제",
+    description = "작업과 결과 파일을 지운다.",
+    responses(
+        (status = 204, description = "내보내기 작업 삭제 성공"),
+        (status = 404, description = "내보내기 작업 없음"),
+    ),
+    tag = "Export"
+)]
+#[delete("/projects/{project_id}/exports/{id}")]
+pub async fn delete_export_job(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, job_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let job = find_export_job(db.get_ref(), project_id, user_id, job_id).await?;
+    if let Some(file_path) = &job.file_path
+        && let Err(e) = tokio::fs::remove_file(file_path).await
+    {
+        warn!("내보내기 파일을 지울 수 없습니다 ({}): {}", file_path, e);
+    }
+    job.delete(db.get_ref()).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn find_export_job(
+    db: &DatabaseConnection,
+    project_id: i32,
+    user_id: i32,
+    job_id: i32,
+) -> Result<export_job::Model, AppError> {
+    ExportJobEntity::find_by_id(job_id)
+        .filter(export_job::Column::ProjectId.eq(project_id))
+        .filter(export_job::Column::UserId.eq(user_id))
+        .one(db)
+        .await?
+        .ok_or_else(|| AppError::not_found(ErrorCode::ExportJobNotFound))
+}
+
+async fn stream_export(
+    req: &HttpRequest,
+    db: &DatabaseConnection,
+    project_id: i32,
+    user_id: i32,
+    target: ExportTarget,
+    format: ExportFormat,
+) -> Result<HttpResponse, AppError> {
+    check_project_member(db, project_id, user_id).await?;
+
+    let rows = export_source(db, project_id, user_id, target, format, req.query_string()).await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(format.content_type())
+        .insert_header((header::CONTENT_DISPOSITION, attachment(project_id, target, format)))
+        .streaming(rows.map(|chunk| chunk.map(|chunk| chunk.bytes))))
+}
+
+fn attachment(project_id: i32, target: ExportTarget, format: ExportFormat) -> String {
+    format!("attachment; filename=\"{}-{}.{}\"", target.as_str(), project_id, format.extension())
+}
+
+// 목록 API의 쿼리 문자열로 필터를 만들고, 읽어 나갈 스트림을 준비한다.
+async fn export_source(
+    db: &DatabaseConnection,
+    project_id: i32,
+    user_id: i32,
+    target: ExportTarget,
+    format: ExportFormat,
+    filters: &str,
+) -> Result<ExportStream, AppError> {
+    let rows = match target {
+        ExportTarget::Events => {
+            let query: EventQuery = parse_filters(filters)?;
+            let (select, _) = filtered_events(db, project_id, user_id, &query).await?;
+            export_stream(db.clone(), select, event::Column::Id, |event| event.id, format, &EVENT_COLUMNS, EventReportListResponse::from)
+        }
+        ExportTarget::Issues => {
+            let query: IssueQuery = parse_filters(filters)?;
+            let select = filtered_issues(db, project_id, user_id, &query).await?;
+            export_stream(db.clone(), select, issue::Column::Id, |issue| issue.id, format, &ISSUE_COLUMNS, IssueResponse::from)
+        }
+        ExportTarget::Transactions => {
+            let query: TransactionListQuery = parse_filters(filters)?;
+            let select = transaction::Entity::find().filter(transaction_condition(project_id, &query)?);
+            export_stream(db.clone(), select, transaction::Column::Id, |transaction| transaction.id, format, &TRANSACTION_COLUMNS, TransactionResponse::from)
+        }
+        ExportTarget::Spans => {
+            let query: TransactionListQuery = parse_filters(filters)?;
+            let transaction_ids = Query::select()
+                .column(transaction::Column::Id)
+                .from(transaction::Entity)
+                .cond_where(transaction_condition(project_id, &query)?)
+                .to_owned();
+            let select = span::Entity::find().filter(span::Column::TransactionId.in_subquery(transaction_ids));
+            export_stream(db.clone(), select, span::Column::Id, |span| span.id, format, &SPAN_COLUMNS, SpanResponse::from)
+        }
+    };
+
+    Ok(rows)
+}
+
+fn parse_filters<T: DeserializeOwned>(filters: &str) -> Result<T, AppError> {
+    web::Query::<T>::from_query(filters)
+        .map(web::Query::into_inner)
+        .map_err(|e| AppError::ValidationError(vec![ValidationFieldError {
+            field: "filters".to_string(),
+            message: e.to_string(),
+        }]))
+}
+
+fn transaction_condition(project_id: i32, query: &TransactionListQuery) -> Result<Condition, AppError> {
+    let mut condition = Condition::all().add(transaction::Column::ProjectId.eq(project_id));
+    if let Some(search_query) = query.query.as_deref().filter(|q| !q.trim().is_empty()) {
+        condition = condition.add(transaction_search_condition(search_query)?);
+    }
+    Ok(condition)
+}
+
+// 마지막으로 보낸 ID 뒤에서부터 한 묶음씩 읽어 바로 내보낸다.
+fn export_stream<E, R>(
+    db: DatabaseConnection,
+    select: Select<E>,
+    id_column: E::Column,
+    id_of: fn(&E::Model) -> i32,
+    format: ExportFormat,
+    columns: &'static [&'static str],
+    to_row: fn(E::Model) -> R,
+) -> ExportStream
+where
+    E: EntityTrait,
+    R: Serialize + 'static,
+{
+    let header = (format == ExportFormat::Csv)
+        .then(|| Ok(ExportChunk { bytes: Bytes::from(csv_line(columns)), rows: 0 }));
+
+    let batches = stream::try_unfold(Some(0), move |after| {
+        let (db, select) = (db.clone(), select.clone());
+        async move {
+            let Some(after) = after else {
+                return Ok(None);
+            };
+
+            let models = select
+                .filter(id_column.gt(after))
+                .order_by_asc(id_column)
+                .limit(EXPORT_BATCH_SIZE)
+                .all(&db)
+                .await?;
+            let Some(last_id) = models.last().map(id_of) else {
+                return Ok(None);
+            };
+            let next = (models.len() as u64 == EXPORT_BATCH_SIZE).then_some(last_id);
+
+            let rows = models.len() as i64;
+            let mut out = String::new();
+            for model in models {
+                encode_row(&mut out, format, columns, &to_row(model))?;
+            }
+
+            Ok(Some((ExportChunk { bytes: Bytes::from(out), rows }, next)))
+        }
+    });
+
+    Box::pin(stream::iter(header).chain(batches))
+}
+
+fn encode_row<R: Serialize>(out: &mut String, format: ExportFormat, columns: &[&str], row: &R) -> Result<(), AppError> {
+    let encode_error = |e: serde_json::Error| {
+        error!("내보내기 행을 직렬화할 수 없습니다: {}", e);
+        AppError::internal_error(ErrorCode::InternalError)
+    };
+
+    match format {
+        ExportFormat::Csv => {
+            let value = serde_json::to_value(row).map_err(encode_error)?;
+            out.push_str(&csv_line(columns.iter().map(|column| csv_cell(value.get(column)))));
+        }
+        ExportFormat::Ndjson => {
+            out.push_str(&serde_json::to_string(row).map_err(encode_error)?);
+            out.push('\n');
+        }
+    }
+
+    Ok(())
+}
+
+async fn run_export_job(db: DatabaseConnection, job: export_job::Model, rows: ExportStream) {
+    let path = EXPORT_DIR.join(format!("export-{}.{}", job.id, job.format.extension()));
+
+    let mut running: ExportJobActiveModel = job.into();
+    running.status = Set(ExportStatus::Running);
+    let job = match running.update(&db).await {
+        Ok(job) => job,
+        Err(e) => {
+            error!("내보내기 작업 상태를 바꿀 수 없습니다: {}", e);
+            return;
+        }
+    };
+
+    let result = write_export(&path, rows).await;
+
+    let job_id = job.id;
+    let mut finished: ExportJobActiveModel = job.into();
+    finished.completed_at = Set(Some(Utc::now()));
+    match result {
+        Ok((row_count, file_size)) => {
+            finished.status = Set(ExportStatus::Completed);
+            finished.row_count = Set(Some(row_count));
+            finished.file_size = Set(Some(file_size));
+            finished.file_path = Set(Some(path.to_string_lossy().into_owned()));
+        }
+        Err(message) => {
+            error!("내보내기 작업 {} 실패: {}", job_id, message);
+            let _ = tokio::fs::remove_file(&path).await;
+            finished.status = Set(ExportStatus::Failed);
+            finished.error = Set(Some(message));
+        }
+    }
+
+    if let Err(e) = finished.update(&db).await {
+        error!("내보내기 작업 {} 결과를 저장할 수 없습니다: {}", job_id, e);
+    }
+}
+
+async fn write_export(path: &Path, mut rows: ExportStream) -> Result<(i64, i64), String> {
+    tokio::fs::create_dir_all(EXPORT_DIR.as_path()).await.map_err(|e| e.to_string())?;
+    let mut file = tokio::fs::File::create(path).await.map_err(|e| e.to_string())?;
+
+    let (mut row_count, mut file_size) = (0, 0);
+    while let Some(chunk) = rows.next().await {
+        let chunk = chunk.map_err(|e| e.to_string())?;
+        file.write_all(&chunk.bytes).await.map_err(|e| e.to_string())?;
+        row_count += chunk.rows;
+        file_size += chunk.bytes.len() as i64;
+    }
+    file.flush().await.map_err(|e| e.to_string())?;
+
+    Ok((row_count, file_size))
+}
@@ -0,0 +1,645 @@
+use std::collections::HashMap;
+use actix_web::{get, put, web, HttpRequest, HttpResponse};
+use chrono::{DateTime, Utc};
+use sea_orm::{ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select, TransactionTrait, UpdateMany};
+use sea_query::Expr;
+use serde_json::json;
+use crate::api::pagination::{fetch_cursor_page, parse_cursor, Keyset};
+use crate::api::project::check_project_member;
+use crate::api::search::issue_search_condition;
+use crate::api::stats::issue_sparklines;
+use crate::entity::event::{self, Entity as EventEntity, EventStatus};
+use crate::entity::issue::{self, Entity as IssueEntity, IssueStatus};
+use crate::entity::issue_activity::{self, ActiveModel as IssueActivityActiveModel, ActivityKind, Entity as IssueActivityEntity};
+use crate::entity::project_member::{self, Entity as ProjectMemberEntity};
+use crate::model::common::PaginationMode;
+use crate::model::event::{EventReportListResponse, EventReportResponse, PaginatedResponse};
+use crate::model::global_error::{AppError, ErrorCode, ValidationFieldError};
+use crate::model::issue::{IssueActivityResponse, IssueAssignee, IssueDetailResponse, IssueQuery, IssueResponse, IssueSort, IssueStatusDto, SortOrder};
+use crate::model::stats::StatsPeriod;
+use crate::util::cursor::SortKey;
+
+// 이슈 상세에서 보여줄 최근 이벤트 수
+const RECENT_EVENT_LIMIT: u64 = 10;
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/issues",
+    summary = "프로젝트 이슈 목록 조회",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("status" = Option<IssueStatus>, Query, description = "이슈 상태"),
+        ("assignedTo" = Option<i32>, Query, description = "담당자 ID"),
+        ("search" = Option<String>, Query, description = "제목 검색어"),
+        ("query" = Option<String>, Query, description = "구조화된 검색어 (예: is:unresolved assigned:me release:>=1.4 age:-24h)"),
+        ("startDate" = Option<String>, Query, description = "마지막 발생 시작일 (ISO8601)"),
+        ("endDate" = Option<String>, Query, description = "마지막 발생 종료일 (ISO8601)"),
+        ("sort" = Option<IssueSort>, Query, description = "정렬 기준 (lastSeen, firstSeen, count)"),
+        ("order" = Option<SortOrder>, Query, description = "정렬 방향 (asc, desc)"),
+        ("pagination" = Option<PaginationMode>, Query, description = "페이지 방식 (offset, cursor). cursor는 전체 개수를 세지 않는다"),
+        ("cursor" = Option<String>, Query, description = "이전 응답의 nextCursor/prevCursor. 주면 cursor 방식으로 조회한다"),
+        ("statsPeriod" = Option<StatsPeriod>, Query, description = "sparkline 기간 (24h: 1시간 단위 24개, 14d: 하루 단위 14개). 기본값: 24h"),
+        ("page" = Option<i32>, Query, description = "페이지 번호 (offset 방식)"),
+        ("pageSize" = Option<i32>, Query, description = "페이지 크기"),
+    ),
+    responses(
+        (status = 200, description = "이슈 목록 조회 성공", body = Vec<IssueResponse>),
+        (status = 400, description = "잘못된 커서 또는 검색어", body = ValidationFieldError),
+    ),
+    tag = "Issue"
+)]
+#[get("/projects/{project_id}/issues")]
+pub async fn list_project_issues(
+    req: HttpRequest,
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    query: web::Query<IssueQuery>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let query = query.into_inner();
+    let cursor = parse_cursor(query.cursor.as_deref())?;
+    let (pagination, sort, order) = (query.pagination, query.sort, query.order);
+    let stats_period = query.stats_period.unwrap_or_default();
+
+    let page = query.page.unwrap_or(1).max(1);
+    let page_size = query.page_size.unwrap_or(20).min(100);
+    let offset = (page - 1) * page_size;
+
+    let query = filtered_issues(db.get_ref(), project_id, user_id, &query).await?;
+
+    let sort = sort.unwrap_or_default();
+    let sort_column = sort_column(sort);
+    let order = sort_order(order.unwrap_or_default());
+
+    if pagination == Some(PaginationMode::Cursor) || cursor.is_some() {
+        let page = fetch_cursor_page(
+            db.get_ref(),
+            query,
+            Keyset { sort_column, id_column: issue::Column::Id, order },
+            cursor,
+            page_size,
+            |issue| {
+                let key = match sort {
+                    IssueSort::LastSeen => SortKey::Time(issue.last_seen),
+                    IssueSort::FirstSeen => SortKey::Time(issue.first_seen),
+                    IssueSort::Count => SortKey::Number(issue.count as i64),
+                };
+                (key, issue.id)
+            },
+        )
+        .await?;
+        let issue_ids = page.items.iter().map(|issue| issue.id).collect::<Vec<_>>();
+        let mut sparklines = issue_sparklines(db.get_ref(), &issue_ids, stats_period).await?;
+        return Ok(page.respond(&req, page_size, |issue| with_sparkline(issue, &mut sparklines)));
+    }
+
+    let total_elements = IssueEntity::find()
+        .filter(issue::Column::ProjectId.eq(project_id))
+        .count(db.get_ref())
+        .await?;
+    let filtered_elements = query.clone().count(db.get_ref()).await?;
+
+    let issues = query
+        .order_by(sort_column, order.clone())
+        .order_by(issue::Column::Id, order)
+        .offset(Some(offset as u64))
+        .limit(Some(page_size as u64))
+        .all(db.get_ref())
+        .await?;
+
+    let issue_ids = issues.iter().map(|issue| issue.id).collect::<Vec<_>>();
+    let mut sparklines = issue_sparklines(db.get_ref(), &issue_ids, stats_period).await?;
+
+    let response = PaginatedResponse {
+        content: issues
+            .into_iter()
+            .map(|issue| with_sparkline(issue, &mut sparklines))
+            .collect::<Vec<_>>(),
+        page,
+        page_size,
+        total_elements,
+        filtered_elements,
+        total_pages: ((filtered_elements as f64) / (page_size as f64)).ceil() as u32,
+        has_next: (offset + page_size) < (filtered_elements as u32),
+    };
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+fn sort_column(sort: IssueSort) -> issue::Column {
+    match sort {
+        IssueSort::LastSeen => issue::Column::LastSeen,
+        IssueSort::FirstSeen => issue::Column::FirstSeen,
+        IssueSort::Count => issue::Column::Count,
+    }
+}
+
+fn sort_order(order: SortOrder) -> sea_orm::Order {
+    match order {
+        SortOrder::Asc => sea_orm::Order::Asc,
+        SortOrder::Desc => sea_orm::Order::Desc,
+    }
+}
+
+fn with_sparkline(issue: issue::Model, sparklines: &mut HashMap<i32, Vec<i64>>) -> IssueResponse {
+    let sparkline = sparklines.remove(&issue.id);
+    IssueResponse { sparkline, ..IssueResponse::from(issue) }
+}
+
+/// 이슈 목록과 내보내기가 함께 쓰는 필터
+pub async fn filtered_issues(
+    db: &DatabaseConnection,
+    project_id: i32,
+    user_id: i32,
+    query: &IssueQuery,
+) -> Result<Select<IssueEntity>, AppError> {
+    let mut select = issue_filters(project_id, query);
+    if let Some(search_query) = query.query.as_deref().filter(|q| !q.trim().is_empty()) {
+        select = select.filter(issue_search_condition(db, project_id, user_id, search_query).await?);
+    }
+
+    Ok(select)
+}
+
+/// 검색어(`query`)를 뺀, DB 조회 없이 만들 수 있는 필터
+fn issue_filters(project_id: i32, query: &IssueQuery) -> Select<IssueEntity> {
+    let mut select = IssueEntity::find()
+        .filter(issue::Column::ProjectId.eq(project_id));
+
+    if let Some(status) = query.status {
+        select = select.filter(issue::Column::Status.eq(status));
+    }
+    if let Some(assigned_to) = query.assigned_to {
+        select = select.filter(issue::Column::AssignedTo.eq(assigned_to));
+    }
+    if let Some(search_term) = &query.search {
+        select = select.filter(issue::Column::Title.like(format!("%{}%", search_term)));
+    }
+    if let Some(start) = query.start_date {
+        select = select.filter(issue::Column::LastSeen.gte(start));
+    }
+    if let Some(end) = query.end_date {
+        select = select.filter(issue::Column::LastSeen.lte(end));
+    }
+
+    select
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/issues/{id}",
+    summary = "프로젝트 이슈 상세 조회",
+    responses(
+        (status = 200, description = "이슈 상세 조회 성공", body = IssueDetailResponse),
+        (status = 404, description = "이슈 없음"),
+    ),
+    tag = "Issue"
+)]
+#[get("/projects/{project_id}/issues/{id}")]
+pub async fn get_project_issue(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, issue_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    let issue = find_issue(db.get_ref(), project_id, issue_id).await?;
+
+    let recent_events = EventEntity::find()
+        .filter(event::Column::IssueId.eq(issue.id))
+        .order_by_desc(event::Column::CreatedAt)
+        .order_by_desc(event::Column::Id)
+        .limit(RECENT_EVENT_LIMIT)
+        .all(db.get_ref())
+        .await?;
+
+    let latest_event = recent_events
+        .first()
+        .cloned()
+        .map(EventReportResponse::from);
+
+    Ok(HttpResponse::Ok().json(IssueDetailResponse {
+        issue: IssueResponse::from(issue),
+        latest_event,
+        recent_events: recent_events
+            .into_iter()
+            .map(EventReportListResponse::from)
+            .collect(),
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/issues/status",
+    summary = "이슈 상태 일괄 설정",
+    request_body = IssueStatusDto,
+    responses(
+        (status = 200, description = "상태 일괄 설정 성공", body = [IssueResponse]),
+        (status = 400, description = "잘못된 요청"),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Issue"
+)]
+#[put("/projects/{project_id}/issues/status")]
+pub async fn set_issue_status(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    auth_user: web::ReqData<i32>,
+    body: web::Json<IssueStatusDto>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let IssueStatusDto { issue_ids, status, resolved_in_version, resolved_in_next_release } = body.into_inner();
+
+    validate_resolution(status, resolved_in_version.as_deref(), resolved_in_next_release)?;
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    check_issue_in_project(db.get_ref(), project_id, &issue_ids).await?;
+
+    let now = Utc::now();
+    let txn = db.begin().await?;
+
+    let previous = IssueEntity::find()
+        .filter(issue::Column::Id.is_in(issue_ids.clone()))
+        .all(&txn)
+        .await?;
+
+    status_update(status, resolved_in_version.clone(), resolved_in_next_release, now)
+        .filter(issue::Column::Id.is_in(issue_ids.clone()))
+        .exec(&txn)
+        .await?;
+
+    let activities = previous
+        .iter()
+        .filter(|issue| issue.status != status)
+        .map(|issue| IssueActivityActiveModel::new(
+            issue.id,
+            project_id,
+            ActivityKind::StatusChanged,
+            Some(user_id),
+            Some(json!({
+                "from": issue.status,
+                "to": status,
+                "resolvedInVersion": resolved_in_version,
+                "resolvedInNextRelease": resolved_in_next_release,
+            })),
+        ))
+        .collect::<Vec<_>>();
+    if !activities.is_empty() {
+        IssueActivityEntity::insert_many(activities).exec(&txn).await?;
+    }
+
+    if let Some(event_status) = cascaded_event_status(status) {
+        EventEntity::update_many()
+            .col_expr(event::Column::Status, Expr::value(event_status))
+            .filter(event::Column::IssueId.is_in(issue_ids.clone()))
+            .exec(&txn)
+            .await?;
+    }
+
+    txn.commit().await?;
+
+    let responses = find_issues(db.get_ref(), project_id, issue_ids).await?;
+    Ok(HttpResponse::Ok().json(responses))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/issues/assignee",
+    summary = "이슈 담당자 일괄 설정",
+    request_body = IssueAssignee,
+    responses(
+        (status = 200, description = "담당자 일괄 설정 성공", body = [IssueResponse]),
+        (status = 400, description = "잘못된 요청"),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Issue"
+)]
+#[put("/projects/{project_id}/issues/assignee")]
+pub async fn set_issue_assignee(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    auth_user: web::ReqData<i32>,
+    body: web::Json<IssueAssignee>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let IssueAssignee { issue_ids, assigned_to } = body.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    if let Some(uid) = assigned_to {
+        let is_member = ProjectMemberEntity::find()
+            .filter(
+                project_member::Column::ProjectId.eq(project_id)
+                    .and(project_member::Column::UserId.eq(uid)),
+            )
+            .one(db.get_ref())
+            .await?;
+        if is_member.is_none() {
+            return Err(AppError::bad_request(ErrorCode::InvalidAssignee));
+        }
+    }
+
+    check_issue_in_project(db.get_ref(), project_id, &issue_ids).await?;
+
+    let txn = db.begin().await?;
+
+    let previous = IssueEntity::find()
+        .filter(issue::Column::Id.is_in(issue_ids.clone()))
+        .all(&txn)
+        .await?;
+
+    IssueEntity::update_many()
+        .col_expr(issue::Column::AssignedTo, Expr::value(assigned_to))
+        .col_expr(issue::Column::UpdatedAt, Expr::value(Utc::now()))
+        .filter(issue::Column::Id.is_in(issue_ids.clone()))
+        .exec(&txn)
+        .await?;
+
+    EventEntity::update_many()
+        .col_expr(event::Column::AssignedTo, Expr::value(assigned_to))
+        .filter(event::Column::IssueId.is_in(issue_ids.clone()))
+        .exec(&txn)
+        .await?;
+
+    let activities = previous
+        .iter()
+        .filter(|issue| issue.assigned_to != assigned_to)
+        .map(|issue| IssueActivityActiveModel::new(
+            issue.id,
+            project_id,
+            ActivityKind::Assigned,
+            Some(user_id),
+            Some(json!({ "from": issue.assigned_to, "to": assigned_to })),
+        ))
+        .collect::<Vec<_>>();
+    if !activities.is_empty() {
+        IssueActivityEntity::insert_many(activities).exec(&txn).await?;
+    }
+
+    txn.commit().await?;
+
+    let responses = find_issues(db.get_ref(), project_id, issue_ids).await?;
+    Ok(HttpResponse::Ok().json(responses))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/issues/{issue_id}/activities",
+    summary = "이슈 활동 이력 조회",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("issue_id" = i32, Path, description = "이슈 ID"),
+    ),
+    responses(
+        (status = 200, description = "활동 이력 조회 성공", body = [IssueActivityResponse]),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "이슈 없음"),
+    ),
+    tag = "Issue"
+)]
+#[get("/projects/{project_id}/issues/{issue_id}/activities")]
+pub async fn get_issue_activities(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, issue_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    let issue = find_issue(db.get_ref(), project_id, issue_id).await?;
+
+    let activities = IssueActivityEntity::find()
+        .filter(issue_activity::Column::IssueId.eq(issue.id))
+        .order_by_desc(issue_activity::Column::CreatedAt)
+        .all(db.get_ref())
+        .await?;
+
+    let responses: Vec<IssueActivityResponse> = activities
+        .into_iter()
+        .map(IssueActivityResponse::from)
+        .collect();
+
+    Ok(HttpResponse::Ok().json(responses))
+}
+
+/// 이슈 상태와 해결 정보를 함께 바꾸는 update. resolved 가 아니면 해결 정보를 지운다.
+fn status_update(
+    status: IssueStatus,
+    resolved_in_version: Option<String>,
+    resolved_in_next_release: bool,
+    now: DateTime<Utc>,
+) -> UpdateMany<IssueEntity> {
+    let update = IssueEntity::update_many()
+        .col_expr(issue::Column::Status, Expr::value(status))
+        .col_expr(issue::Column::UpdatedAt, Expr::value(now));
+
+    // "다음 릴리즈에서 해결" 은 현재까지 본 가장 최신 릴리즈를 기준으로 삼는다.
+    if status == IssueStatus::Resolved {
+        let resolved_in = if resolved_in_next_release {
+            Expr::col(issue::Column::LastRelease).into()
+        } else {
+            Expr::value(resolved_in_version)
+        };
+        update
+            .col_expr(issue::Column::ResolvedInVersion, resolved_in)
+            .col_expr(issue::Column::ResolvedInNextRelease, Expr::value(resolved_in_next_release))
+            .col_expr(issue::Column::ResolvedAt, Expr::value(now))
+    } else {
+        update
+            .col_expr(issue::Column::ResolvedInVersion, Expr::value(Option::<String>::None))
+            .col_expr(issue::Column::ResolvedInNextRelease, Expr::value(false))
+            .col_expr(issue::Column::ResolvedAt, Expr::value(Option::<DateTime<Utc>>::None))
+    }
+}
+
+/// 이슈 단위로 트리아지하고, 이벤트 상태는 이슈 상태를 따라간다. ignored 는 이벤트 상태를 건드리지 않는다.
+fn cascaded_event_status(status: IssueStatus) -> Option<EventStatus> {
+    match status {
+        IssueStatus::Resolved => Some(EventStatus::RESOLVED),
+        IssueStatus::Open | IssueStatus::InProgress | IssueStatus::Regressed => Some(EventStatus::UNRESOLVED),
+        IssueStatus::Ignored => None,
+    }
+}
+
+fn validate_resolution(
+    status: IssueStatus,
+    resolved_in_version: Option<&str>,
+    resolved_in_next_release: bool,
+) -> Result<(), AppError> {
+    let mut errors = Vec::new();
+
+    if status != IssueStatus::Resolved && (resolved_in_version.is_some() || resolved_in_next_release) {
+        errors.push(ValidationFieldError {
+            field: "status".to_string(),
+            message: "해결 버전은 resolved 상태에서만 지정할 수 있습니다.".to_string(),
+        });
+    }
+
+    if resolved_in_version.is_some() && resolved_in_next_release {
+        errors.push(ValidationFieldError {
+            field: "resolvedInNextRelease".to_string(),
+            message: "해결 버전과 다음 릴리즈 해결은 함께 지정할 수 없습니다.".to_string(),
+        });
+    }
+
+    if resolved_in_version.is_some_and(|version| version.trim().is_empty()) {
+        errors.push(ValidationFieldError {
+            field: "resolvedInVersion".to_string(),
+            message: "해결 버전이 비어 있습니다.".to_string(),
+        });
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(AppError::ValidationError(errors))
+    }
+}
+
+pub async fn find_issue(
+    db: &DatabaseConnection,
+    project_id: i32,
+    issue_id: i32,
+) -> Result<issue::Model, AppError> {
+    IssueEntity::find()
+        .filter(
+            Condition::all()
+                .add(issue::Column::Id.eq(issue_id))
+                .add(issue::Column::ProjectId.eq(project_id))
+        )
+        .one(db)
+        .await?
+        .ok_or_else(|| AppError::not_found(ErrorCode::IssueNotFound))
+}
+
+async fn find_issues(
+    db: &DatabaseConnection,
+    project_id: i32,
+    issue_ids: Vec<i32>,
+) -> Result<Vec<IssueResponse>, AppError> {
+    let issues = IssueEntity::find()
+        .filter(issue::Column::ProjectId.eq(project_id))
+        .filter(issue::Column::Id.is_in(issue_ids))
+        .all(db)
+        .await?;
+
+    Ok(issues.into_iter().map(IssueResponse::from).collect())
+}
+
+pub async fn check_issue_in_project(
+    db: &DatabaseConnection,
+    project_id: i32,
+    issue_ids: &[i32],
+) -> Result<(), AppError> {
+    let count = IssueEntity::find()
+        .filter(issue::Column::ProjectId.eq(project_id))
+        .filter(issue::Column::Id.is_in(issue_ids.to_vec()))
+        .count(db)
+        .await?;
+
+    if count as usize != issue_ids.len() {
+        Err(AppError::bad_request(ErrorCode::InvalidIssue))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use sea_orm::{DbBackend, QueryTrait};
+
+    fn parse(query: &str) -> IssueQuery {
+        web::Query::<IssueQuery>::from_query(query).unwrap().into_inner()
+    }
+
+    #[test]
+    fn builds_filters_from_query_string() {
+        let query = parse("status=resolved&assignedTo=3&search=timeout&startDate=2025-04-01T00:00:00Z&endDate=2025-04-02T00:00:00Z");
+        let sql = issue_filters(7, &query).build(DbBackend::MySql).to_string();
+
+        assert!(sql.contains("`issues`.`project_id` = 7"), "{sql}");
+        assert!(sql.contains("`issues`.`status` = 'resolved'"), "{sql}");
+        assert!(sql.contains("`issues`.`assigned_to` = 3"), "{sql}");
+        assert!(sql.contains("`issues`.`title` LIKE '%timeout%'"), "{sql}");
+        assert!(sql.contains("`issues`.`last_seen` >= '2025-04-01 00:00:00"), "{sql}");
+        assert!(sql.contains("`issues`.`last_seen` <= '2025-04-02 00:00:00"), "{sql}");
+
+        let sql = issue_filters(7, &parse("")).build(DbBackend::MySql).to_string();
+        assert!(sql.ends_with("WHERE `issues`.`project_id` = 7"), "{sql}");
+    }
+
+    #[test]
+    fn maps_sort_and_order() {
+        let query = parse("sort=firstSeen&order=asc");
+        assert!(matches!(sort_column(query.sort.unwrap()), issue::Column::FirstSeen));
+        assert!(matches!(sort_order(query.order.unwrap()), sea_orm::Order::Asc));
+
+        let query = parse("sort=count");
+        assert!(matches!(sort_column(query.sort.unwrap()), issue::Column::Count));
+
+        // 지정하지 않으면 마지막 발생 시각 내림차순
+        assert!(matches!(sort_column(IssueSort::default()), issue::Column::LastSeen));
+        assert!(matches!(sort_order(SortOrder::default()), sea_orm::Order::Desc));
+
+        assert!(web::Query::<IssueQuery>::from_query("sort=title").is_err());
+    }
+
+    #[test]
+    fn cascades_issue_status_to_events() {
+        assert_eq!(cascaded_event_status(IssueStatus::Resolved), Some(EventStatus::RESOLVED));
+        for status in [IssueStatus::Open, IssueStatus::InProgress, IssueStatus::Regressed] {
+            assert_eq!(cascaded_event_status(status), Some(EventStatus::UNRESOLVED));
+        }
+        assert_eq!(cascaded_event_status(IssueStatus::Ignored), None);
+    }
+
+    #[test]
+    fn resolves_in_next_release_from_last_seen_release() {
+        let now = Utc.with_ymd_and_hms(2025, 4, 20, 0, 0, 0).unwrap();
+
+        let sql = status_update(IssueStatus::Resolved, None, true, now).build(DbBackend::MySql).to_string();
+        assert!(sql.contains("`resolved_in_version` = `last_release`"), "{sql}");
+        assert!(sql.contains("`resolved_in_next_release` = TRUE"), "{sql}");
+        assert!(sql.contains("`resolved_at` = '2025-04-20 00:00:00"), "{sql}");
+
+        let sql = status_update(IssueStatus::Resolved, Some("1.4.0".to_string()), false, now)
+            .build(DbBackend::MySql)
+            .to_string();
+        assert!(sql.contains("`resolved_in_version` = '1.4.0'"), "{sql}");
+        assert!(sql.contains("`resolved_in_next_release` = FALSE"), "{sql}");
+
+        // 다시 열면 해결 정보를 지운다
+        let sql = status_update(IssueStatus::Open, None, false, now).build(DbBackend::MySql).to_string();
+        assert!(sql.contains("`status` = 'open'"), "{sql}");
+        assert!(sql.contains("`resolved_in_version` = NULL"), "{sql}");
+        assert!(sql.contains("`resolved_at` = NULL"), "{sql}");
+    }
+
+    #[test]
+    fn validates_resolution_transitions() {
+        assert!(validate_resolution(IssueStatus::Resolved, None, false).is_ok());
+        assert!(validate_resolution(IssueStatus::Resolved, Some("1.4.0"), false).is_ok());
+        assert!(validate_resolution(IssueStatus::Resolved, None, true).is_ok());
+        assert!(validate_resolution(IssueStatus::Open, None, false).is_ok());
+
+        let fields = |result: Result<(), AppError>| match result {
+            Err(AppError::ValidationError(errors)) => errors.into_iter().map(|e| e.field).collect::<Vec<_>>(),
+            other => panic!("unexpected {:?}", other),
+        };
+        assert_eq!(fields(validate_resolution(IssueStatus::Open, Some("1.4.0"), false)), ["status"]);
+        assert_eq!(fields(validate_resolution(IssueStatus::Ignored, None, true)), ["status"]);
+        assert_eq!(fields(validate_resolution(IssueStatus::Resolved, Some("1.4.0"), true)), ["resolvedInNextRelease"]);
+        assert_eq!(fields(validate_resolution(IssueStatus::Resolved, Some("  "), false)), ["resolvedInVersion"]);
+    }
+}
@@ -4,8 +4,35 @@ pub mod project;
 pub mod health_check;
 pub mod trace;
 pub mod project_member;
+pub mod artifact;
+pub mod issue;
+pub mod alert_rule;
+pub mod notification_channel;
+pub mod notification_delivery;
+pub mod usage;
+pub mod sdk_config;
+pub mod scrubbing;
+pub mod pagination;
+pub mod search;
+pub mod tag;
+pub mod saved_search;
+pub mod export;
+pub mod stats;
 
 pub use crate::api::auth::{register, login, refresh_token, get_me};
-pub use crate::api::event::{get_project_events, list_project_events, report_event, report_batch_events, set_priority, set_assignee, set_event_status};
-pub use crate::api::project::{create_project, update_project, list_user_projects, get_project, delete_project, get_project_users};
+pub use crate::api::event::{get_event_countries, get_project_events, list_project_events, report_event, report_batch_events, set_priority, set_assignee, set_event_status};
+pub use crate::api::project::{create_project, update_project, list_user_projects, get_project, delete_project, get_project_users, update_project_ip_settings};
 pub use crate::api::trace::{receive_traces, get_transaction_spans, get_transactions};
+pub use crate::api::artifact::{upload_artifact, list_artifacts, delete_artifact};
+pub use crate::api::issue::{list_project_issues, get_project_issue, set_issue_status, set_issue_assignee, get_issue_activities};
+
+pub use crate::api::alert_rule::{create_alert_rule, list_alert_rules, get_alert_rule, update_alert_rule, delete_alert_rule};
+pub use crate::api::notification_channel::{create_notification_channel, list_notification_channels, update_notification_channel, delete_notification_channel, test_notification_channel};
+pub use crate::api::notification_delivery::{list_notification_deliveries, replay_notification_delivery};
+pub use crate::api::usage::{get_project_usage, update_project_limits};
+pub use crate::api::sdk_config::{get_sdk_config, update_project_sampling};
+pub use crate::api::scrubbing::{get_scrubbing_rules, update_scrubbing_rules};
+pub use crate::api::tag::{list_tag_facets, get_tag_distribution};
+pub use crate::api::saved_search::{create_saved_search, list_saved_searches, get_saved_search, update_saved_search, delete_saved_search};
+pub use crate::api::export::{export_events, export_issues, export_transactions, export_spans, create_export_job, list_export_jobs, get_export_job, download_export, delete_export_job};
+pub use crate::api::stats::get_project_stats;
\ No newline at end of file
@@ -0,0 +1,275 @@
+use actix_web::{delete, get, post, put, web, HttpResponse};
+use reqwest::Client;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, ModelTrait, QueryFilter, QueryOrder, Set};
+use tracing::warn;
+use crate::api::project::{check_active_project, check_project_member};
+use crate::entity::notification_channel::{self, ActiveModel as NotificationChannelActiveModel, Entity as NotificationChannelEntity};
+use crate::model::global_error::{AppError, ErrorCode, ValidationFieldError};
+use crate::model::notification_channel::{ChannelConfig, NotificationChannelRequest, NotificationChannelResponse};
+use crate::notification::{channel_for, Notification};
+
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/notification-channels",
+    summary = "알림 채널 생성",
+    request_body = NotificationChannelRequest,
+    responses(
+        (status = 201, description = "알림 채널 생성 성공", body = NotificationChannelResponse),
+        (status = 400, description = "잘못된 요청", body = ValidationFieldError),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "NotificationChannel"
+)]
+#[post("/projects/{project_id}/notification-channels")]
+pub async fn create_notification_channel(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    auth_user: web::ReqData<i32>,
+    body: web::Json<NotificationChannelRequest>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let NotificationChannelRequest { name, enabled, config } = body.into_inner();
+
+    validate_channel(&name, &config)?;
+    check_active_project(db.get_ref(), project_id).await?;
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let inserted = NotificationChannelActiveModel {
+        project_id: Set(project_id),
+        name: Set(name.trim().to_string()),
+        kind: Set(config.kind()),
+        config: Set(serde_json::to_value(&config).unwrap_or_default()),
+        enabled: Set(enabled),
+        ..Default::default()
+    }
+        .insert(db.get_ref())
+        .await?;
+
+    Ok(HttpResponse::Created().json(NotificationChannelResponse::from(inserted)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/notification-channels",
+    summary = "알림 채널 목록 조회",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+    ),
+    responses(
+        (status = 200, description = "알림 채널 목록 조회 성공", body = [NotificationChannelResponse]),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "NotificationChannel"
+)]
+#[get("/projects/{project_id}/notification-channels")]
+pub async fn list_notification_channels(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let channels = NotificationChannelEntity::find()
+        .filter(notification_channel::Column::ProjectId.eq(project_id))
+        .order_by_asc(notification_channel::Column::Id)
+        .all(db.get_ref())
+        .await?;
+
+    let responses: Vec<NotificationChannelResponse> = channels
+        .into_iter()
+        .map(NotificationChannelResponse::from)
+        .collect();
+
+    Ok(HttpResponse::Ok().json(responses))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/notification-channels/{id}",
+    summary = "알림 채널 수정",
+    request_body = NotificationChannelRequest,
+    responses(
+        (status = 200, description = "알림 채널 수정 성공", body = NotificationChannelResponse),
+        (status = 400, description = "잘못된 요청", body = ValidationFieldError),
+        (status = 404, description = "알림 채널 없음"),
+    ),
+    tag = "NotificationChannel"
+)]
+#[put("/projects/{project_id}/notification-channels/{id}")]
+pub async fn update_notification_channel(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+    body: web::Json<NotificationChannelRequest>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, channel_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let NotificationChannelRequest { name, enabled, config } = body.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    let channel = find_notification_channel(db.get_ref(), project_id, channel_id).await?;
+
+    // 응답에서 가려진 비밀값을 그대로 돌려보낸 경우 저장된 값을 유지한다.
+    let config = match serde_json::from_value::<ChannelConfig>(channel.config.clone()) {
+        Ok(previous) => config.keep_secrets(&previous),
+        Err(_) => config,
+    };
+    validate_channel(&name, &config)?;
+
+    let mut channel: NotificationChannelActiveModel = channel.into();
+    channel.name = Set(name.trim().to_string());
+    channel.kind = Set(config.kind());
+    channel.config = Set(serde_json::to_value(&config).unwrap_or_default());
+    channel.enabled = Set(enabled);
+
+    let updated = channel.update(db.get_ref()).await?;
+
+    Ok(HttpResponse::Ok().json(NotificationChannelResponse::from(updated)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/projects/{project_id}/notification-channels/{id}",
+    summary = "알림 채널 삭제",
+    responses(
+        (status = 204, description = "알림 채널 삭제 성공"),
+        (status = 404, description = "알림 채널 없음"),
+    ),
+    tag = "NotificationChannel"
+)]
+#[delete("/projects/{project_id}/notification-channels/{id}")]
+pub async fn delete_notification_channel(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, channel_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let channel = find_notification_channel(db.get_ref(), project_id, channel_id).await?;
+    channel.delete(db.get_ref()).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/notification-channels/{id}/test",
+    summary = "테스트 알림 발송",
+    responses(
+        (status = 204, description = "테스트 알림 발송 성공"),
+        (status = 400, description = "발송 실패"),
+        (status = 404, description = "알림 채널 없음"),
+    ),
+    tag = "NotificationChannel"
+)]
+#[post("/projects/{project_id}/notification-channels/{id}/test")]
+pub async fn test_notification_channel(
+    db: web::Data<DatabaseConnection>,
+    http: web::Data<Client>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, channel_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let channel = find_notification_channel(db.get_ref(), project_id, channel_id).await?;
+    let config: ChannelConfig = serde_json::from_value(channel.config)
+        .map_err(|_| AppError::bad_request(ErrorCode::InvalidNotificationChannel))?;
+
+    let notification = Notification::new(
+        "✅ 테스트 알림",
+        format!("'{}' 채널이 정상적으로 연결되었습니다.", channel.name),
+    );
+    channel_for(config, http.get_ref().clone())
+        .send(&notification)
+        .await
+        .map_err(|e| {
+            warn!("테스트 알림 발송 실패 (channel {}): {:?}", channel.id, e);
+            AppError::bad_request(ErrorCode::NotificationFailed)
+        })?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn find_notification_channel(
+    db: &DatabaseConnection,
+    project_id: i32,
+    channel_id: i32,
+) -> Result<notification_channel::Model, AppError> {
+    NotificationChannelEntity::find_by_id(channel_id)
+        .filter(notification_channel::Column::ProjectId.eq(project_id))
+        .one(db)
+        .await?
+        .ok_or_else(|| AppError::not_found(ErrorCode::NotificationChannelNotFound))
+}
+
+fn validate_channel(name: &str, config: &ChannelConfig) -> Result<(), AppError> {
+    let mut errors = Vec::new();
+
+    if name.trim().is_empty() {
+        errors.push(ValidationFieldError {
+            field: "name".to_string(),
+            message: "채널 이름은 필수입니다.".to_string(),
+        });
+    }
+
+    match config {
+        ChannelConfig::Slack { webhook_url }
+        | ChannelConfig::Discord { webhook_url }
+        | ChannelConfig::Teams { webhook_url } => {
+            if !is_http_url(webhook_url) {
+                errors.push(ValidationFieldError {
+                    field: "config.webhookUrl".to_string(),
+                    message: "올바른 http(s) URL이 아닙니다.".to_string(),
+                });
+            }
+        }
+        ChannelConfig::Webhook { url, .. } => {
+            if !is_http_url(url) {
+                errors.push(ValidationFieldError {
+                    field: "config.url".to_string(),
+                    message: "올바른 http(s) URL이 아닙니다.".to_string(),
+                });
+            }
+        }
+        ChannelConfig::Email(email) => {
+            if email.smtp_host.trim().is_empty() {
+                errors.push(ValidationFieldError {
+                    field: "config.smtpHost".to_string(),
+                    message: "SMTP 호스트는 필수입니다.".to_string(),
+                });
+            }
+            if email.from.parse::<lettre::Address>().is_err() {
+                errors.push(ValidationFieldError {
+                    field: "config.from".to_string(),
+                    message: "보내는 주소가 올바르지 않습니다.".to_string(),
+                });
+            }
+            if email.to.is_empty() || email.to.iter().any(|to| to.parse::<lettre::Address>().is_err()) {
+                errors.push(ValidationFieldError {
+                    field: "config.to".to_string(),
+                    message: "받는 주소가 올바르지 않습니다.".to_string(),
+                });
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(AppError::ValidationError(errors))
+    }
+}
+
+fn is_http_url(url: &str) -> bool {
+    reqwest::Url::parse(url).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+}
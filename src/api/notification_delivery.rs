@@ -0,0 +1,174 @@
+use actix_web::{get, post, web, HttpResponse};
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set};
+use tracing::error;
+use crate::amqp::AmqpClient;
+use crate::api::project::check_project_member;
+use crate::entity::notification_delivery::{self, ActiveModel as NotificationDeliveryActiveModel, DeliveryStatus, Entity as NotificationDeliveryEntity};
+use crate::model::event::PaginatedResponse;
+use crate::model::global_error::{AppError, ErrorCode};
+use crate::model::notification_delivery::{NotificationDeliveryQuery, NotificationDeliveryResponse};
+use crate::notification::Notification;
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/notification-deliveries",
+    summary = "알림 발송 이력 조회",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("status" = Option<DeliveryStatus>, Query, description = "발송 상태"),
+        ("channelId" = Option<i32>, Query, description = "알림 채널 ID"),
+        ("page" = Option<i32>, Query, description = "페이지 번호"),
+        ("pageSize" = Option<i32>, Query, description = "페이지 크기"),
+    ),
+    responses(
+        (status = 200, description = "발송 이력 조회 성공", body = Vec<NotificationDeliveryResponse>),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "NotificationChannel"
+)]
+#[get("/projects/{project_id}/notification-deliveries")]
+pub async fn list_notification_deliveries(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    query: web::Query<NotificationDeliveryQuery>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let NotificationDeliveryQuery { status, channel_id, page, page_size } = query.into_inner();
+
+    let page = page.unwrap_or(1).max(1);
+    let page_size = page_size.unwrap_or(20).min(100);
+    let offset = (page - 1) * page_size;
+
+    let total_elements = NotificationDeliveryEntity::find()
+        .filter(notification_delivery::Column::ProjectId.eq(project_id))
+        .count(db.get_ref())
+        .await?;
+
+    let mut query = NotificationDeliveryEntity::find()
+        .filter(notification_delivery::Column::ProjectId.eq(project_id));
+
+    if let Some(status) = status {
+        query = query.filter(notification_delivery::Column::Status.eq(status));
+    }
+    if let Some(channel_id) = channel_id {
+        query = query.filter(notification_delivery::Column::ChannelId.eq(channel_id));
+    }
+
+    let filtered_elements = query.clone().count(db.get_ref()).await?;
+
+    let deliveries = query
+        .order_by_desc(notification_delivery::Column::Id)
+        .offset(Some(offset as u64))
+        .limit(Some(page_size as u64))
+        .all(db.get_ref())
+        .await?;
+
+    let response = PaginatedResponse {
+        content: deliveries
+            .into_iter()
+            .map(NotificationDeliveryResponse::from)
+            .collect::<Vec<_>>(),
+        page,
+        page_size,
+        total_elements,
+        filtered_elements,
+        total_pages: ((filtered_elements as f64) / (page_size as f64)).ceil() as u32,
+        has_next: (offset + page_size) < (filtered_elements as u32),
+    };
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/notification-deliveries/{id}/replay",
+    summary = "실패한 알림 재발송",
+    responses(
+        (status = 202, description = "재발송 요청 성공", body = NotificationDeliveryResponse),
+        (status = 400, description = "실패한 발송이 아님"),
+        (status = 404, description = "발송 이력 없음"),
+    ),
+    tag = "NotificationChannel"
+)]
+#[post("/projects/{project_id}/notification-deliveries/{id}/replay")]
+pub async fn replay_notification_delivery(
+    db: web::Data<DatabaseConnection>,
+    amqp: web::Data<AmqpClient>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, delivery_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let delivery = NotificationDeliveryEntity::find_by_id(delivery_id)
+        .filter(notification_delivery::Column::ProjectId.eq(project_id))
+        .one(db.get_ref())
+        .await?
+        .ok_or_else(|| AppError::not_found(ErrorCode::NotificationDeliveryNotFound))?;
+
+    if delivery.status != DeliveryStatus::Failed {
+        return Err(AppError::bad_request(ErrorCode::DeliveryNotReplayable));
+    }
+
+    // 재발송은 처음부터 다시 재시도 횟수를 센다. 마지막 오류는 기록으로 남겨둔다.
+    let mut delivery: NotificationDeliveryActiveModel = delivery.into();
+    delivery.status = Set(DeliveryStatus::Pending);
+    delivery.attempts = Set(0);
+    let delivery = delivery.update(db.get_ref()).await?;
+
+    amqp.publish_notification(delivery.id).await.map_err(|e| {
+        error!("알림 재발송 발행 실패 (delivery {}): {:?}", delivery.id, e);
+        AppError::internal_error(ErrorCode::InternalError)
+    })?;
+
+    Ok(HttpResponse::Accepted().json(NotificationDeliveryResponse::from(delivery)))
+}
+
+/// 발송 이력을 남기고 알림 큐에 발행할 행을 만든다.
+pub async fn create_delivery(
+    db: &DatabaseConnection,
+    project_id: i32,
+    channel_id: Option<i32>,
+    notification: &Notification,
+) -> Result<notification_delivery::Model, AppError> {
+    let delivery = NotificationDeliveryActiveModel::new(
+        project_id,
+        channel_id,
+        notification.title.clone(),
+        notification.text.clone(),
+    )
+        .insert(db)
+        .await?;
+
+    Ok(delivery)
+}
+
+/// 발송 시도 결과를 기록한다.
+pub async fn record_attempt(
+    db: &DatabaseConnection,
+    delivery: notification_delivery::Model,
+    status: DeliveryStatus,
+    error: Option<String>,
+) -> Result<(), AppError> {
+    let attempts = delivery.attempts + 1;
+    let mut delivery: NotificationDeliveryActiveModel = delivery.into();
+    delivery.attempts = Set(attempts);
+    delivery.status = Set(status);
+    if status == DeliveryStatus::Delivered {
+        delivery.delivered_at = Set(Some(Utc::now()));
+    }
+    if error.is_some() {
+        delivery.last_error = Set(error);
+    }
+    delivery.update(db).await?;
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use actix_web::http::header;
+use actix_web::{HttpRequest, HttpResponse};
+use sea_orm::{ColumnTrait, Condition, DatabaseConnection, EntityTrait, Order, QueryFilter, QueryOrder, QuerySelect, Select};
+use serde::Serialize;
+use crate::model::common::CursorPaginationResponse;
+use crate::model::global_error::{AppError, ValidationFieldError};
+use crate::util::cursor::{Cursor, CursorDirection, SortKey};
+
+/// 키셋으로 가져온 한 페이지와 앞뒤 페이지 커서
+pub struct CursorPage<M> {
+    pub items: Vec<M>,
+    pub next: Option<Cursor>,
+    pub prev: Option<Cursor>,
+}
+
+impl<M> CursorPage<M> {
+    /// 응답 본문과 `Link` 헤더에 같은 커서를 담는다.
+    pub fn respond<T: Serialize>(self, req: &HttpRequest, page_size: u32, map: impl FnMut(M) -> T) -> HttpResponse {
+        let next_cursor = self.next.map(|cursor| cursor.encode());
+        let prev_cursor = self.prev.map(|cursor| cursor.encode());
+
+        let links = [(&next_cursor, "next"), (&prev_cursor, "prev")]
+            .into_iter()
+            .filter_map(|(cursor, rel)| cursor.as_ref().map(|cursor| format!("<{}>; rel=\"{}\"", cursor_url(req, cursor), rel)))
+            .collect::<Vec<_>>();
+
+        let body = CursorPaginationResponse {
+            content: self.items.into_iter().map(map).collect(),
+            page_size,
+            has_next: next_cursor.is_some(),
+            has_prev: prev_cursor.is_some(),
+            next_cursor,
+            prev_cursor,
+        };
+
+        let mut response = HttpResponse::Ok();
+        if !links.is_empty() {
+            response.insert_header((header::LINK, links.join(", ")));
+        }
+        response.json(body)
+    }
+}
+
+pub fn parse_cursor(raw: Option<&str>) -> Result<Option<Cursor>, AppError> {
+    raw.filter(|raw| !raw.trim().is_empty())
+        .map(|raw| {
+            Cursor::decode(raw).map_err(|message| AppError::ValidationError(vec![ValidationFieldError {
+                field: "cursor".to_string(),
+                message,
+            }]))
+        })
+        .transpose()
+}
+
+/// 목록 정렬 기준. 정렬 값이 같은 행은 ID로 순서를 정한다.
+pub struct Keyset<C> {
+    pub sort_column: C,
+    pub id_column: C,
+    pub order: Order,
+}
+
+/// 커서 다음(또는 앞) 페이지를 가져온다. 전체 개수는 세지 않는다.
+/// 한 행을 더 읽어 그 방향에 페이지가 더 있는지 판단한다.
+pub async fn fetch_cursor_page<E>(
+    db: &DatabaseConnection,
+    select: Select<E>,
+    keyset: Keyset<E::Column>,
+    cursor: Option<Cursor>,
+    page_size: u32,
+    key_of: impl Fn(&E::Model) -> (SortKey, i32),
+) -> Result<CursorPage<E::Model>, AppError>
+where
+    E: EntityTrait,
+{
+    let Keyset { sort_column, id_column, order } = keyset;
+    let fetch_order = fetch_order(order, cursor);
+
+    let mut select = select;
+    if let Some(cursor) = cursor {
+        select = select.filter(after(sort_column, id_column, &fetch_order, cursor));
+    }
+
+    let items = select
+        .order_by(sort_column, fetch_order.clone())
+        .order_by(id_column, fetch_order)
+        .limit(Some(page_size as u64 + 1))
+        .all(db)
+        .await?;
+
+    let (items, has_next, has_prev) = settle_page(items, page_size, cursor);
+    let at = |item: &E::Model, direction| {
+        let (key, id) = key_of(item);
+        Cursor { direction, key, id }
+    };
+
+    Ok(CursorPage {
+        next: items.last().filter(|_| has_next).map(|item| at(item, CursorDirection::Next)),
+        prev: items.first().filter(|_| has_prev).map(|item| at(item, CursorDirection::Prev)),
+        items,
+    })
+}
+
+fn is_backward(cursor: Option<Cursor>) -> bool {
+    cursor.is_some_and(|cursor| cursor.direction == CursorDirection::Prev)
+}
+
+// 앞 페이지는 반대 순서로 읽은 뒤 뒤집는다.
+fn fetch_order(order: Order, cursor: Option<Cursor>) -> Order {
+    match (is_backward(cursor), order) {
+        (true, Order::Asc) => Order::Desc,
+        (true, _) => Order::Asc,
+        (false, order) => order,
+    }
+}
+
+// 한 행 더 읽은 결과를 페이지 크기로 자르고 원래 정렬 순서로 되돌린다. 앞뒤에 페이지가 더 있는지도 돌려준다.
+fn settle_page<M>(mut items: Vec<M>, page_size: u32, cursor: Option<Cursor>) -> (Vec<M>, bool, bool) {
+    let backward = is_backward(cursor);
+    let has_more = items.len() > page_size as usize;
+    items.truncate(page_size as usize);
+    if backward {
+        items.reverse();
+    }
+
+    // 커서를 따라 왔다면 온 쪽에는 항상 페이지가 있다.
+    let (has_next, has_prev) = if backward { (true, has_more) } else { (has_more, cursor.is_some()) };
+    (items, has_next, has_prev)
+}
+
+// 정렬 순서상 커서 행 뒤에 오는 행. 정렬 값이 같으면 ID로 가른다.
+fn after(sort_column: impl ColumnTrait, id_column: impl ColumnTrait, order: &Order, cursor: Cursor) -> Condition {
+    let (past_key, past_id) = match order {
+        Order::Asc => (sort_column.gt(cursor.key), id_column.gt(cursor.id)),
+        _ => (sort_column.lt(cursor.key), id_column.lt(cursor.id)),
+    };
+
+    Condition::any()
+        .add(past_key)
+        .add(Condition::all().add(sort_column.eq(cursor.key)).add(past_id))
+}
+
+// 현재 요청 URL에서 페이지 관련 파라미터만 바꾼 링크
+fn cursor_url(req: &HttpRequest, cursor: &str) -> String {
+    let mut params = req
+        .query_string()
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .filter(|pair| {
+            let name = pair.split('=').next().unwrap_or_default();
+            !matches!(name, "cursor" | "page" | "pagination")
+        })
+        .collect::<Vec<_>>();
+
+    let cursor = format!("cursor={}", cursor);
+    params.push(&cursor);
+    format!("{}?{}", req.path(), params.join("&"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbBackend, QueryTrait};
+    use crate::entity::issue::{self, Entity as IssueEntity};
+
+    fn cursor(direction: CursorDirection) -> Cursor {
+        Cursor { direction, key: SortKey::Number(5), id: 42 }
+    }
+
+    fn after_sql(order: Order) -> String {
+        let condition = after(issue::Column::Count, issue::Column::Id, &order, cursor(CursorDirection::Next));
+        let sql = IssueEntity::find().filter(condition).build(DbBackend::MySql).to_string();
+        sql[sql.find(" WHERE ").unwrap() + " WHERE ".len()..].to_string()
+    }
+
+    #[test]
+    fn keyset_boundary_follows_order() {
+        assert_eq!(
+            after_sql(Order::Asc),
+            "`issues`.`count` > 5 OR (`issues`.`count` = 5 AND `issues`.`id` > 42)"
+        );
+        assert_eq!(
+            after_sql(Order::Desc),
+            "`issues`.`count` < 5 OR (`issues`.`count` = 5 AND `issues`.`id` < 42)"
+        );
+    }
+
+    #[test]
+    fn reads_previous_pages_in_reverse() {
+        assert_eq!(fetch_order(Order::Desc, None), Order::Desc);
+        assert_eq!(fetch_order(Order::Desc, Some(cursor(CursorDirection::Next))), Order::Desc);
+        assert_eq!(fetch_order(Order::Desc, Some(cursor(CursorDirection::Prev))), Order::Asc);
+        assert_eq!(fetch_order(Order::Asc, Some(cursor(CursorDirection::Prev))), Order::Desc);
+    }
+
+    #[test]
+    fn settles_backward_page() {
+        // 앞 페이지는 반대 순서로 한 행 더 읽어 온다.
+        let (items, has_next, has_prev) = settle_page(vec![9, 8, 7, 6], 3, Some(cursor(CursorDirection::Prev)));
+        assert_eq!(items, [7, 8, 9]);
+        assert!(has_next && has_prev);
+
+        // 첫 페이지까지 돌아오면 더 앞은 없다.
+        let (items, has_next, has_prev) = settle_page(vec![2, 1], 3, Some(cursor(CursorDirection::Prev)));
+        assert_eq!(items, [1, 2]);
+        assert!(has_next && !has_prev);
+    }
+
+    #[test]
+    fn settles_forward_page() {
+        let (items, has_next, has_prev) = settle_page(vec![1, 2, 3, 4], 3, None);
+        assert_eq!(items, [1, 2, 3]);
+        assert!(has_next && !has_prev);
+
+        let (items, has_next, has_prev) = settle_page(vec![4, 5], 3, Some(cursor(CursorDirection::Next)));
+        assert_eq!(items, [4, 5]);
+        assert!(!has_next && has_prev);
+    }
+}
@@ -1,14 +1,16 @@
 use actix_web::{delete, get, post, put, web, HttpResponse};
 use chrono::Utc;
-use sea_orm::{Set, ActiveModelTrait, EntityTrait, QueryFilter, ColumnTrait, DatabaseConnection};
-use sea_query::Condition;
+use sea_orm::{Set, ActiveModelTrait, EntityTrait, QueryFilter, ColumnTrait, DatabaseConnection, TransactionTrait};
+use sea_query::{Condition, Query, SelectStatement};
 use crate::api::project_member::check_project_owner;
+use crate::entity::alert_rule::ActiveModel as AlertRuleActiveModel;
 use crate::entity::project_member::{Entity as ProjectMemberEntity, ActiveModel as ProjectMemberActiveModel};
 use crate::entity::project::{Entity as ProjectEntity, ActiveModel as ProjectActiveModel};
 use crate::entity::{project_member, user};
 use crate::entity::project_member::Role as ProjectMemberRole;
-use crate::model::global_error::{AppError, ErrorCode};
-use crate::model::project::{ProjectCreateRequest, ProjectDetailResponse, ProjectMemberResponse, ProjectResponse, ProjectUpdateRequest};
+use crate::model::global_error::{AppError, ErrorCode, ValidationFieldError};
+use crate::model::project::{ProjectCreateRequest, ProjectDetailResponse, ProjectIpSettingsRequest, ProjectMemberResponse, ProjectResponse, ProjectUpdateRequest};
+use crate::util::client_ip::IpRange;
 
 #[utoipa::path(
     post,
@@ -38,7 +40,9 @@ pub async fn create_project(
         ..Default::default()
     };
 
-    let inserted_project = new_project.insert(db.get_ref()).await?;
+    // 소유자와 기본 회귀 알림 규칙이 없는 프로젝트가 남지 않도록 함께 만든다.
+    let txn = db.begin().await?;
+    let inserted_project = new_project.insert(&txn).await?;
 
     let member = ProjectMemberActiveModel {
         user_id: Set(*auth_user),
@@ -47,7 +51,10 @@ pub async fn create_project(
         joined_at: Set(now.into()),
     };
 
-    member.insert(db.get_ref()).await?;
+    member.insert(&txn).await?;
+
+    AlertRuleActiveModel::default_regression(inserted_project.id).insert(&txn).await?;
+    txn.commit().await?;
 
     Ok(HttpResponse::Created().json(ProjectResponse::from(inserted_project)))
 }
@@ -250,6 +257,56 @@ pub async fn get_project_users(
     Ok(HttpResponse::Ok().json(member_responses))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/projects/{id}/ip-settings",
+    summary = "클라이언트 IP 수집 설정",
+    request_body = ProjectIpSettingsRequest,
+    responses(
+        (status = 200, description = "IP 수집 설정 성공", body = ProjectResponse),
+        (status = 400, description = "잘못된 요청", body = ValidationFieldError),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Project"
+)]
+#[put("/projects/{id}/ip-settings")]
+pub async fn update_project_ip_settings(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    body: web::Json<ProjectIpSettingsRequest>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = *auth_user;
+    let ProjectIpSettingsRequest { collect_ip_address, trusted_proxies } = body.into_inner();
+
+    let errors: Vec<ValidationFieldError> = trusted_proxies
+        .iter()
+        .enumerate()
+        .filter_map(|(index, proxy)| {
+            proxy.parse::<IpRange>().err().map(|e| ValidationFieldError {
+                field: format!("trustedProxies[{}]", index),
+                message: e,
+            })
+        })
+        .collect();
+    if !errors.is_empty() {
+        return Err(AppError::ValidationError(errors));
+    }
+
+    check_project_owner(db.get_ref(), project_id, user_id).await?;
+    let project = check_active_project(db.get_ref(), project_id).await?;
+    let mut project_model: ProjectActiveModel = project.into();
+
+    project_model.collect_ip_address = Set(collect_ip_address);
+    project_model.trusted_proxies = Set(serde_json::to_value(&trusted_proxies).ok());
+    project_model.updated_at = Set(Some(Utc::now()));
+
+    let updated_project = project_model.update(db.get_ref()).await?;
+
+    Ok(HttpResponse::Ok().json(ProjectResponse::from(updated_project)))
+}
+
 pub async fn check_active_project(
     db: &DatabaseConnection,
     project_id: i32,
@@ -289,4 +346,12 @@ pub async fn check_project_member(
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+/// 유저가 멤버인 프로젝트 ID 서브쿼리. `project_id` 컬럼의 `in_subquery`로 쓴다.
+pub fn member_project_ids(user_id: i32) -> SelectStatement {
+    Query::select()
+        .column(project_member::Column::ProjectId)
+        .from(ProjectMemberEntity)
+        .and_where(project_member::Column::UserId.eq(user_id))
+        .to_owned()
+}
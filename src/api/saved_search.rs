@@ -0,0 +1,369 @@
+use actix_web::{delete, get, post, put, web, HttpResponse};
+use sea_orm::{ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, ModelTrait, QueryFilter, QueryOrder, Set, UpdateMany};
+use sea_query::Expr;
+use crate::api::project::{check_active_project, check_project_member};
+use crate::api::project_member::check_project_owner;
+use crate::api::search::event_search_condition;
+use crate::entity::saved_search::{self, ActiveModel as SavedSearchActiveModel, Entity as SavedSearchEntity, SavedSearchVisibility};
+use crate::model::global_error::{AppError, ErrorCode, ValidationFieldError};
+use crate::model::saved_search::{SavedSearchRequest, SavedSearchResponse};
+
+const MAX_NAME_LENGTH: usize = 100;
+const MAX_QUERY_LENGTH: usize = 1000;
+
+#[utoipa::path(
+    post,
+    path = "/api/projects/{project_id}/saved-searches",
+    summary = "검색 저장",
+    request_body = SavedSearchRequest,
+    responses(
+        (status = 201, description = "검색 저장 성공", body = SavedSearchResponse),
+        (status = 400, description = "잘못된 요청", body = ValidationFieldError),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "SavedSearch"
+)]
+#[post("/projects/{project_id}/saved-searches")]
+pub async fn create_saved_search(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    auth_user: web::ReqData<i32>,
+    body: web::Json<SavedSearchRequest>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let request = body.into_inner();
+
+    check_active_project(db.get_ref(), project_id).await?;
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    validate_saved_search(db.get_ref(), project_id, user_id, &request).await?;
+
+    let mut search = SavedSearchActiveModel {
+        project_id: Set(project_id),
+        user_id: Set(user_id),
+        ..Default::default()
+    };
+    apply_request(&mut search, request);
+
+    let inserted = search.insert(db.get_ref()).await?;
+    if inserted.is_default {
+        clear_other_defaults(db.get_ref(), &inserted).await?;
+    }
+
+    Ok(HttpResponse::Created().json(SavedSearchResponse::from(inserted)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/saved-searches",
+    summary = "저장된 검색 목록 조회",
+    description = "프로젝트에 공유된 검색과 내 비공개 검색을 고정한 것부터 이름순으로 돌려준다.",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+    ),
+    responses(
+        (status = 200, description = "저장된 검색 목록 조회 성공", body = [SavedSearchResponse]),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "SavedSearch"
+)]
+#[get("/projects/{project_id}/saved-searches")]
+pub async fn list_saved_searches(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let searches = SavedSearchEntity::find()
+        .filter(saved_search::Column::ProjectId.eq(project_id))
+        .filter(visible_to(user_id))
+        .order_by_desc(saved_search::Column::IsPinned)
+        .order_by_asc(saved_search::Column::Name)
+        .order_by_asc(saved_search::Column::Id)
+        .all(db.get_ref())
+        .await?;
+
+    let responses: Vec<SavedSearchResponse> = searches
+        .into_iter()
+        .map(SavedSearchResponse::from)
+        .collect();
+
+    Ok(HttpResponse::Ok().json(responses))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/saved-searches/{id}",
+    summary = "저장된 검색 조회",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("id" = i32, Path, description = "저장된 검색 ID"),
+    ),
+    responses(
+        (status = 200, description = "저장된 검색 조회 성공", body = SavedSearchResponse),
+        (status = 404, description = "저장된 검색 없음"),
+    ),
+    tag = "SavedSearch"
+)]
+#[get("/projects/{project_id}/saved-searches/{id}")]
+pub async fn get_saved_search(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, search_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    let search = find_saved_search(db.get_ref(), project_id, user_id, search_id).await?;
+
+    Ok(HttpResponse::Ok().json(SavedSearchResponse::from(search)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/saved-searches/{id}",
+    summary = "저장된 검색 수정",
+    description = "공유된 검색은 만든 사람과 프로젝트 소유자만 수정할 수 있다.",
+    request_body = SavedSearchRequest,
+    responses(
+        (status = 200, description = "저장된 검색 수정 성공", body = SavedSearchResponse),
+        (status = 400, description = "잘못된 요청", body = ValidationFieldError),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "저장된 검색 없음"),
+    ),
+    tag = "SavedSearch"
+)]
+#[put("/projects/{project_id}/saved-searches/{id}")]
+pub async fn update_saved_search(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+    body: web::Json<SavedSearchRequest>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, search_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let request = body.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    let search = find_saved_search(db.get_ref(), project_id, user_id, search_id).await?;
+    check_can_modify(db.get_ref(), &search, user_id).await?;
+    validate_saved_search(db.get_ref(), project_id, user_id, &request).await?;
+
+    let mut search: SavedSearchActiveModel = search.into();
+    apply_request(&mut search, request);
+
+    let updated = search.update(db.get_ref()).await?;
+    if updated.is_default {
+        clear_other_defaults(db.get_ref(), &updated).await?;
+    }
+
+    Ok(HttpResponse::Ok().json(SavedSearchResponse::from(updated)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/projects/{project_id}/saved-searches/{id}",
+    summary = "저장된 검색 삭제",
+    description = "공유된 검색은 만든 사람과 프로젝트 소유자만 삭제할 수 있다.",
+    responses(
+        (status = 204, description = "저장된 검색 삭제 성공"),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "저장된 검색 없음"),
+    ),
+    tag = "SavedSearch"
+)]
+#[delete("/projects/{project_id}/saved-searches/{id}")]
+pub async fn delete_saved_search(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<(i32, i32)>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, search_id) = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let search = find_saved_search(db.get_ref(), project_id, user_id, search_id).await?;
+    check_can_modify(db.get_ref(), &search, user_id).await?;
+    search.delete(db.get_ref()).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// 사용자가 볼 수 있는 저장된 검색을 찾는다. 다른 사람의 비공개 검색은 없는 것으로 본다.
+pub async fn find_saved_search(
+    db: &DatabaseConnection,
+    project_id: i32,
+    user_id: i32,
+    search_id: i32,
+) -> Result<saved_search::Model, AppError> {
+    SavedSearchEntity::find_by_id(search_id)
+        .filter(saved_search::Column::ProjectId.eq(project_id))
+        .filter(visible_to(user_id))
+        .one(db)
+        .await?
+        .ok_or_else(|| AppError::not_found(ErrorCode::SavedSearchNotFound))
+}
+
+fn visible_to(user_id: i32) -> Condition {
+    Condition::any()
+        .add(saved_search::Column::Visibility.eq(SavedSearchVisibility::Shared))
+        .add(saved_search::Column::UserId.eq(user_id))
+}
+
+// 공유된 검색은 다른 멤버에게도 보이므로 만든 사람이 아니면 프로젝트 소유자만 바꿀 수 있다.
+async fn check_can_modify(
+    db: &DatabaseConnection,
+    search: &saved_search::Model,
+    user_id: i32,
+) -> Result<(), AppError> {
+    if !requires_owner(search, user_id) {
+        return Ok(());
+    }
+    check_project_owner(db, search.project_id, user_id).await
+}
+
+fn requires_owner(search: &saved_search::Model, user_id: i32) -> bool {
+    search.user_id != user_id
+}
+
+async fn clear_other_defaults(db: &DatabaseConnection, search: &saved_search::Model) -> Result<(), AppError> {
+    clear_other_defaults_query(search).exec(db).await?;
+    Ok(())
+}
+
+// 기본 검색은 비공개는 사용자마다, 공유는 프로젝트마다 하나만 둔다.
+fn clear_other_defaults_query(search: &saved_search::Model) -> UpdateMany<SavedSearchEntity> {
+    let mut scope = Condition::all()
+        .add(saved_search::Column::ProjectId.eq(search.project_id))
+        .add(saved_search::Column::Visibility.eq(search.visibility))
+        .add(saved_search::Column::IsDefault.eq(true))
+        .add(saved_search::Column::Id.ne(search.id));
+    if search.visibility == SavedSearchVisibility::Private {
+        scope = scope.add(saved_search::Column::UserId.eq(search.user_id));
+    }
+
+    SavedSearchEntity::update_many()
+        .col_expr(saved_search::Column::IsDefault, Expr::value(false))
+        .filter(scope)
+}
+
+fn apply_request(search: &mut SavedSearchActiveModel, request: SavedSearchRequest) {
+    search.visibility = Set(request.visibility);
+    search.name = Set(request.name.trim().to_string());
+    search.query = Set(request.query.trim().to_string());
+    search.sort = Set(request.sort);
+    search.is_pinned = Set(request.is_pinned);
+    search.is_default = Set(request.is_default);
+}
+
+async fn validate_saved_search(
+    db: &DatabaseConnection,
+    project_id: i32,
+    user_id: i32,
+    request: &SavedSearchRequest,
+) -> Result<(), AppError> {
+    let mut errors = Vec::new();
+
+    let name = request.name.trim();
+    if name.is_empty() {
+        errors.push(ValidationFieldError {
+            field: "name".to_string(),
+            message: "검색 이름은 필수입니다.".to_string(),
+        });
+    } else if name.chars().count() > MAX_NAME_LENGTH {
+        errors.push(ValidationFieldError {
+            field: "name".to_string(),
+            message: format!("검색 이름은 {}자 이하여야 합니다.", MAX_NAME_LENGTH),
+        });
+    }
+
+    if request.query.chars().count() > MAX_QUERY_LENGTH {
+        errors.push(ValidationFieldError {
+            field: "query".to_string(),
+            message: format!("검색어는 {}자 이하여야 합니다.", MAX_QUERY_LENGTH),
+        });
+    } else {
+        match event_search_condition(db, project_id, user_id, request.query.trim()).await {
+            Ok(_) => {}
+            Err(AppError::ValidationError(query_errors)) => errors.extend(query_errors),
+            Err(e) => return Err(e),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(AppError::ValidationError(errors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use sea_orm::{DbBackend, QueryTrait};
+    use crate::entity::saved_search::SavedSearchSort;
+
+    fn search(visibility: SavedSearchVisibility) -> saved_search::Model {
+        saved_search::Model {
+            id: 3,
+            project_id: 1,
+            user_id: 7,
+            visibility,
+            name: "내 이슈".to_string(),
+            query: "assigned:me".to_string(),
+            sort: SavedSearchSort::default(),
+            is_pinned: false,
+            is_default: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn shows_shared_and_own_searches() {
+        let sql = SavedSearchEntity::find().filter(visible_to(7)).build(DbBackend::MySql).to_string();
+        assert!(
+            sql.ends_with("WHERE `saved_searches`.`visibility` = 'shared' OR `saved_searches`.`user_id` = 7"),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn clears_private_defaults_per_user() {
+        let sql = clear_other_defaults_query(&search(SavedSearchVisibility::Private)).build(DbBackend::MySql).to_string();
+        assert_eq!(
+            sql,
+            "UPDATE `saved_searches` SET `is_default` = FALSE WHERE `saved_searches`.`project_id` = 1 \
+             AND `saved_searches`.`visibility` = 'private' AND `saved_searches`.`is_default` = TRUE \
+             AND `saved_searches`.`id` <> 3 AND `saved_searches`.`user_id` = 7"
+        );
+    }
+
+    #[test]
+    fn clears_shared_defaults_per_project() {
+        let sql = clear_other_defaults_query(&search(SavedSearchVisibility::Shared)).build(DbBackend::MySql).to_string();
+        assert_eq!(
+            sql,
+            "UPDATE `saved_searches` SET `is_default` = FALSE WHERE `saved_searches`.`project_id` = 1 \
+             AND `saved_searches`.`visibility` = 'shared' AND `saved_searches`.`is_default` = TRUE \
+             AND `saved_searches`.`id` <> 3"
+        );
+    }
+
+    #[tokio::test]
+    async fn only_other_members_need_ownership() {
+        let shared = search(SavedSearchVisibility::Shared);
+        assert!(!requires_owner(&shared, 7));
+        assert!(requires_owner(&shared, 8));
+
+        // 만든 사람은 소유자 확인(DB 조회) 없이 바꿀 수 있다.
+        assert!(check_can_modify(&DatabaseConnection::default(), &shared, 7).await.is_ok());
+    }
+}
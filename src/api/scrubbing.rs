@@ -0,0 +1,174 @@
+use std::collections::BTreeSet;
+use actix_web::{get, put, web, HttpResponse};
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use sea_orm::{ActiveModelTrait, DatabaseConnection, Set};
+use tracing::warn;
+use crate::api::project::{check_active_project, check_project_member};
+use crate::api::project_member::check_project_owner;
+use crate::entity::project::{self, ActiveModel as ProjectActiveModel};
+use crate::model::event::{EventReportRequest, UserContext};
+use crate::model::global_error::{AppError, ValidationFieldError};
+use crate::model::scrubbing::ScrubbingRules;
+use crate::util::scrub::Scrubber;
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/scrubbing-rules",
+    summary = "데이터 스크러빙 규칙 조회",
+    responses(
+        (status = 200, description = "스크러빙 규칙 조회 성공", body = ScrubbingRules),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Project"
+)]
+#[get("/projects/{project_id}/scrubbing-rules")]
+pub async fn get_scrubbing_rules(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    let project = check_active_project(db.get_ref(), project_id).await?;
+
+    Ok(HttpResponse::Ok().json(scrubbing_rules(&project)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/scrubbing-rules",
+    summary = "데이터 스크러빙 규칙 설정",
+    request_body = ScrubbingRules,
+    responses(
+        (status = 200, description = "스크러빙 규칙 설정 성공", body = ScrubbingRules),
+        (status = 400, description = "잘못된 요청", body = ValidationFieldError),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Project"
+)]
+#[put("/projects/{project_id}/scrubbing-rules")]
+pub async fn update_scrubbing_rules(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    body: web::Json<ScrubbingRules>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let rules = body.into_inner();
+
+    validate_scrubbing_rules(&rules)?;
+
+    check_project_owner(db.get_ref(), project_id, user_id).await?;
+    let project = check_active_project(db.get_ref(), project_id).await?;
+
+    let mut project: ProjectActiveModel = project.into();
+    project.scrubbing_rules = Set(serde_json::to_value(&rules).ok());
+    project.updated_at = Set(Some(Utc::now()));
+    project.update(db.get_ref()).await?;
+
+    Ok(HttpResponse::Ok().json(rules))
+}
+
+/// 프로젝트에 저장된 스크러빙 규칙. 없거나 읽을 수 없으면 기본 규칙을 쓴다.
+pub fn scrubbing_rules(project: &project::Model) -> ScrubbingRules {
+    project
+        .scrubbing_rules
+        .clone()
+        .and_then(|rules| serde_json::from_value(rules).ok())
+        .unwrap_or_default()
+}
+
+/// 프로젝트 규칙으로 스크러버를 만든다. 저장된 규칙을 쓸 수 없으면 기본 규칙으로 대신한다.
+pub fn project_scrubber(project: &project::Model) -> Scrubber {
+    Scrubber::new(&scrubbing_rules(project)).unwrap_or_else(|e| {
+        warn!("스크러빙 규칙을 적용할 수 없어 기본 규칙을 사용합니다 (project {}): {}", project.id, e);
+        Scrubber::new(&ScrubbingRules::default()).expect("기본 스크러빙 규칙은 항상 유효하다")
+    })
+}
+
+/// 저장하기 전에 이벤트의 자유 입력 필드를 가리고, 가린 필드 경로를 돌려준다.
+pub fn scrub_event(scrubber: &Scrubber, event: &mut EventReportRequest) -> Vec<String> {
+    let mut redacted = BTreeSet::new();
+
+    scrubber.scrub_str("message", &mut event.message, &mut redacted);
+    scrubber.scrub_str("stacktrace", &mut event.stacktrace, &mut redacted);
+    if let Some(user_agent) = event.user_agent.as_mut() {
+        scrubber.scrub_str("user_agent", user_agent, &mut redacted);
+    }
+    if let Some(additional_info) = event.additional_info.as_mut() {
+        scrubber.scrub_json("additional_info", additional_info, &mut redacted);
+    }
+    if let Some(replay) = event.replay.as_mut() {
+        scrubber.scrub_json("replay", replay, &mut redacted);
+    }
+    for breadcrumb in &mut event.breadcrumbs {
+        if let Some(message) = breadcrumb.message.as_mut() {
+            scrubber.scrub_str("breadcrumbs[].message", message, &mut redacted);
+        }
+        if let Some(data) = breadcrumb.data.as_mut() {
+            scrubber.scrub_json("breadcrumbs[].data", data, &mut redacted);
+        }
+    }
+    scrubber.scrub_map("tags", &mut event.tags, &mut redacted);
+    if let Some(user) = event.user.as_mut() {
+        // 기본 규칙은 이메일을 가리므로, 가리기 전에 영향받은 사용자를 셀 키를 남긴다.
+        user.key_hash = user_key_hash(user);
+        for (path, value) in [("user.id", &mut user.id), ("user.email", &mut user.email), ("user.username", &mut user.username)] {
+            if let Some(value) = value.as_mut() {
+                scrubber.scrub_str(path, value, &mut redacted);
+            }
+        }
+    }
+    if let Some(request) = event.request.as_mut() {
+        if let Some(url) = request.url.as_mut() {
+            scrubber.scrub_str("request.url", url, &mut redacted);
+        }
+        scrubber.scrub_map("request.headers", &mut request.headers, &mut redacted);
+        scrubber.scrub_map("request.query", &mut request.query, &mut redacted);
+    }
+
+    redacted.into_iter().collect()
+}
+
+// id가 있으면 id로, 없으면 대소문자를 무시한 이메일로 사용자를 구분한다.
+fn user_key_hash(user: &UserContext) -> Option<String> {
+    let id = user.id.as_deref().map(str::trim).filter(|id| !id.is_empty());
+    let email = user.email.as_deref().map(str::trim).filter(|email| !email.is_empty());
+    let key = match (id, email) {
+        (Some(id), _) => format!("id:{}", id),
+        (None, Some(email)) => format!("email:{}", email.to_lowercase()),
+        (None, None) => return None,
+    };
+    Some(format!("{:x}", Sha256::digest(key.as_bytes())))
+}
+
+fn validate_scrubbing_rules(rules: &ScrubbingRules) -> Result<(), AppError> {
+    let mut errors = Vec::new();
+
+    for (index, pattern) in rules.patterns.iter().enumerate() {
+        if let Err(e) = regex::Regex::new(pattern) {
+            errors.push(ValidationFieldError {
+                field: format!("patterns[{}]", index),
+                message: format!("정규식이 올바르지 않습니다: {}", e),
+            });
+        }
+    }
+    for (index, key) in rules.keys.iter().enumerate() {
+        if key.trim().is_empty() {
+            errors.push(ValidationFieldError {
+                field: format!("keys[{}]", index),
+                message: "키 이름은 비워둘 수 없습니다.".to_string(),
+            });
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(AppError::ValidationError(errors))
+    }
+}
@@ -0,0 +1,87 @@
+use actix_web::{get, put, web, HttpResponse};
+use actix_web::http::header;
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+use crate::api::project::check_active_project;
+use crate::api::project_member::check_project_owner;
+use crate::entity::project::{self, ActiveModel as ProjectActiveModel, Entity as ProjectEntity};
+use crate::model::global_error::{AppError, ErrorCode, ValidationFieldError};
+use crate::model::project::ProjectResponse;
+use crate::model::sdk_config::{ProjectSamplingRequest, SdkConfigQuery, SdkConfigResponse};
+
+// SDK가 설정을 캐시해도 되는 시간(초)
+const SDK_CONFIG_MAX_AGE: u32 = 60;
+
+#[utoipa::path(
+    get,
+    path = "/sdk-config",
+    summary = "SDK 설정 조회",
+    params(
+        ("apiKey" = String, Query, description = "프로젝트 API 키"),
+    ),
+    responses(
+        (status = 200, description = "SDK 설정 조회 성공", body = SdkConfigResponse),
+        (status = 400, description = "유효하지 않은 API 키"),
+    ),
+    tag = "Event"
+)]
+#[get("/sdk-config")]
+pub async fn get_sdk_config(
+    db: web::Data<DatabaseConnection>,
+    query: web::Query<SdkConfigQuery>,
+) -> Result<HttpResponse, AppError> {
+    let project = ProjectEntity::find()
+        .filter(project::Column::ApiKey.eq(&query.api_key))
+        .filter(project::Column::DeletedAt.is_null())
+        .one(db.get_ref())
+        .await?
+        .ok_or_else(|| AppError::bad_request(ErrorCode::InvalidApiKey))?;
+
+    Ok(HttpResponse::Ok()
+        .insert_header(header::CacheControl(vec![header::CacheDirective::MaxAge(SDK_CONFIG_MAX_AGE)]))
+        .json(SdkConfigResponse {
+            sample_rate: project.sample_rate.unwrap_or(1.0),
+        }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/sampling",
+    summary = "프로젝트 샘플링 비율 설정",
+    request_body = ProjectSamplingRequest,
+    responses(
+        (status = 200, description = "샘플링 비율 설정 성공", body = ProjectResponse),
+        (status = 400, description = "잘못된 요청", body = ValidationFieldError),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Project"
+)]
+#[put("/projects/{project_id}/sampling")]
+pub async fn update_project_sampling(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    body: web::Json<ProjectSamplingRequest>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let sample_rate = body.into_inner().sample_rate;
+
+    if sample_rate.is_some_and(|rate| !(0.0..=1.0).contains(&rate)) {
+        return Err(AppError::ValidationError(vec![ValidationFieldError {
+            field: "sampleRate".to_string(),
+            message: "샘플링 비율은 0 이상 1 이하여야 합니다.".to_string(),
+        }]));
+    }
+
+    check_project_owner(db.get_ref(), project_id, user_id).await?;
+    let project = check_active_project(db.get_ref(), project_id).await?;
+
+    let mut project: ProjectActiveModel = project.into();
+    project.sample_rate = Set(sample_rate);
+    project.updated_at = Set(Some(Utc::now()));
+
+    let updated = project.update(db.get_ref()).await?;
+
+    Ok(HttpResponse::Ok().json(ProjectResponse::from(updated)))
+}
@@ -0,0 +1,469 @@
+use std::cmp::Ordering;
+use chrono::{DateTime, NaiveDate, Utc};
+use sea_orm::{ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect};
+use sea_query::{IntoCondition, SimpleExpr, Value};
+use crate::api::tag::{tagged_event_ids, tagged_issue_ids};
+use crate::entity::event::{self, Entity as EventEntity, EventStatus, Priority};
+use crate::entity::issue::{self, Entity as IssueEntity, IssueStatus};
+use crate::entity::transaction;
+use crate::model::global_error::{AppError, ValidationFieldError};
+use crate::util::search::{parse_relative_age, parse_search, tag_key, SearchFilter, SearchOp, SearchTerm};
+use crate::util::version::compare_versions;
+
+const EVENT_KEYS: &str = "is, assigned, environment, browser, os, device, country, release, message, issue, priority, age, timestamp, tags[키]";
+const ISSUE_KEYS: &str = "is, assigned, release, message, age, last_seen, times_seen, environment, browser, os, device, country, tags[키]";
+const TRANSACTION_KEYS: &str = "name, environment, status, trace, duration, age, timestamp";
+
+struct SearchContext {
+    project_id: i32,
+    user_id: i32,
+    now: DateTime<Utc>,
+    // `release:>=1.4`처럼 버전을 비교할 때 쓰는, 프로젝트에 있는 버전 목록
+    releases: Vec<String>,
+}
+
+/// 이벤트 검색어를 조건으로 바꾼다. 잘못된 키나 값은 모두 모아 검증 오류로 돌려준다.
+pub async fn event_search_condition(
+    db: &DatabaseConnection,
+    project_id: i32,
+    user_id: i32,
+    query: &str,
+) -> Result<Condition, AppError> {
+    let terms = parse(query)?;
+    let releases = if compares_release(&terms) {
+        EventEntity::find()
+            .select_only()
+            .column(event::Column::AppVersion)
+            .distinct()
+            .filter(event::Column::ProjectId.eq(project_id))
+            .into_tuple()
+            .all(db)
+            .await?
+    } else {
+        Vec::new()
+    };
+
+    let ctx = SearchContext { project_id, user_id, now: Utc::now(), releases };
+    compile_event_terms(&terms, &ctx)
+}
+
+/// 이슈 검색어를 조건으로 바꾼다. 브라우저/환경 같은 이벤트 속성은 태그 색인으로 찾는다.
+pub async fn issue_search_condition(
+    db: &DatabaseConnection,
+    project_id: i32,
+    user_id: i32,
+    query: &str,
+) -> Result<Condition, AppError> {
+    let terms = parse(query)?;
+    let releases = if compares_release(&terms) {
+        IssueEntity::find()
+            .select_only()
+            .column(issue::Column::LastRelease)
+            .distinct()
+            .filter(issue::Column::ProjectId.eq(project_id))
+            .filter(issue::Column::LastRelease.is_not_null())
+            .into_tuple()
+            .all(db)
+            .await?
+    } else {
+        Vec::new()
+    };
+
+    let ctx = SearchContext { project_id, user_id, now: Utc::now(), releases };
+    compile_issue_terms(&terms, &ctx)
+}
+
+/// 트랜잭션 검색어를 조건으로 바꾼다.
+pub fn transaction_search_condition(query: &str) -> Result<Condition, AppError> {
+    let terms = parse(query)?;
+    let now = Utc::now();
+    compile(&terms, |text| transaction::Column::Name.contains(text).into_condition(), |filter| transaction_filter(filter, now))
+}
+
+fn parse(query: &str) -> Result<Vec<SearchTerm>, AppError> {
+    parse_search(query).map_err(|message| validation_error(vec![message]))
+}
+
+fn compile_event_terms(terms: &[SearchTerm], ctx: &SearchContext) -> Result<Condition, AppError> {
+    compile(terms, |text| event::Column::Message.contains(text).into_condition(), |filter| event_filter(filter, ctx))
+}
+
+fn compile_issue_terms(terms: &[SearchTerm], ctx: &SearchContext) -> Result<Condition, AppError> {
+    compile(terms, |text| issue::Column::Title.contains(text).into_condition(), |filter| issue_filter(filter, ctx))
+}
+
+fn compile(
+    terms: &[SearchTerm],
+    text: impl Fn(&str) -> Condition,
+    filter: impl Fn(&SearchFilter) -> Result<Condition, String>,
+) -> Result<Condition, AppError> {
+    let mut condition = Condition::all();
+    let mut errors = Vec::new();
+
+    for term in terms {
+        let (negated, compiled) = match term {
+            SearchTerm::Text { negated, value } => (*negated, Ok(text(value))),
+            SearchTerm::Filter(f) => (f.negated, filter(f)),
+        };
+        match compiled {
+            Ok(compiled) if negated => condition = condition.add(compiled.not()),
+            Ok(compiled) => condition = condition.add(compiled),
+            Err(message) => errors.push(message),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(condition)
+    } else {
+        Err(validation_error(errors))
+    }
+}
+
+fn event_filter(filter: &SearchFilter, ctx: &SearchContext) -> Result<Condition, String> {
+    let value = filter.value.as_str();
+
+    if let Some(tag) = tag_key(&filter.key) {
+        eq_only(filter)?;
+        return Ok(event::Column::Id.in_subquery(tagged_event_ids(ctx.project_id, tag, value)).into_condition());
+    }
+
+    let condition = match filter.key.as_str() {
+        "is" => {
+            eq_only(filter)?;
+            match value {
+                "unresolved" => event::Column::Status.eq(EventStatus::UNRESOLVED).into_condition(),
+                "resolved" => event::Column::Status.eq(EventStatus::RESOLVED).into_condition(),
+                "assigned" => event::Column::AssignedTo.is_not_null().into_condition(),
+                "unassigned" => event::Column::AssignedTo.is_null().into_condition(),
+                _ => return Err(invalid_value(filter, "unresolved, resolved, assigned, unassigned")),
+            }
+        }
+        "assigned" => assignee(filter, ctx, event::Column::AssignedTo)?,
+        "environment" => eq(filter, event::Column::Environment)?,
+        "browser" => Condition::any()
+            .add(eq(filter, event::Column::BrowserName)?)
+            .add(eq(filter, event::Column::Browser)?),
+        "os" => Condition::any()
+            .add(eq(filter, event::Column::OsName)?)
+            .add(eq(filter, event::Column::Os)?),
+        "device" => eq(filter, event::Column::DeviceType)?,
+        "country" => {
+            eq_only(filter)?;
+            present(event::Column::CountryCode, event::Column::CountryCode.eq(value.to_uppercase()))
+        }
+        "release" => release(filter, ctx, event::Column::AppVersion),
+        "message" => {
+            eq_only(filter)?;
+            event::Column::Message.contains(value).into_condition()
+        }
+        "issue" => {
+            eq_only(filter)?;
+            present(event::Column::IssueId, event::Column::IssueId.eq(parse_number::<i32>(filter)?))
+        }
+        "priority" => {
+            eq_only(filter)?;
+            let priority = match value.to_lowercase().as_str() {
+                "high" => Priority::HIGH,
+                "med" | "medium" => Priority::MED,
+                "low" => Priority::LOW,
+                _ => return Err(invalid_value(filter, "high, med, low")),
+            };
+            present(event::Column::Priority, event::Column::Priority.eq(priority))
+        }
+        "age" => relative_age(filter, ctx.now, event::Column::CreatedAt)?,
+        "timestamp" => absolute_time(filter, event::Column::Timestamp)?,
+        _ => return Err(unknown_key(filter, EVENT_KEYS)),
+    };
+
+    Ok(condition)
+}
+
+fn issue_filter(filter: &SearchFilter, ctx: &SearchContext) -> Result<Condition, String> {
+    let value = filter.value.as_str();
+
+    // 이벤트에서 온 속성은 모두 태그로 색인돼 있다.
+    let tag = match filter.key.as_str() {
+        "environment" | "browser" | "os" | "device" | "country" => Some(filter.key.as_str()),
+        key => tag_key(key),
+    };
+    if let Some(tag) = tag {
+        eq_only(filter)?;
+        let value = if tag == "country" { value.to_uppercase() } else { value.to_string() };
+        return Ok(issue::Column::Id.in_subquery(tagged_issue_ids(ctx.project_id, tag, &value)).into_condition());
+    }
+
+    let condition = match filter.key.as_str() {
+        "is" => {
+            eq_only(filter)?;
+            match value {
+                "unresolved" => issue::Column::Status
+                    .is_in([IssueStatus::Open, IssueStatus::InProgress, IssueStatus::Regressed])
+                    .into_condition(),
+                "open" => issue::Column::Status.eq(IssueStatus::Open).into_condition(),
+                "in_progress" => issue::Column::Status.eq(IssueStatus::InProgress).into_condition(),
+                "resolved" => issue::Column::Status.eq(IssueStatus::Resolved).into_condition(),
+                "ignored" => issue::Column::Status.eq(IssueStatus::Ignored).into_condition(),
+                "regressed" => issue::Column::Status.eq(IssueStatus::Regressed).into_condition(),
+                "assigned" => issue::Column::AssignedTo.is_not_null().into_condition(),
+                "unassigned" => issue::Column::AssignedTo.is_null().into_condition(),
+                _ => return Err(invalid_value(
+                    filter,
+                    "unresolved, open, in_progress, resolved, ignored, regressed, assigned, unassigned",
+                )),
+            }
+        }
+        "assigned" => assignee(filter, ctx, issue::Column::AssignedTo)?,
+        "release" => release(filter, ctx, issue::Column::LastRelease),
+        "message" => {
+            eq_only(filter)?;
+            issue::Column::Title.contains(value).into_condition()
+        }
+        "age" => relative_age(filter, ctx.now, issue::Column::FirstSeen)?,
+        "last_seen" => relative_age(filter, ctx.now, issue::Column::LastSeen)?,
+        "times_seen" => compare(issue::Column::Count, filter.op, parse_number::<i32>(filter)?).into_condition(),
+        _ => return Err(unknown_key(filter, ISSUE_KEYS)),
+    };
+
+    Ok(condition)
+}
+
+fn transaction_filter(filter: &SearchFilter, now: DateTime<Utc>) -> Result<Condition, String> {
+    let value = filter.value.as_str();
+
+    let condition = match filter.key.as_str() {
+        "name" => {
+            eq_only(filter)?;
+            transaction::Column::Name.contains(value).into_condition()
+        }
+        "environment" => eq(filter, transaction::Column::Environment)?,
+        "status" => eq(filter, transaction::Column::Status)?,
+        "trace" => eq(filter, transaction::Column::TraceId)?,
+        "duration" => {
+            let millis = parse_duration_ms(value).ok_or_else(|| {
+                format!("`duration`은 밀리초 숫자나 `500ms`, `2s`처럼 써야 합니다: `{}`", value)
+            })?;
+            compare(transaction::Column::DurationMs, filter.op, millis).into_condition()
+        }
+        "age" => relative_age(filter, now, transaction::Column::StartTimestamp)?,
+        "timestamp" => absolute_time(filter, transaction::Column::StartTimestamp)?,
+        _ => return Err(unknown_key(filter, TRANSACTION_KEYS)),
+    };
+
+    Ok(condition)
+}
+
+fn eq(filter: &SearchFilter, column: impl ColumnTrait) -> Result<Condition, String> {
+    eq_only(filter)?;
+    Ok(present(column, column.eq(filter.value.as_str())))
+}
+
+fn assignee(filter: &SearchFilter, ctx: &SearchContext, column: impl ColumnTrait) -> Result<Condition, String> {
+    eq_only(filter)?;
+    let condition = match filter.value.as_str() {
+        "me" => present(column, column.eq(ctx.user_id)),
+        "none" => column.is_null().into_condition(),
+        _ => present(column, column.eq(parse_number::<i32>(filter)?)),
+    };
+    Ok(condition)
+}
+
+// `NOT (col = v)`는 NULL 행을 빼버리므로 `!assigned:me`가 미배정 이벤트도 찾도록 NULL 검사를 함께 건다.
+fn present(column: impl ColumnTrait, expr: SimpleExpr) -> Condition {
+    Condition::all().add(column.is_not_null()).add(expr)
+}
+
+// 같다(`release:1.4`)는 그대로 비교하고, 크기 비교는 프로젝트의 버전 목록에서 골라 IN으로 찾는다.
+fn release(filter: &SearchFilter, ctx: &SearchContext, column: impl ColumnTrait) -> Condition {
+    if filter.op == SearchOp::Eq {
+        return present(column, column.eq(filter.value.as_str()));
+    }
+
+    let matching = ctx
+        .releases
+        .iter()
+        .filter(|release| op_matches(filter.op, compare_versions(release, &filter.value)))
+        .cloned()
+        .collect::<Vec<_>>();
+    present(column, column.is_in(matching))
+}
+
+fn relative_age(filter: &SearchFilter, now: DateTime<Utc>, column: impl ColumnTrait) -> Result<Condition, String> {
+    eq_only(filter)?;
+    let (newer, since) = parse_relative_age(&filter.value, now)?;
+
+    Ok(if newer { column.gte(since) } else { column.lt(since) }.into_condition())
+}
+
+fn absolute_time(filter: &SearchFilter, column: impl ColumnTrait) -> Result<Condition, String> {
+    let value = filter.value.as_str();
+    let at = DateTime::parse_from_rfc3339(value)
+        .map(|at| at.with_timezone(&Utc))
+        .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%m-%d").map(|date| date.and_hms_opt(0, 0, 0).unwrap().and_utc()))
+        .map_err(|_| format!("`{}`은 ISO8601 시각이나 `YYYY-MM-DD` 날짜여야 합니다: `{}`", filter.key, value))?;
+
+    Ok(compare(column, filter.op, at).into_condition())
+}
+
+fn compare<V: Into<Value>>(column: impl ColumnTrait, op: SearchOp, value: V) -> SimpleExpr {
+    match op {
+        SearchOp::Eq => column.eq(value),
+        SearchOp::Gt => column.gt(value),
+        SearchOp::Gte => column.gte(value),
+        SearchOp::Lt => column.lt(value),
+        SearchOp::Lte => column.lte(value),
+    }
+}
+
+fn op_matches(op: SearchOp, ordering: Ordering) -> bool {
+    match op {
+        SearchOp::Eq => ordering == Ordering::Equal,
+        SearchOp::Gt => ordering == Ordering::Greater,
+        SearchOp::Gte => ordering != Ordering::Less,
+        SearchOp::Lt => ordering == Ordering::Less,
+        SearchOp::Lte => ordering != Ordering::Greater,
+    }
+}
+
+fn compares_release(terms: &[SearchTerm]) -> bool {
+    terms.iter().any(|term| matches!(term, SearchTerm::Filter(f) if f.key == "release" && f.op != SearchOp::Eq))
+}
+
+fn parse_number<T: std::str::FromStr>(filter: &SearchFilter) -> Result<T, String> {
+    filter
+        .value
+        .parse()
+        .map_err(|_| format!("`{}`에는 숫자를 써야 합니다: `{}`", filter.key, filter.value))
+}
+
+fn parse_duration_ms(value: &str) -> Option<i32> {
+    if let Some(seconds) = value.strip_suffix('s').filter(|v| !v.ends_with('m')) {
+        return seconds.parse::<f64>().ok().map(|seconds| (seconds * 1000.0) as i32);
+    }
+    value.strip_suffix("ms").unwrap_or(value).parse().ok()
+}
+
+fn eq_only(filter: &SearchFilter) -> Result<(), String> {
+    if filter.op == SearchOp::Eq {
+        Ok(())
+    } else {
+        Err(format!("`{}`에는 크기 비교(>, <)를 쓸 수 없습니다.", filter.key))
+    }
+}
+
+fn invalid_value(filter: &SearchFilter, allowed: &str) -> String {
+    format!("`{}:{}`은 지원하지 않는 값입니다. (사용 가능: {})", filter.key, filter.value, allowed)
+}
+
+fn unknown_key(filter: &SearchFilter, allowed: &str) -> String {
+    format!("알 수 없는 검색 키입니다: `{}` (사용 가능: {})", filter.key, allowed)
+}
+
+fn validation_error(messages: Vec<String>) -> AppError {
+    AppError::ValidationError(
+        messages
+            .into_iter()
+            .map(|message| ValidationFieldError { field: "query".to_string(), message })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbBackend, QueryTrait};
+    use crate::entity::transaction::Entity as TransactionEntity;
+
+    fn context(releases: &[&str]) -> SearchContext {
+        SearchContext {
+            project_id: 1,
+            user_id: 7,
+            now: Utc::now(),
+            releases: releases.iter().map(|release| release.to_string()).collect(),
+        }
+    }
+
+    fn event_sql(query: &str, releases: &[&str]) -> String {
+        let condition = compile_event_terms(&parse(query).unwrap(), &context(releases)).unwrap();
+        EventEntity::find().filter(condition).build(DbBackend::MySql).to_string()
+    }
+
+    fn issue_sql(query: &str) -> String {
+        let condition = compile_issue_terms(&parse(query).unwrap(), &context(&[])).unwrap();
+        IssueEntity::find().filter(condition).build(DbBackend::MySql).to_string()
+    }
+
+    fn where_clause(sql: &str) -> &str {
+        &sql[sql.find(" WHERE ").unwrap() + " WHERE ".len()..]
+    }
+
+    #[test]
+    fn negated_filters_keep_null_rows() {
+        // NULL 행은 `NOT (col IS NOT NULL AND ...)`이 참이 되어 검색 결과에 남는다.
+        assert_eq!(
+            where_clause(&event_sql("!assigned:me", &[])),
+            "NOT (`event`.`assigned_to` IS NOT NULL AND `event`.`assigned_to` = 7)"
+        );
+        assert_eq!(
+            where_clause(&event_sql("!environment:production", &[])),
+            "NOT (`event`.`environment` IS NOT NULL AND `event`.`environment` = 'production')"
+        );
+        assert_eq!(where_clause(&event_sql("assigned:none", &[])), "`event`.`assigned_to` IS NULL");
+    }
+
+    #[test]
+    fn compares_releases_against_known_versions() {
+        assert_eq!(
+            where_clause(&event_sql("release:>=1.4", &["1.3", "1.4", "1.10"])),
+            "`event`.`app_version` IS NOT NULL AND `event`.`app_version` IN ('1.4', '1.10')"
+        );
+        assert_eq!(
+            where_clause(&event_sql("release:<1.4", &["1.3", "1.4", "1.10"])),
+            "`event`.`app_version` IS NOT NULL AND `event`.`app_version` IN ('1.3')"
+        );
+        // 맞는 버전이 없으면 아무 행도 찾지 않는다.
+        assert_eq!(where_clause(&event_sql("release:>9", &["1.3"])), "`event`.`app_version` IS NOT NULL AND 1 = 2");
+        assert_eq!(
+            where_clause(&event_sql("release:1.4", &[])),
+            "`event`.`app_version` IS NOT NULL AND `event`.`app_version` = '1.4'"
+        );
+    }
+
+    #[test]
+    fn compares_transaction_durations() {
+        let condition = transaction_search_condition("duration:>2s duration:<=500ms").unwrap();
+        let sql = TransactionEntity::find().filter(condition).build(DbBackend::MySql).to_string();
+        assert_eq!(where_clause(&sql), "`transaction`.`duration_ms` > 2000 AND `transaction`.`duration_ms` <= 500");
+
+        assert!(transaction_search_condition("duration:>2m").is_err());
+    }
+
+    #[test]
+    fn finds_tags_through_the_tag_index() {
+        assert_eq!(
+            where_clause(&event_sql("tags[browser.name]:Chrome checkout", &[])),
+            "`event`.`id` IN (SELECT `event_id` FROM `event_tags` WHERE `event_tags`.`project_id` = 1 \
+             AND `event_tags`.`key` = 'browser.name' AND `event_tags`.`value` = 'Chrome') \
+             AND `event`.`message` LIKE '%checkout%'"
+        );
+        assert_eq!(
+            where_clause(&issue_sql("country:kr")),
+            "`issues`.`id` IN (SELECT DISTINCT `issue_id` FROM `event_tags` WHERE `event_tags`.`project_id` = 1 \
+             AND `event_tags`.`key` = 'country' AND `event_tags`.`value` = 'KR' AND `event_tags`.`issue_id` IS NOT NULL)"
+        );
+    }
+
+    #[test]
+    fn collects_every_invalid_filter() {
+        let terms = parse("bogus:1 is:whatever tags[plan]:>1").unwrap();
+        let Err(AppError::ValidationError(errors)) = compile_event_terms(&terms, &context(&[])) else {
+            panic!("검증 오류가 나야 합니다");
+        };
+
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().all(|error| error.field == "query"));
+        assert_eq!(errors[0].message, format!("알 수 없는 검색 키입니다: `bogus` (사용 가능: {})", EVENT_KEYS));
+        assert!(errors[1].message.starts_with("`is:whatever`은 지원하지 않는 값입니다."));
+
+        assert!(matches!(transaction_search_condition("message:x"), Err(AppError::ValidationError(_))));
+    }
+}
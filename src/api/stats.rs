@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use actix_web::{get, web, HttpResponse};
+use chrono::{Duration, Utc};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect, Select};
+use sea_query::{Alias, Expr, Func, SimpleExpr};
+use crate::api::alert_rule::affected_user_key;
+use crate::api::project::check_project_member;
+use crate::entity::event::{self, Entity as EventEntity};
+use crate::entity::issue::{self, Entity as IssueEntity};
+use crate::entity::project_usage::{self, Entity as ProjectUsageEntity};
+use crate::model::global_error::{AppError, ValidationFieldError};
+use crate::model::stats::{StatKind, StatsGroupBy, StatsPeriod, StatsPoint, StatsQuery, StatsResponse, StatsSeries};
+use crate::util::stats::{fill_series, StatsInterval};
+
+// 한 번에 돌려줄 최대 구간 수 (분 단위로 하루)
+const MAX_BUCKETS: i64 = 1440;
+const DEFAULT_GROUP_LIMIT: usize = 10;
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/stats",
+    summary = "프로젝트 시계열 통계 조회",
+    description = "이벤트 수, 새 이슈 수, 영향받은 사용자 수, 버린 이벤트 수를 구간별로 센다. \
+                   이벤트 수와 사용자 수는 환경, 릴리스, 이슈별로 나눌 수 있다. 비어 있는 구간은 0으로 채운다.",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("stat" = Option<StatKind>, Query, description = "집계할 값 (events, new_issues, users, dropped). 기본값: events"),
+        ("interval" = Option<StatsInterval>, Query, description = "구간 (minute, hour, day). 기본값: hour"),
+        ("startDate" = Option<String>, Query, description = "시작 (ISO8601, 기본값: 종료 24시간 전)"),
+        ("endDate" = Option<String>, Query, description = "종료 (ISO8601, 기본값: 지금)"),
+        ("groupBy" = Option<StatsGroupBy>, Query, description = "나눌 기준 (environment, release, issue)"),
+        ("limit" = Option<usize>, Query, description = "돌려줄 그룹 수 (합계가 많은 순, 기본값: 10)"),
+    ),
+    responses(
+        (status = 200, description = "통계 조회 성공", body = StatsResponse),
+        (status = 400, description = "잘못된 요청", body = ValidationFieldError),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Stats"
+)]
+#[get("/projects/{project_id}/stats")]
+pub async fn get_project_stats(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    query: web::Query<StatsQuery>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let query = query.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+
+    let end = query.end_date.unwrap_or_else(Utc::now);
+    let start = query.start_date.unwrap_or(end - Duration::hours(24));
+    let interval = query.interval;
+    // 구간 목록은 개수를 확인한 뒤에 만든다. 긴 기간을 분 단위로 요청하면 목록만으로 메모리가 바닥난다.
+    validate_stats_query(&query, start < end, interval.bucket_count(start, end))?;
+    let buckets = interval.buckets(start, end);
+
+    let group = query.group_by.map(|group_by| match group_by {
+        StatsGroupBy::Environment => Expr::col(event::Column::Environment).into(),
+        StatsGroupBy::Release => Expr::col(event::Column::AppVersion).into(),
+        StatsGroupBy::Issue => Expr::cust_with_expr("CAST(? AS CHAR)", Expr::col(event::Column::IssueId)),
+    });
+
+    let rows = match query.stat {
+        StatKind::Events | StatKind::Users => {
+            let mut select = EventEntity::find()
+                .filter(event::Column::ProjectId.eq(project_id))
+                .filter(event::Column::CreatedAt.gte(start))
+                .filter(event::Column::CreatedAt.lte(end));
+            // 사용자 수는 알림 규칙과 같은 기준(SDK가 보낸 사용자, 없으면 보고한 멤버)으로 센다.
+            let count = if query.stat == StatKind::Users {
+                Func::count_distinct(affected_user_key()).into()
+            } else {
+                Expr::col(event::Column::Id).count()
+            };
+            if query.group_by == Some(StatsGroupBy::Issue) {
+                select = select.filter(event::Column::IssueId.is_not_null());
+            }
+            bucket_counts(db.get_ref(), select, event::Column::CreatedAt, interval, group, count).await?
+        }
+        StatKind::NewIssues => {
+            let select = IssueEntity::find()
+                .filter(issue::Column::ProjectId.eq(project_id))
+                .filter(issue::Column::FirstSeen.gte(start))
+                .filter(issue::Column::FirstSeen.lte(end));
+            bucket_counts(db.get_ref(), select, issue::Column::FirstSeen, interval, None, Expr::col(issue::Column::Id).count()).await?
+        }
+        StatKind::Dropped => {
+            // 수집량은 정시 단위로 기록되므로 시작 시각이 속한 시간부터 센다.
+            let select = ProjectUsageEntity::find()
+                .filter(project_usage::Column::ProjectId.eq(project_id))
+                .filter(project_usage::Column::Bucket.gte(StatsInterval::Hour.bucket_start(StatsInterval::Hour.bucket_of(start))))
+                .filter(project_usage::Column::Bucket.lte(end));
+            let dropped = Expr::cust_with_exprs(
+                "CAST(SUM(? + ? + ?) AS SIGNED)",
+                [
+                    Expr::col(project_usage::Column::RateLimited).into(),
+                    Expr::col(project_usage::Column::OverQuota).into(),
+                    Expr::col(project_usage::Column::SpikeDropped).into(),
+                ],
+            );
+            bucket_counts(db.get_ref(), select, project_usage::Column::Bucket, interval, None, dropped).await?
+        }
+    };
+
+    let limit = query.limit.unwrap_or(DEFAULT_GROUP_LIMIT);
+    let series = build_series(interval, &buckets, rows, query.group_by.is_some(), limit);
+
+    Ok(HttpResponse::Ok().json(StatsResponse {
+        stat: query.stat,
+        interval,
+        start,
+        end,
+        group_by: query.group_by,
+        series,
+    }))
+}
+
+/// 이슈 목록에 붙일 최근 추이. 이슈마다 기간 안의 구간별 이벤트 수를 오래된 순으로 돌려준다.
+pub async fn issue_sparklines(
+    db: &DatabaseConnection,
+    issue_ids: &[i32],
+    period: StatsPeriod,
+) -> Result<HashMap<i32, Vec<i64>>, AppError> {
+    if issue_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let interval = period.interval();
+    let now = Utc::now();
+    let last = interval.bucket_of(now);
+    let buckets = ((last - period.points() + 1)..=last).collect::<Vec<_>>();
+
+    let rows: Vec<(i32, i64, i64)> = EventEntity::find()
+        .select_only()
+        .column(event::Column::IssueId)
+        .expr_as(bucket_expr(event::Column::CreatedAt, interval), "time_bucket")
+        .expr(Expr::col(event::Column::Id).count())
+        .filter(event::Column::IssueId.is_in(issue_ids.iter().copied()))
+        .filter(event::Column::CreatedAt.gte(interval.bucket_start(buckets[0])))
+        .group_by(event::Column::IssueId)
+        .group_by(Expr::col(Alias::new("time_bucket")))
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    let mut counts: HashMap<i32, HashMap<i64, i64>> = HashMap::new();
+    for (issue_id, bucket, count) in rows {
+        counts.entry(issue_id).or_default().insert(bucket, count);
+    }
+
+    Ok(issue_ids
+        .iter()
+        .map(|issue_id| {
+            let series = counts.get(issue_id).map(|counts| fill_series(&buckets, counts));
+            (*issue_id, series.unwrap_or_else(|| vec![0; buckets.len()]))
+        })
+        .collect())
+}
+
+// 유닉스 시각을 구간 길이로 나눈 몫. DB 세션 시간대와 상관없이 UTC 경계로 나뉜다.
+fn bucket_expr(column: impl ColumnTrait, interval: StatsInterval) -> SimpleExpr {
+    Expr::cust_with_expr(format!("CAST(UNIX_TIMESTAMP(?) DIV {} AS SIGNED)", interval.seconds()), Expr::col(column))
+}
+
+// (구간, 그룹, 값) 목록
+async fn bucket_counts<E: EntityTrait>(
+    db: &DatabaseConnection,
+    select: Select<E>,
+    time_column: E::Column,
+    interval: StatsInterval,
+    group: Option<SimpleExpr>,
+    count: SimpleExpr,
+) -> Result<Vec<(i64, Option<String>, i64)>, AppError> {
+    let select = select
+        .select_only()
+        .expr_as(bucket_expr(time_column, interval), "time_bucket")
+        .group_by(Expr::col(Alias::new("time_bucket")));
+
+    let rows = match group {
+        Some(group) => select
+            .expr_as(group, "group_key")
+            .expr(count)
+            .group_by(Expr::col(Alias::new("group_key")))
+            .into_tuple()
+            .all(db)
+            .await?,
+        None => select
+            .expr(count)
+            .into_tuple::<(i64, i64)>()
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|(bucket, count)| (bucket, None, count))
+            .collect(),
+    };
+
+    Ok(rows)
+}
+
+// 그룹마다 모든 구간을 채우고 합계가 많은 그룹부터 `limit`개만 남긴다.
+fn build_series(
+    interval: StatsInterval,
+    buckets: &[i64],
+    rows: Vec<(i64, Option<String>, i64)>,
+    grouped: bool,
+    limit: usize,
+) -> Vec<StatsSeries> {
+    let mut by_group: HashMap<Option<String>, HashMap<i64, i64>> = HashMap::new();
+    if !grouped {
+        by_group.insert(None, HashMap::new());
+    }
+    for (bucket, group, count) in rows {
+        *by_group.entry(group).or_default().entry(bucket).or_default() += count;
+    }
+
+    let mut series = by_group
+        .into_iter()
+        .map(|(group, counts)| {
+            let values = fill_series(buckets, &counts);
+            StatsSeries {
+                group,
+                // 사용자 수는 구간마다 따로 센 값의 합이다.
+                total: values.iter().sum(),
+                points: buckets
+                    .iter()
+                    .zip(values)
+                    .map(|(bucket, count)| StatsPoint { bucket: interval.bucket_start(*bucket), count })
+                    .collect(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    series.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.group.cmp(&b.group)));
+    series.truncate(limit.max(1));
+    series
+}
+
+fn validate_stats_query(query: &StatsQuery, ordered: bool, bucket_count: i64) -> Result<(), AppError> {
+    let mut errors = Vec::new();
+
+    if !ordered {
+        errors.push(ValidationFieldError {
+            field: "startDate".to_string(),
+            message: "시작 시각은 종료 시각보다 앞서야 합니다.".to_string(),
+        });
+    } else if bucket_count > MAX_BUCKETS {
+        errors.push(ValidationFieldError {
+            field: "interval".to_string(),
+            message: format!("구간이 너무 많습니다 (최대 {}개). 기간을 줄이거나 더 큰 구간을 쓰세요.", MAX_BUCKETS),
+        });
+    }
+
+    if query.stat == StatKind::Dropped && query.interval == StatsInterval::Minute {
+        errors.push(ValidationFieldError {
+            field: "interval".to_string(),
+            message: "버린 이벤트 수는 1시간 단위로 기록되어 hour 또는 day로만 볼 수 있습니다.".to_string(),
+        });
+    }
+
+    if query.group_by.is_some() && matches!(query.stat, StatKind::NewIssues | StatKind::Dropped) {
+        errors.push(ValidationFieldError {
+            field: "groupBy".to_string(),
+            message: "groupBy는 events와 users에만 쓸 수 있습니다.".to_string(),
+        });
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(AppError::ValidationError(errors))
+    }
+}
@@ -0,0 +1,315 @@
+use std::collections::BTreeMap;
+use actix_web::{get, web, HttpResponse};
+use sea_orm::{ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, EntityTrait, FromQueryResult, QueryFilter, QuerySelect, QueryTrait, Select, Set};
+use sea_query::{Alias, Expr, Order, Query, SelectStatement};
+use crate::api::issue::find_issue;
+use crate::api::project::check_project_member;
+use crate::entity::event;
+use crate::entity::event_tag::{self, ActiveModel as EventTagActiveModel, Entity as EventTagEntity};
+use crate::model::global_error::{AppError, ValidationFieldError};
+use crate::model::tag::{TagFacetResponse, TagQuery, TagValueCount};
+
+// 태그 목록에서 키마다 보여줄 기본 값 수 / 분포 조회에서 돌려줄 최대 값 수
+const DEFAULT_FACET_VALUES: u64 = 10;
+const MAX_DISTRIBUTION_VALUES: u64 = 1000;
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/tags",
+    summary = "태그별 상위 값 조회",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("issueId" = Option<i32>, Query, description = "이슈 ID (비우면 프로젝트 전체)"),
+        ("startDate" = Option<String>, Query, description = "시작일 (ISO8601)"),
+        ("endDate" = Option<String>, Query, description = "종료일 (ISO8601)"),
+        ("limit" = Option<u64>, Query, description = "키마다 돌려줄 값 수 (기본값: 10)"),
+    ),
+    responses(
+        (status = 200, description = "태그 조회 성공", body = Vec<TagFacetResponse>),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "이슈 없음"),
+    ),
+    tag = "Event"
+)]
+#[get("/projects/{project_id}/tags")]
+pub async fn list_tag_facets(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    query: web::Query<TagQuery>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let query = query.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    if let Some(issue_id) = query.issue_id {
+        find_issue(db.get_ref(), project_id, issue_id).await?;
+    }
+
+    let limit = query.limit.unwrap_or(DEFAULT_FACET_VALUES).min(MAX_DISTRIBUTION_VALUES);
+    let rows = top_tag_values(db.get_ref(), tag_counts(project_id, &query), limit).await?;
+
+    Ok(HttpResponse::Ok().json(facets(rows)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/tags/{key}",
+    summary = "태그 값 분포 조회",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("key" = String, Path, description = "태그 키 (browser, os, app_version, environment 또는 사용자 태그)"),
+        ("issueId" = Option<i32>, Query, description = "이슈 ID (비우면 프로젝트 전체)"),
+        ("startDate" = Option<String>, Query, description = "시작일 (ISO8601)"),
+        ("endDate" = Option<String>, Query, description = "종료일 (ISO8601)"),
+        ("limit" = Option<u64>, Query, description = "돌려줄 값 수 (최대 1000)"),
+    ),
+    responses(
+        (status = 200, description = "태그 값 분포 조회 성공", body = TagFacetResponse),
+        (status = 403, description = "권한 없음"),
+        (status = 404, description = "이슈 없음"),
+    ),
+    tag = "Event"
+)]
+#[get("/projects/{project_id}/tags/{key}")]
+pub async fn get_tag_distribution(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<(i32, String)>,
+    query: web::Query<TagQuery>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let (project_id, key) = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let query = query.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    if let Some(issue_id) = query.issue_id {
+        find_issue(db.get_ref(), project_id, issue_id).await?;
+    }
+
+    let limit = query.limit.unwrap_or(MAX_DISTRIBUTION_VALUES).min(MAX_DISTRIBUTION_VALUES);
+    let select = tag_counts(project_id, &query).filter(event_tag::Column::Key.eq(key.as_str()));
+    let facet = facets(top_tag_values(db.get_ref(), select, limit).await?)
+        .pop()
+        .unwrap_or(TagFacetResponse { key, total: 0, distinct_values: 0, values: Vec::new() });
+
+    Ok(HttpResponse::Ok().json(facet))
+}
+
+/// 이벤트에 붙일 태그. 이벤트 필드에서 뽑은 기본 태그가 같은 이름의 사용자 태그보다 우선한다.
+pub fn event_tags(event: &event::Model, custom: &BTreeMap<String, String>) -> BTreeMap<String, String> {
+    let mut tags = custom.clone();
+
+    let builtin = [
+        ("browser", event.browser_name.clone().or_else(|| event.browser.clone())),
+        ("os", event.os_name.clone().or_else(|| event.os.clone())),
+        ("device", event.device_type.clone()),
+        ("country", event.country_code.clone()),
+        ("app_version", Some(event.app_version.clone())),
+        ("environment", Some(event.environment.clone())),
+    ];
+    for (key, value) in builtin {
+        if let Some(value) = value.filter(|value| !value.is_empty()) {
+            tags.insert(key.to_string(), value);
+        }
+    }
+
+    tags
+}
+
+/// 저장한 이벤트의 태그를 `event_tags`에 색인한다.
+pub async fn index_event_tags(
+    db: &DatabaseConnection,
+    event: &event::Model,
+    custom: &BTreeMap<String, String>,
+) -> Result<(), AppError> {
+    let rows = event_tags(event, custom)
+        .into_iter()
+        .map(|(key, value)| EventTagActiveModel {
+            event_id: Set(event.id),
+            project_id: Set(event.project_id),
+            issue_id: Set(event.issue_id),
+            key: Set(key),
+            value: Set(value),
+            created_at: Set(event.created_at),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    if !rows.is_empty() {
+        EventTagEntity::insert_many(rows).exec(db).await?;
+    }
+
+    Ok(())
+}
+
+/// `browser:Chrome,os:Windows` 형태의 태그 필터를 (키, 값) 목록으로 바꾼다.
+pub fn parse_tag_filters(raw: &str) -> Result<Vec<(String, String)>, AppError> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|filter| !filter.is_empty())
+        .map(|filter| match filter.split_once(':') {
+            Some((key, value)) if !key.is_empty() && !value.is_empty() => Ok((key.to_string(), value.to_string())),
+            _ => Err(AppError::ValidationError(vec![ValidationFieldError {
+                field: "tag".to_string(),
+                message: format!("태그 필터는 `키:값` 형태여야 합니다: {}", filter),
+            }])),
+        })
+        .collect()
+}
+
+/// 이 태그가 붙은 이벤트 ID 서브쿼리. `event::Column::Id.in_subquery`로 쓴다.
+pub fn tagged_event_ids(project_id: i32, key: &str, value: &str) -> SelectStatement {
+    Query::select()
+        .column(event_tag::Column::EventId)
+        .from(EventTagEntity)
+        .cond_where(
+            Condition::all()
+                .add(event_tag::Column::ProjectId.eq(project_id))
+                .add(event_tag::Column::Key.eq(key))
+                .add(event_tag::Column::Value.eq(value))
+        )
+        .to_owned()
+}
+
+/// 이 태그가 붙은 이벤트가 있는 이슈 ID 서브쿼리. `issue::Column::Id.in_subquery`로 쓴다.
+pub fn tagged_issue_ids(project_id: i32, key: &str, value: &str) -> SelectStatement {
+    Query::select()
+        .distinct()
+        .column(event_tag::Column::IssueId)
+        .from(EventTagEntity)
+        .cond_where(
+            Condition::all()
+                .add(event_tag::Column::ProjectId.eq(project_id))
+                .add(event_tag::Column::Key.eq(key))
+                .add(event_tag::Column::Value.eq(value))
+                .add(event_tag::Column::IssueId.is_not_null())
+        )
+        .to_owned()
+}
+
+/// 키, 값별 이벤트 수와 키 안에서의 순위, 키 전체 합계를 함께 센다.
+fn tag_counts(project_id: i32, query: &TagQuery) -> Select<EventTagEntity> {
+    let count = || Expr::col(event_tag::Column::Id).count();
+    let mut select = EventTagEntity::find()
+        .select_only()
+        .column(event_tag::Column::Key)
+        .column(event_tag::Column::Value)
+        .expr_as(count(), "count")
+        .expr_as(
+            Expr::cust_with_exprs(
+                "ROW_NUMBER() OVER (PARTITION BY ? ORDER BY ? DESC, ?)",
+                [Expr::col(event_tag::Column::Key).into(), count(), Expr::col(event_tag::Column::Value).into()],
+            ),
+            "value_rank",
+        )
+        // SUM 은 DECIMAL 을 돌려주므로 정수로 바꾼다.
+        .expr_as(Expr::cust_with_exprs("CAST(SUM(?) OVER (PARTITION BY ?) AS SIGNED)", [count(), Expr::col(event_tag::Column::Key).into()]), "total")
+        .expr_as(Expr::cust_with_expr("COUNT(*) OVER (PARTITION BY ?)", Expr::col(event_tag::Column::Key)), "distinct_values")
+        .filter(event_tag::Column::ProjectId.eq(project_id))
+        .group_by(event_tag::Column::Key)
+        .group_by(event_tag::Column::Value);
+
+    if let Some(issue_id) = query.issue_id {
+        select = select.filter(event_tag::Column::IssueId.eq(issue_id));
+    }
+    if let Some(start) = query.start_date {
+        select = select.filter(event_tag::Column::CreatedAt.gte(start));
+    }
+    if let Some(end) = query.end_date {
+        select = select.filter(event_tag::Column::CreatedAt.lte(end));
+    }
+
+    select
+}
+
+#[derive(Debug, FromQueryResult)]
+struct TagValueRow {
+    key: String,
+    value: String,
+    count: i64,
+    total: i64,
+    distinct_values: i64,
+}
+
+/// 키마다 많은 순 상위 `limit`개 값만 DB에서 가져온다. 값이 많은 키도 전부 읽지 않는다.
+fn top_tag_values_query(counts: Select<EventTagEntity>, limit: u64) -> SelectStatement {
+    let [key, value, count, rank, total, distinct_values] =
+        ["key", "value", "count", "value_rank", "total", "distinct_values"].map(Alias::new);
+
+    Query::select()
+        .columns([key.clone(), value.clone(), count.clone(), total, distinct_values])
+        .from_subquery(counts.into_query(), Alias::new("tag_counts"))
+        .and_where(Expr::col(rank).lte(limit))
+        .order_by(key, Order::Asc)
+        .order_by(count, Order::Desc)
+        .order_by(value, Order::Asc)
+        .to_owned()
+}
+
+async fn top_tag_values(db: &DatabaseConnection, counts: Select<EventTagEntity>, limit: u64) -> Result<Vec<TagValueRow>, AppError> {
+    let statement = db.get_database_backend().build(&top_tag_values_query(counts, limit));
+    Ok(TagValueRow::find_by_statement(statement).all(db).await?)
+}
+
+// 행은 키 순, 키 안에서는 많은 순으로 정렬돼 있다.
+fn facets(rows: Vec<TagValueRow>) -> Vec<TagFacetResponse> {
+    let mut facets: Vec<TagFacetResponse> = Vec::new();
+    for row in rows {
+        let value = TagValueCount { value: row.value, count: row.count };
+        match facets.last_mut() {
+            Some(facet) if facet.key == row.key => facet.values.push(value),
+            _ => facets.push(TagFacetResponse {
+                key: row.key,
+                total: row.total,
+                distinct_values: row.distinct_values as usize,
+                values: vec![value],
+            }),
+        }
+    }
+    facets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::DbBackend;
+
+    fn row(key: &str, value: &str, count: i64, total: i64, distinct_values: i64) -> TagValueRow {
+        TagValueRow { key: key.to_string(), value: value.to_string(), count, total, distinct_values }
+    }
+
+    fn query(issue_id: Option<i32>) -> TagQuery {
+        TagQuery { issue_id, start_date: None, end_date: None, limit: None }
+    }
+
+    #[test]
+    fn limits_values_per_key_in_sql() {
+        let sql = DbBackend::MySql.build(&top_tag_values_query(tag_counts(3, &query(Some(9))), 10)).to_string();
+
+        assert!(sql.contains("ROW_NUMBER() OVER (PARTITION BY `key` ORDER BY COUNT(`id`) DESC, `value`) AS `value_rank`"), "{sql}");
+        assert!(sql.contains("CAST(SUM(COUNT(`id`)) OVER (PARTITION BY `key`) AS SIGNED) AS `total`"), "{sql}");
+        assert!(sql.contains("COUNT(*) OVER (PARTITION BY `key`) AS `distinct_values`"), "{sql}");
+        assert!(sql.contains("`event_tags`.`project_id` = 3 AND `event_tags`.`issue_id` = 9"), "{sql}");
+        assert!(sql.ends_with("AS `tag_counts` WHERE `value_rank` <= 10 ORDER BY `key` ASC, `count` DESC, `value` ASC"), "{sql}");
+    }
+
+    #[test]
+    fn groups_rows_into_facets() {
+        let facets = facets(vec![
+            row("browser", "Chrome", 7, 12, 3),
+            row("browser", "Firefox", 4, 12, 3),
+            row("os", "Windows", 5, 5, 1),
+        ]);
+
+        assert_eq!(facets.len(), 2);
+        assert_eq!(facets[0].key, "browser");
+        // 상위 값만 받아도 합계와 값 종류 수는 키 전체 기준이다.
+        assert_eq!((facets[0].total, facets[0].distinct_values), (12, 3));
+        assert_eq!(facets[0].values.iter().map(|v| (v.value.as_str(), v.count)).collect::<Vec<_>>(), [("Chrome", 7), ("Firefox", 4)]);
+        assert_eq!((facets[1].key.as_str(), facets[1].total, facets[1].values.len()), ("os", 5, 1));
+
+        assert!(super::facets(Vec::new()).is_empty());
+    }
+}
@@ -1,47 +1,198 @@
-use crate::entity::{span, transaction};
-use crate::model::common::PaginationResponse;
+use crate::entity::{project, span, transaction};
+use crate::model::common::{PaginationMode, PaginationResponse};
 use crate::model::global_error::{AppError, ErrorCode};
 use crate::model::span::{SpanResponse, TransactionListQuery, TransactionWithSpansResponse};
 use crate::model::transaction::TransactionResponse;
-use actix_web::{get, post, web, HttpResponse};
+use actix_web::{get, post, web, HttpMessage, HttpRequest, HttpResponse};
+use crate::api::pagination::{fetch_cursor_page, parse_cursor, Keyset};
+use crate::api::search::transaction_search_condition;
+use crate::api::usage::IngestLimiter;
+use crate::api::project::{check_project_member, member_project_ids};
+use crate::api::scrubbing::project_scrubber;
+use crate::util::cursor::SortKey;
+use crate::amqp::AmqpClient;
+use crate::model::event::EventReportRequest;
+use crate::util::otlp::{any_value_to_json, attributes_to_json, json_to_string, span_exception, SpanException};
+use crate::util::scrub::Scrubber;
 use chrono::{DateTime, TimeZone, Utc};
-use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
-use opentelemetry_proto::tonic::common::v1::any_value::Value as AnyValueKind;
+use opentelemetry_proto::tonic::collector::trace::v1::{ExportTracePartialSuccess, ExportTraceServiceRequest, ExportTraceServiceResponse};
+use opentelemetry_proto::tonic::resource::v1::Resource;
+use opentelemetry_proto::tonic::trace::v1::span::SpanKind;
+use opentelemetry_proto::tonic::trace::v1::status::StatusCode;
+use opentelemetry_proto::tonic::trace::v1::{ResourceSpans, Status};
 use prost::Message;
-use rand::{rng, Rng};
-use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, DatabaseTransaction, EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait};
 use serde::Deserialize;
-use std::collections::HashMap;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::sync::Arc;
 use std::env;
 use std::sync::LazyLock;
-use tracing::error;
-use crate::api::event::SLACK_WEBHOOK_URL;
 use crate::model::transaction::TraceRequest;
-use crate::util::slack::send_slack_alert;
 
-pub fn generate_mixed_id() -> String {
-    const CHARSET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
-    let mut rng = rng();
+/// 받은 span 한 개. attribute는 이미 스크러빙한 상태다.
+struct IncomingSpan {
+    span_id: Vec<u8>,
+    parent_span_id: Vec<u8>, // root span이면 비어 있다
+    name: String,
+    kind: Option<&'static str>,
+    status_code: &'static str,
+    status_message: Option<String>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    attributes: Map<String, Value>,
+    events: Vec<Value>,
+    links: Vec<Value>,
+    resource: Arc<ResourceInfo>,
+    scrubbed: BTreeSet<String>,
+}
 
-    (0..8)
-        .map(|_| {
-            let idx = rng.random_range(0..CHARSET.len());
-            CHARSET[idx] as char
-        })
-        .collect()
+impl IncomingSpan {
+    // OTLP의 UNSET은 기존 transaction과 같이 ok로 본다.
+    fn transaction_status(&self) -> &'static str {
+        if self.status_code == "error" { "error" } else { "ok" }
+    }
+
+    fn attribute(&self, key: &str) -> Option<String> {
+        self.attributes.get(key).and_then(json_to_string)
+    }
 }
 
-// AnyValueKind → String
-fn any_to_string(kind: &AnyValueKind) -> Option<String> {
-    match kind {
-        AnyValueKind::StringValue(s) => Some(s.clone()),
-        AnyValueKind::IntValue(i) => Some(i.to_string()),
-        AnyValueKind::DoubleValue(d) => Some(d.to_string()),
-        AnyValueKind::BoolValue(b) => Some(b.to_string()),
+/// 받은 span을 trace ID별로 묶은 결과
+#[derive(Default)]
+struct IncomingTraces {
+    traces: BTreeMap<String, Vec<IncomingSpan>>,
+    rejected: i64, // trace ID나 시각이 잘못돼 저장하지 않은 span 수
+    exceptions: Vec<EventReportRequest>,
+}
+
+/// 같은 resource/scope의 span이 함께 쓰는 attribute. 이미 스크러빙한 상태다.
+struct ResourceInfo {
+    attributes: Map<String, Value>,
+    scope_name: Option<String>,
+    scope_version: Option<String>,
+    scope_attributes: Map<String, Value>,
+    scrubbed: BTreeSet<String>,
+}
+
+fn span_kind_name(kind: i32) -> Option<&'static str> {
+    match SpanKind::try_from(kind) {
+        Ok(SpanKind::Internal) => Some("internal"),
+        Ok(SpanKind::Server) => Some("server"),
+        Ok(SpanKind::Client) => Some("client"),
+        Ok(SpanKind::Producer) => Some("producer"),
+        Ok(SpanKind::Consumer) => Some("consumer"),
         _ => None,
     }
 }
 
+fn status_code_name(status: Option<&Status>) -> &'static str {
+    match status.and_then(|status| StatusCode::try_from(status.code).ok()) {
+        Some(StatusCode::Ok) => "ok",
+        Some(StatusCode::Error) => "error",
+        _ => "unset",
+    }
+}
+
+// attribute 객체를 스크러빙한다. 경로는 `attributes.<키>`처럼 `path` 아래로 남는다.
+fn scrub_attributes(scrubber: &Scrubber, path: &str, attributes: Map<String, Value>, scrubbed: &mut BTreeSet<String>) -> Map<String, Value> {
+    let mut value = Value::Object(attributes);
+    scrubber.scrub_json(path, &mut value, scrubbed);
+    match value {
+        Value::Object(attributes) => attributes,
+        _ => Map::new(),
+    }
+}
+
+/// OTLP/HTTP 본문 형식. 응답도 요청과 같은 형식으로 돌려준다.
+/// `Content-Encoding: gzip` 본문은 `web::Bytes`로 꺼낼 때 actix가 먼저 풀어준다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OtlpEncoding {
+    Protobuf,
+    Json,
+}
+
+impl OtlpEncoding {
+    // Content-Type이 없거나 모르는 값이면 OTLP 기본값인 protobuf로 본다.
+    fn of(req: &HttpRequest) -> Self {
+        if req.content_type().eq_ignore_ascii_case("application/json") {
+            OtlpEncoding::Json
+        } else {
+            OtlpEncoding::Protobuf
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            OtlpEncoding::Protobuf => "application/x-protobuf",
+            OtlpEncoding::Json => "application/json",
+        }
+    }
+
+    /// JSON은 OTLP JSON 매핑(16진수 ID, 문자열 int64)을 따르되, 숫자로 보낸 시각도 받는다.
+    fn decode(self, body: &[u8]) -> Result<ExportTraceServiceRequest, AppError> {
+        let decoded = match self {
+            OtlpEncoding::Protobuf => ExportTraceServiceRequest::decode(body).map_err(|e| e.to_string()),
+            OtlpEncoding::Json => serde_json::from_slice::<Value>(body)
+                .and_then(|mut value| {
+                    stringify_nano_times(&mut value);
+                    serde_json::from_value(value)
+                })
+                .map_err(|e| e.to_string()),
+        };
+
+        decoded.map_err(|e| {
+            log::error!("OTLP decode error ({}): {}", self.content_type(), e);
+            AppError::bad_request(ErrorCode::InvalidEvent)
+        })
+    }
+
+    fn respond(self, rejected_spans: i64) -> HttpResponse {
+        let response = export_response(rejected_spans);
+        let body = match self {
+            OtlpEncoding::Protobuf => response.encode_to_vec(),
+            // 생성된 serde 구현은 빈 partialSuccess를 null로 쓰므로 전부 받은 경우는 빈 객체로 보낸다.
+            OtlpEncoding::Json if response.partial_success.is_none() => b"{}".to_vec(),
+            OtlpEncoding::Json => serde_json::to_vec(&response).unwrap_or_default(),
+        };
+        HttpResponse::Ok().content_type(self.content_type()).body(body)
+    }
+}
+
+// OTLP/JSON은 int64를 문자열로 보내야 하지만 숫자로 보내는 exporter도 많다.
+// opentelemetry-proto는 나노초 시각을 문자열로만 받으므로 디코딩 전에 문자열로 바꾼다.
+const NANO_TIME_FIELDS: [&str; 3] = ["startTimeUnixNano", "endTimeUnixNano", "timeUnixNano"];
+
+fn stringify_nano_times(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match value {
+                    Value::Number(n) if NANO_TIME_FIELDS.contains(&key.as_str()) => {
+                        if let Some(nanos) = n.as_u64() {
+                            *value = Value::String(nanos.to_string());
+                        }
+                    }
+                    _ => stringify_nano_times(value),
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(stringify_nano_times),
+        _ => {}
+    }
+}
+
+/// 저장하지 못한 span이 있으면 `partial_success`에 그 수를 담는다.
+pub fn export_response(rejected_spans: i64) -> ExportTraceServiceResponse {
+    ExportTraceServiceResponse {
+        partial_success: (rejected_spans > 0).then(|| ExportTracePartialSuccess {
+            rejected_spans,
+            error_message: format!("{} spans rejected: invalid trace id or timestamp", rejected_spans),
+        }),
+    }
+}
+
 pub fn calculate_duration(start: &DateTime<Utc>, end: &DateTime<Utc>) -> i32 {
     (end.timestamp_millis() - start.timestamp_millis()) as i32
 }
@@ -55,13 +206,82 @@ fn format_utc(nano: u64) -> Result<DateTime<Utc>, &'static str> {
         .ok_or("Invalid timestamp")
 }
 
+/// OTLP 요청에 프로젝트 API 키를 싣는 헤더
+pub const API_KEY_HEADER: &str = "x-replay-api-key";
+
+/// 헤더를 붙일 수 없는 exporter는 resource attribute로 API 키를 보낸다.
+const API_KEY_RESOURCE_ATTRIBUTE: &str = "replay.api_key";
+
+// 헤더 키가 있으면 모든 resource에 쓰고, 없으면 resource마다 attribute에서 찾는다.
+fn resource_api_key(header_key: Option<&str>, resource: Option<&Resource>) -> Option<String> {
+    if let Some(key) = header_key {
+        return Some(key.to_string());
+    }
+
+    resource?
+        .attributes
+        .iter()
+        .find(|kv| kv.key == API_KEY_RESOURCE_ATTRIBUTE)
+        .and_then(|kv| kv.value.as_ref())
+        .and_then(|any| json_to_string(&any_value_to_json(any)))
+        .map(|key| key.trim().to_string())
+        .filter(|key| !key.is_empty())
+}
+
+// API 키별로 resource를 모은다. 키가 없는 resource가 하나라도 있으면 요청 전체를 거절한다.
+fn group_by_api_key(header_key: Option<&str>, resource_spans: Vec<ResourceSpans>) -> Result<Vec<(String, Vec<ResourceSpans>)>, AppError> {
+    let mut by_api_key: Vec<(String, Vec<ResourceSpans>)> = Vec::new();
+    for rs in resource_spans {
+        let api_key = resource_api_key(header_key, rs.resource.as_ref())
+            .ok_or_else(|| AppError::unauthorized(ErrorCode::InvalidApiKey))?;
+        match by_api_key.iter_mut().find(|(key, _)| *key == api_key) {
+            Some((_, group)) => group.push(rs),
+            None => by_api_key.push((api_key, vec![rs])),
+        }
+    }
+    Ok(by_api_key)
+}
+
+// 저장하기 전에 모든 키를 확인해서, 알 수 없는 키가 섞인 요청은 아무것도 저장하지 않는다.
+async fn admit_projects(
+    db: &DatabaseConnection,
+    limiter: &IngestLimiter,
+    by_api_key: Vec<(String, Vec<ResourceSpans>)>,
+) -> Result<Vec<(String, project::Model, Vec<ResourceSpans>)>, AppError> {
+    let mut by_project = Vec::with_capacity(by_api_key.len());
+    for (api_key, resource_spans) in by_api_key {
+        let span_count: usize = resource_spans
+            .iter()
+            .flat_map(|rs| &rs.scope_spans)
+            .map(|ss| ss.spans.len())
+            .sum();
+        let project = limiter.admit(db, &api_key, span_count.max(1) as u32).await?;
+        by_project.push((api_key, project, resource_spans));
+    }
+    Ok(by_project)
+}
+
+/// 사용자가 볼 수 있는 transaction 조건. `project_id`를 주면 호출하는 쪽에서 멤버인지 먼저 확인한다.
+fn transaction_scope(user_id: i32, project_id: Option<i32>) -> Condition {
+    match project_id {
+        Some(project_id) => Condition::all().add(transaction::Column::ProjectId.eq(project_id)),
+        None => Condition::all().add(transaction::Column::ProjectId.in_subquery(member_project_ids(user_id))),
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/api/trace",
+    description = "OTLP/HTTP trace 수집. `Content-Type: application/x-protobuf`(기본값) 또는 `application/json`, `Content-Encoding: gzip`을 받고 같은 형식의 `ExportTraceServiceResponse`를 돌려준다.",
     request_body = TraceRequest,
+    params(
+        ("x-replay-api-key" = Option<String>, Header, description = "프로젝트 API 키. 없으면 resource attribute `replay.api_key`를 쓴다"),
+    ),
     responses(
-        (status = 200, description = "Traces received successfully"),
-        (status = 400, description = "Invalid request", body = AppError),
+        (status = 200, description = "Traces received successfully (저장하지 못한 span 수는 partialSuccess.rejectedSpans)"),
+        (status = 400, description = "Invalid request 또는 알 수 없는 API 키", body = AppError),
+        (status = 401, description = "API 키 없음", body = AppError),
+        (status = 429, description = "수집 제한 초과"),
     ),
     security(
         ("api_key" = [])
@@ -71,35 +291,103 @@ fn format_utc(nano: u64) -> Result<DateTime<Utc>, &'static str> {
 )]
 #[post("/traces")]
 pub async fn receive_traces(
+    http_req: HttpRequest,
     body: web::Bytes,
     db: web::Data<DatabaseConnection>,
+    amqp: web::Data<AmqpClient>,
+    limiter: web::Data<IngestLimiter>,
 ) -> Result<HttpResponse, AppError> {
-    let req = ExportTraceServiceRequest::decode(body.as_ref())
-        .map_err(|e| {
-            log::error!("OTLP decode error: {}", e);
-            AppError::bad_request(ErrorCode::InvalidEvent)
-        })?;
+    let encoding = OtlpEncoding::of(&http_req);
+    let req = encoding.decode(&body)?;
+
+    let header_key = http_req
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|key| !key.is_empty());
+
+    let rejected = ingest_traces(db.get_ref(), amqp.get_ref(), limiter.get_ref(), header_key, req).await?;
+    Ok(encoding.respond(rejected))
+}
+
+/// OTLP trace 요청을 프로젝트별로 저장한다. HTTP와 gRPC 수집이 함께 쓴다.
+/// `header_key`는 헤더나 gRPC 메타데이터로 받은 API 키이고, 저장하지 못한 span 수를 돌려준다.
+/// 예외 span event는 `/events`로 받은 에러와 같이 이벤트 큐로 보낸다.
+pub async fn ingest_traces(
+    db: &DatabaseConnection,
+    amqp: &AmqpClient,
+    limiter: &IngestLimiter,
+    header_key: Option<&str>,
+    req: ExportTraceServiceRequest,
+) -> Result<i64, AppError> {
+    let by_api_key = group_by_api_key(header_key, req.resource_spans)?;
+    let by_project = admit_projects(db, limiter, by_api_key).await?;
+
+    let txn = db.begin().await?;
+    let mut stored = false;
+    let mut rejected: i64 = 0;
+    let mut exceptions = Vec::new();
+
+    for (api_key, project, resource_spans) in by_project {
+        let incoming = incoming_traces(&project_scrubber(&project), &api_key, resource_spans);
+        rejected += incoming.rejected;
+        exceptions.extend(incoming.exceptions);
 
-    println!("OTLP traces: {:?}", req);
+        for (trace_id, spans) in incoming.traces {
+            store_trace(&txn, project.id, trace_id, spans).await?;
+            stored = true;
+        }
+    }
 
-    let webhook_url = SLACK_WEBHOOK_URL.clone();
-    let alert_message = format!("🚨 OTLP Trace Alert 🚨\n\nTrace ID: {}\nNumber of Spans: {}", req.resource_spans.len(), req.resource_spans.iter().map(|rs| rs.scope_spans.len()).sum::<usize>());
+    if stored {
+        txn.commit().await?;
+    }
 
-    tokio::spawn(async move {
-        if let Err(e) = send_slack_alert(&webhook_url, &alert_message).await {
-            error!("Slack 알림 전송 실패: {:?}", e);
+    for event in &exceptions {
+        if let Err(e) = amqp.publish_event(event, None).await {
+            log::error!("예외 span event 발행 실패: {:?}", e);
         }
-    });
+    }
+
+    Ok(rejected)
+}
+
+/// 한 프로젝트로 받은 span을 스크러빙해서 trace ID별로 묶는다.
+fn incoming_traces(scrubber: &Scrubber, api_key: &str, resource_spans: Vec<ResourceSpans>) -> IncomingTraces {
+    let mut incoming = IncomingTraces::default();
+    for rs in resource_spans {
+        let mut resource_attributes = rs.resource.map(|resource| attributes_to_json(&resource.attributes)).unwrap_or_default();
+        // 인증에 쓴 키는 span과 함께 저장하지 않는다.
+        resource_attributes.remove(API_KEY_RESOURCE_ATTRIBUTE);
+
+        let mut resource_scrubbed = BTreeSet::new();
+        let resource_attributes = scrub_attributes(scrubber, "resource.attributes", resource_attributes, &mut resource_scrubbed);
 
-    let mut all_spans = Vec::new();
-    for rs in req.resource_spans {
         for ss in rs.scope_spans {
+            let scope = ss.scope.unwrap_or_default();
+            let mut scrubbed = resource_scrubbed.clone();
+            let scope_attributes = scrub_attributes(scrubber, "scope.attributes", attributes_to_json(&scope.attributes), &mut scrubbed);
+            let resource = Arc::new(ResourceInfo {
+                attributes: resource_attributes.clone(),
+                scope_name: Some(scope.name).filter(|name| !name.is_empty()),
+                scope_version: Some(scope.version).filter(|version| !version.is_empty()),
+                scope_attributes,
+                scrubbed,
+            });
+
             for span in ss.spans {
-                let trace_id = hex::encode(span.trace_id);
+                if span.trace_id.len() != 16 || span.trace_id.iter().all(|b| *b == 0) {
+                    log::warn!("Invalid trace id for span {}", span.name);
+                    incoming.rejected += 1;
+                    continue;
+                }
+
                 let start = match format_utc(span.start_time_unix_nano) {
                     Ok(ts) => ts,
                     Err(e) => {
                         log::warn!("Invalid start timestamp for span {}: {}", span.name, e);
+                        incoming.rejected += 1;
                         continue;
                     }
                 };
@@ -108,87 +396,269 @@ pub async fn receive_traces(
                     Ok(ts) => ts,
                     Err(e) => {
                         log::warn!("Invalid end timestamp for span {}: {}", span.name, e);
+                        incoming.rejected += 1;
                         continue;
                     }
                 };
 
-                let mut http = HashMap::new();
-                for kv in span.attributes {
-                    if let Some(val) = kv.value.and_then(|any| any.value).and_then(|k| any_to_string(&k)) {
-                        http.insert(kv.key, val);
+                let trace_id = hex::encode(&span.trace_id);
+                let mut scrubbed = BTreeSet::new();
+                let attributes = scrub_attributes(scrubber, "attributes", attributes_to_json(&span.attributes), &mut scrubbed);
+
+                let mut events = Vec::with_capacity(span.events.len());
+                for event in &span.events {
+                    let attributes = scrub_attributes(scrubber, "events[].attributes", attributes_to_json(&event.attributes), &mut scrubbed);
+                    let timestamp = format_utc(event.time_unix_nano).unwrap_or(end);
+
+                    if let Some(exception) = span_exception(&event.name, &attributes) {
+                        incoming.exceptions.push(exception_event(api_key, exception, timestamp, &resource, &trace_id, &span.span_id, &span.name));
                     }
+                    events.push(json!({
+                        "name": event.name,
+                        "timestamp": timestamp,
+                        "attributes": attributes,
+                    }));
                 }
-                all_spans.push((
-                    trace_id,
-                    span.span_id.clone(),
-                    span.parent_span_id.clone(),
-                    span.name.clone(),
+
+                let links = span
+                    .links
+                    .iter()
+                    .map(|link| {
+                        let attributes = scrub_attributes(scrubber, "links[].attributes", attributes_to_json(&link.attributes), &mut scrubbed);
+                        json!({
+                            "traceId": hex::encode(&link.trace_id),
+                            "spanId": hex::encode(&link.span_id),
+                            "traceState": Some(&link.trace_state).filter(|state| !state.is_empty()),
+                            "attributes": attributes,
+                        })
+                    })
+                    .collect();
+
+                let status_message = span
+                    .status
+                    .as_ref()
+                    .map(|status| status.message.clone())
+                    .filter(|message| !message.is_empty());
+
+                incoming.traces.entry(trace_id).or_default().push(IncomingSpan {
+                    span_id: span.span_id,
+                    parent_span_id: span.parent_span_id,
+                    kind: span_kind_name(span.kind),
+                    status_code: status_code_name(span.status.as_ref()),
+                    status_message,
+                    name: span.name,
                     start,
                     end,
-                    http,
-                ));
+                    attributes,
+                    events,
+                    links,
+                    resource: resource.clone(),
+                    scrubbed,
+                });
             }
         }
     }
 
-    if all_spans.is_empty() {
-        return Ok(HttpResponse::NoContent().finish());
-    }
+    incoming
+}
 
-    let txn = db.begin().await?;
+// 예외 span event를 SDK가 보낸 에러처럼 그룹핑되도록 이벤트로 바꾼다.
+fn exception_event(
+    api_key: &str,
+    exception: SpanException,
+    timestamp: DateTime<Utc>,
+    resource: &ResourceInfo,
+    trace_id: &str,
+    span_id: &[u8],
+    span_name: &str,
+) -> EventReportRequest {
+    let resource_attribute = |key: &str| resource.attributes.get(key).and_then(json_to_string);
 
-    let default_time = Utc::now();
-    let start_ts = all_spans.iter()
-        .map(|s| &s.4)
-        .min()
-        .unwrap_or(&default_time);
-
-    let end_ts = all_spans.iter()
-        .map(|s| &s.5)
-        .max()
-        .unwrap_or(&default_time);
-
-    let trace_id = generate_mixed_id();
-    let tx_active = transaction::ActiveModel::new(
-        1,
-        trace_id.clone(),
-        "unified_transaction",
-        *start_ts,
-        *end_ts,
-        "production",
-        "ok",
-        None,
-    );
+    EventReportRequest {
+        message: exception.title(),
+        stacktrace: exception.stacktrace.unwrap_or_default(),
+        app_version: resource_attribute("service.version").unwrap_or_else(|| "unknown".to_string()),
+        timestamp,
+        replay: None,
+        environment: resource_attribute("deployment.environment.name").or_else(|| resource_attribute("deployment.environment")),
+        browser: None,
+        os: None,
+        user_agent: None,
+        api_key: api_key.to_string(),
+        user_id: None,
+        additional_info: Some(json!({
+            "traceId": trace_id,
+            "spanId": hex::encode(span_id),
+            "spanName": span_name,
+        })),
+        breadcrumbs: Vec::new(),
+        tags: resource_attribute("service.name")
+            .map(|service| BTreeMap::from([("service".to_string(), service)]))
+            .unwrap_or_default(),
+        user: None,
+        request: None,
+        contexts: None,
+    }
+}
 
-    let tx_inserted: transaction::Model = tx_active.insert(&txn).await?;
+/// trace 하나에서 transaction에 쓸 값
+#[derive(Debug, PartialEq)]
+struct TraceSummary {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    name: String,
+    kind: Option<&'static str>,
+    status: &'static str,
+    has_root: bool,
+}
 
-    for (orig_trace_id, span_id, parent, name, start, end, http) in all_spans {
-        let mut http_with_orig_trace = http.clone();
-        http_with_orig_trace.insert("original_trace_id".to_string(), orig_trace_id);
+impl TraceSummary {
+    fn of(spans: &[IncomingSpan]) -> Option<Self> {
+        let start = spans.iter().map(|s| s.start).min()?;
+        let end = spans.iter().map(|s| s.end).max()?;
+        let root = spans.iter().find(|s| s.parent_span_id.is_empty());
+        // root span이 아직 오지 않았으면 가장 먼저 시작한 span 이름을 임시로 쓴다.
+        let name = root
+            .or_else(|| spans.iter().min_by_key(|s| s.start))
+            .map(|s| s.name.clone())
+            .unwrap_or_default();
 
-        let span_active = span::ActiveModel::new(
-            tx_inserted.id,
-            span_id.clone(),
-            Some(parent),
-            name.clone(),
+        Some(Self {
             start,
             end,
-            http.get("http.method").cloned(),
-            http.get("http.url").cloned(),
-            http.get("http.status_code").and_then(|v| v.parse().ok()),
-            http.get("http.status_text").cloned(),
-            http.get("http.response_content_length").and_then(|v| v.parse().ok()),
-            http.get("http.host").cloned(),
-            http.get("http.scheme").cloned(),
-            http.get("http.user_agent").cloned(),
-            Some(serde_json::to_value(&http_with_orig_trace).unwrap_or_default()),
+            name,
+            kind: root.and_then(|root| root.kind),
+            status: root.map_or("ok", IncomingSpan::transaction_status),
+            has_root: root.is_some(),
+        })
+    }
+
+    fn new_transaction(&self, project_id: i32, trace_id: &str) -> transaction::ActiveModel {
+        let mut active = transaction::ActiveModel::new(
+            project_id,
+            trace_id,
+            self.name.clone(),
+            self.start,
+            self.end,
+            "production",
+            self.status,
+            None,
+        );
+        active.kind = Set(self.kind.map(str::to_string));
+        // `Entity::insert`는 before_save를 부르지 않는다.
+        active.created_at = Set(Utc::now());
+        active
+    }
+
+    /// 이미 있는 transaction에 span을 붙인다. 시작/끝 시각을 넓히고, root span이 있으면 그 이름, 종류, 상태를 쓴다.
+    fn merge_into(&self, existing: transaction::Model) -> transaction::ActiveModel {
+        let start = self.start.min(existing.start_timestamp);
+        let end = self.end.max(existing.end_timestamp);
+
+        let mut active: transaction::ActiveModel = existing.into();
+        active.start_timestamp = Set(start);
+        active.end_timestamp = Set(end);
+        active.duration_ms = Set(calculate_duration(&start, &end));
+        if self.has_root {
+            active.name = Set(self.name.clone());
+            active.kind = Set(self.kind.map(str::to_string));
+            active.status = Set(self.status.to_string());
+        }
+        active
+    }
+}
+
+// exporter가 재전송한 span은 한 번만 저장한다. 같은 요청 안의 중복도 거른다.
+fn unseen_spans(mut known_span_ids: HashSet<Vec<u8>>, spans: Vec<IncomingSpan>) -> Vec<IncomingSpan> {
+    spans
+        .into_iter()
+        .filter(|span| known_span_ids.insert(span.span_id.clone()))
+        .collect()
+}
+
+/// trace 하나의 span을 저장한다. 같은 trace의 transaction이 이미 있으면 거기에 붙인다.
+async fn store_trace(
+    txn: &DatabaseTransaction,
+    project_id: i32,
+    trace_id: String,
+    spans: Vec<IncomingSpan>,
+) -> Result<(), AppError> {
+    let Some(summary) = TraceSummary::of(&spans) else {
+        return Ok(());
+    };
+
+    // (project_id, trace_id)는 유일 인덱스다. 먼저 넣어 보고(이미 있으면 두고) 잠가서 다시 읽으면
+    // 같은 trace를 동시에 받아도 transaction이 하나만 생기고, 없는 행을 잠글 때의 갭 락 교착도 생기지 않는다.
+    transaction::Entity::insert(summary.new_transaction(project_id, &trace_id))
+        .on_conflict(
+            OnConflict::columns([transaction::Column::ProjectId, transaction::Column::TraceId])
+                .update_column(transaction::Column::ProjectId)
+                .to_owned()
+        )
+        .exec_without_returning(txn)
+        .await?;
+
+    let existing = transaction::Entity::find()
+        .filter(transaction::Column::ProjectId.eq(project_id))
+        .filter(transaction::Column::TraceId.eq(trace_id.as_str()))
+        .lock_exclusive()
+        .one(txn)
+        .await?
+        .ok_or_else(|| AppError::internal_error(ErrorCode::DatabaseError))?;
+    let transaction_id = existing.id;
+
+    let known_span_ids: HashSet<Vec<u8>> = span::Entity::find()
+        .select_only()
+        .column(span::Column::SpanId)
+        .filter(span::Column::TransactionId.eq(transaction_id))
+        .into_tuple()
+        .all(txn)
+        .await?
+        .into_iter()
+        .collect();
+
+    summary.merge_into(existing).update(txn).await?;
+
+    for incoming in unseen_spans(known_span_ids, spans) {
+
+        let mut span_active = span::ActiveModel::new(
+            transaction_id,
+            incoming.span_id.clone(),
+            (!incoming.parent_span_id.is_empty()).then(|| incoming.parent_span_id.clone()),
+            incoming.name.clone(),
+            incoming.start,
+            incoming.end,
+            incoming.attribute("http.method"),
+            incoming.attribute("http.url"),
+            incoming.attribute("http.status_code").and_then(|v| v.parse().ok()),
+            incoming.attribute("http.status_text"),
+            incoming.attribute("http.response_content_length").and_then(|v| v.parse().ok()),
+            incoming.attribute("http.host"),
+            incoming.attribute("http.scheme"),
+            incoming.attribute("http.user_agent"),
+            Some(Value::Object(incoming.attributes)),
         );
 
-        span_active.insert(&txn).await?;
+        let resource = &incoming.resource;
+        span_active.kind = Set(incoming.kind.map(str::to_string));
+        span_active.status_code = Set(Some(incoming.status_code.to_string()));
+        span_active.status_message = Set(incoming.status_message);
+        span_active.events = Set((!incoming.events.is_empty()).then_some(Value::Array(incoming.events)));
+        span_active.links = Set((!incoming.links.is_empty()).then_some(Value::Array(incoming.links)));
+        span_active.resource_attributes = Set((!resource.attributes.is_empty()).then(|| Value::Object(resource.attributes.clone())));
+        span_active.scope_name = Set(resource.scope_name.clone());
+        span_active.scope_version = Set(resource.scope_version.clone());
+        span_active.scope_attributes = Set((!resource.scope_attributes.is_empty()).then(|| Value::Object(resource.scope_attributes.clone())));
+
+        let mut scrubbed = incoming.scrubbed;
+        scrubbed.extend(resource.scrubbed.iter().cloned());
+        if !scrubbed.is_empty() {
+            span_active.scrubbed_fields = Set(serde_json::to_value(&scrubbed).ok());
+        }
+        span_active.insert(txn).await?;
     }
 
-    txn.commit().await?;
-    Ok(HttpResponse::Ok().finish())
+    Ok(())
 }
 
 #[utoipa::path(
@@ -198,10 +668,15 @@ pub async fn receive_traces(
     params(
         ("page" = i32, Query, description = "Page number", example = 1),
         ("size" = i32, Query, description = "Page size", example = 10),
+        ("projectId" = Option<i32>, Query, description = "프로젝트 ID (비우면 멤버인 모든 프로젝트)"),
+        ("query" = Option<String>, Query, description = "검색어 (예: environment:production duration:>500ms age:-24h)"),
+        ("pagination" = Option<PaginationMode>, Query, description = "페이지 방식 (offset, cursor). cursor는 전체 개수를 세지 않는다"),
+        ("cursor" = Option<String>, Query, description = "이전 응답의 nextCursor/prevCursor. 주면 cursor 방식으로 조회한다"),
     ),
     responses(
         (status = 200, description = "Transactions retrieved successfully", body = PaginationResponse<TransactionResponse>),
         (status = 400, description = "Invalid request", body = AppError),
+        (status = 403, description = "권한 없음"),
     ),
     security(
         ("api_key" = [])
@@ -210,19 +685,47 @@ pub async fn receive_traces(
 )]
 #[get("/transactions")]
 pub async fn get_transactions(
+    req: HttpRequest,
     query: web::Query<TransactionListQuery>,
     db: web::Data<DatabaseConnection>,
+    auth_user: web::ReqData<i32>,
 ) -> Result<HttpResponse, AppError> {
+    let user_id = auth_user.into_inner();
     let page = query.page;
     let size = query.size;
 
     let offset = ((page - 1) * size) as u64;
 
+    if let Some(project_id) = query.project_id {
+        check_project_member(db.get_ref(), project_id, user_id).await?;
+    }
+    let mut condition = transaction_scope(user_id, query.project_id);
+    if let Some(search_query) = query.query.as_deref().filter(|q| !q.trim().is_empty()) {
+        condition = condition.add(transaction_search_condition(search_query)?);
+    }
+
+    let cursor = parse_cursor(query.cursor.as_deref())?;
+    if query.pagination == Some(PaginationMode::Cursor) || cursor.is_some() {
+        let page_size = size.clamp(1, 100) as u32;
+        let page = fetch_cursor_page(
+            db.as_ref(),
+            transaction::Entity::find().filter(condition),
+            Keyset { sort_column: transaction::Column::StartTimestamp, id_column: transaction::Column::Id, order: Order::Desc },
+            cursor,
+            page_size,
+            |transaction| (SortKey::Time(transaction.start_timestamp), transaction.id),
+        )
+        .await?;
+        return Ok(page.respond(&req, page_size, TransactionResponse::from));
+    }
+
     let total = transaction::Entity::find()
+        .filter(condition.clone())
         .count(db.as_ref())
         .await?;
 
     let transactions = transaction::Entity::find()
+        .filter(condition)
         .order_by_desc(transaction::Column::StartTimestamp)
         .offset(offset)
         .limit(size as u64)
@@ -264,11 +767,15 @@ pub async fn get_transactions(
 pub async fn get_transaction_spans(
     path: web::Path<String>,
     db: web::Data<DatabaseConnection>,
+    auth_user: web::ReqData<i32>,
 ) -> Result<HttpResponse, AppError> {
     let trace_id = path.into_inner();
+    let user_id = auth_user.into_inner();
 
+    // 멤버가 아닌 프로젝트의 transaction은 없는 것으로 본다.
     let transaction = transaction::Entity::find()
         .filter(transaction::Column::TraceId.eq(trace_id.clone()))
+        .filter(transaction_scope(user_id, None))
         .one(db.as_ref())
         .await?
         .ok_or_else(|| AppError::not_found(ErrorCode::TransactionNotFound))?;
@@ -291,4 +798,289 @@ pub async fn get_transaction_spans(
     };
 
     Ok(HttpResponse::Ok().json(response))
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::usage::IngestLimitConfig;
+    use opentelemetry_proto::tonic::common::v1::any_value::Value as AnyValueKind;
+    use opentelemetry_proto::tonic::common::v1::{AnyValue, KeyValue};
+    use opentelemetry_proto::tonic::trace::v1::{ScopeSpans, Span};
+    use sea_orm::{ActiveValue, DbBackend, QueryTrait};
+    use crate::model::scrubbing::ScrubbingRules;
+
+    fn resource_spans(api_key: Option<&str>, service: &str) -> ResourceSpans {
+        let mut attributes = vec![KeyValue {
+            key: "service.name".to_string(),
+            value: Some(AnyValue { value: Some(AnyValueKind::StringValue(service.to_string())) }),
+        }];
+        if let Some(api_key) = api_key {
+            attributes.push(KeyValue {
+                key: API_KEY_RESOURCE_ATTRIBUTE.to_string(),
+                value: Some(AnyValue { value: Some(AnyValueKind::StringValue(api_key.to_string())) }),
+            });
+        }
+        ResourceSpans {
+            resource: Some(Resource { attributes, ..Default::default() }),
+            ..Default::default()
+        }
+    }
+
+    fn keys(groups: &[(String, Vec<ResourceSpans>)]) -> Vec<(&str, usize)> {
+        groups.iter().map(|(key, group)| (key.as_str(), group.len())).collect()
+    }
+
+    #[test]
+    fn header_key_wins_over_resource_attribute() {
+        let rs = resource_spans(Some("proj_resource"), "web");
+        assert_eq!(resource_api_key(Some("proj_header"), rs.resource.as_ref()).as_deref(), Some("proj_header"));
+        assert_eq!(resource_api_key(None, rs.resource.as_ref()).as_deref(), Some("proj_resource"));
+        assert_eq!(resource_api_key(None, resource_spans(Some("  "), "web").resource.as_ref()), None);
+        assert_eq!(resource_api_key(None, None), None);
+    }
+
+    #[test]
+    fn groups_resources_by_api_key() {
+        let groups = group_by_api_key(None, vec![
+            resource_spans(Some("proj_a"), "web"),
+            resource_spans(Some("proj_b"), "api"),
+            resource_spans(Some("proj_a"), "worker"),
+        ])
+        .unwrap();
+        assert_eq!(keys(&groups), [("proj_a", 2), ("proj_b", 1)]);
+
+        // 헤더 키가 있으면 resource attribute와 상관없이 모두 그 키로 받는다.
+        let groups = group_by_api_key(Some("proj_header"), vec![resource_spans(Some("proj_a"), "web"), resource_spans(None, "api")]).unwrap();
+        assert_eq!(keys(&groups), [("proj_header", 2)]);
+    }
+
+    #[test]
+    fn missing_api_key_is_unauthorized() {
+        let result = group_by_api_key(None, vec![resource_spans(Some("proj_a"), "web"), resource_spans(None, "api")]);
+        assert!(matches!(result, Err(AppError::Unauthorized(ErrorCode::InvalidApiKey))));
+    }
+
+    #[tokio::test]
+    async fn unknown_api_key_is_rejected() {
+        let limiter = IngestLimiter::new(IngestLimitConfig { key_rate: 10, key_burst: 10, project_rate: 10, project_burst: 10 });
+        limiter.remember_unknown_key("proj_unknown");
+
+        let groups = group_by_api_key(None, vec![resource_spans(Some("proj_unknown"), "web")]).unwrap();
+        let result = admit_projects(&DatabaseConnection::default(), &limiter, groups).await;
+        assert!(matches!(result, Err(AppError::BadRequest(ErrorCode::InvalidApiKey))));
+    }
+
+    #[test]
+    fn scopes_transactions_to_member_projects() {
+        let sql = transaction::Entity::find().filter(transaction_scope(5, None)).build(DbBackend::MySql).to_string();
+        assert!(
+            sql.ends_with("WHERE `transaction`.`project_id` IN (SELECT `project_id` FROM `project_members` WHERE `project_members`.`user_id` = 5)"),
+            "{sql}"
+        );
+
+        let sql = transaction::Entity::find().filter(transaction_scope(5, Some(3))).build(DbBackend::MySql).to_string();
+        assert!(sql.ends_with("WHERE `transaction`.`project_id` = 3"), "{sql}");
+    }
+
+    const SECOND: u64 = 1_000_000_000;
+    const BASE: u64 = 1_745_107_200 * SECOND; // 2025-04-20T00:00:00Z
+
+    fn span(trace: u8, id: u8, parent: Option<u8>, name: &str, start: u64, end: u64) -> Span {
+        Span {
+            trace_id: vec![trace; 16],
+            span_id: vec![id; 8],
+            parent_span_id: parent.map(|parent| vec![parent; 8]).unwrap_or_default(),
+            name: name.to_string(),
+            kind: SpanKind::Server as i32,
+            start_time_unix_nano: BASE + start * SECOND,
+            end_time_unix_nano: BASE + end * SECOND,
+            ..Default::default()
+        }
+    }
+
+    fn with_spans(mut rs: ResourceSpans, spans: Vec<Span>) -> ResourceSpans {
+        rs.scope_spans.push(ScopeSpans { spans, ..Default::default() });
+        rs
+    }
+
+    fn incoming(spans: Vec<Span>) -> IncomingTraces {
+        let scrubber = Scrubber::new(&ScrubbingRules::default()).unwrap();
+        incoming_traces(&scrubber, "proj_a", vec![with_spans(resource_spans(Some("proj_a"), "web"), spans)])
+    }
+
+    fn at(seconds: u64) -> DateTime<Utc> {
+        format_utc(BASE + seconds * SECOND).unwrap()
+    }
+
+    fn transaction(name: &str, start: u64, end: u64) -> transaction::Model {
+        transaction::Model {
+            id: 1,
+            project_id: 1,
+            trace_id: hex::encode([1u8; 16]),
+            name: name.to_string(),
+            kind: None,
+            start_timestamp: at(start),
+            end_timestamp: at(end),
+            duration_ms: ((end - start) * 1000) as i32,
+            environment: "production".to_string(),
+            status: "ok".to_string(),
+            tags: None,
+            created_at: at(end),
+        }
+    }
+
+    #[test]
+    fn groups_spans_by_trace_id() {
+        let scrubber = Scrubber::new(&ScrubbingRules::default()).unwrap();
+        let incoming = incoming_traces(&scrubber, "proj_a", vec![
+            with_spans(resource_spans(Some("proj_a"), "web"), vec![span(1, 1, None, "GET /", 0, 3), span(2, 2, None, "GET /health", 0, 1)]),
+            with_spans(resource_spans(Some("proj_a"), "api"), vec![span(1, 3, Some(1), "SELECT", 1, 2)]),
+        ]);
+
+        let traces = incoming.traces.iter().map(|(trace_id, spans)| (trace_id.clone(), spans.len())).collect::<Vec<_>>();
+        assert_eq!(traces, [(hex::encode([1u8; 16]), 2), (hex::encode([2u8; 16]), 1)]);
+        assert_eq!(incoming.rejected, 0);
+
+        // 인증에 쓴 키는 저장하지 않는다.
+        let resource = &incoming.traces[&hex::encode([1u8; 16])][0].resource;
+        assert!(!resource.attributes.contains_key(API_KEY_RESOURCE_ATTRIBUTE));
+        assert_eq!(resource.attributes["service.name"], "web");
+    }
+
+    #[test]
+    fn rejects_invalid_trace_ids() {
+        let mut short = span(1, 1, None, "short", 0, 1);
+        short.trace_id.truncate(8);
+
+        let incoming = incoming(vec![short, span(0, 3, None, "zero", 0, 1), span(1, 4, None, "ok", 0, 1)]);
+        assert_eq!(incoming.rejected, 2);
+        assert_eq!(incoming.traces.values().flatten().map(|s| s.name.as_str()).collect::<Vec<_>>(), ["ok"]);
+    }
+
+    #[test]
+    fn names_transaction_after_root_or_earliest_span() {
+        let spans = incoming(vec![span(1, 2, Some(1), "SELECT", 2, 3), span(1, 1, None, "GET /", 1, 5)]).traces.into_values().next().unwrap();
+        let summary = TraceSummary::of(&spans).unwrap();
+        assert_eq!((summary.name.as_str(), summary.kind, summary.has_root), ("GET /", Some("server"), true));
+        assert_eq!((summary.start, summary.end), (at(1), at(5)));
+
+        let spans = incoming(vec![span(1, 3, Some(1), "render", 4, 6), span(1, 2, Some(1), "SELECT", 2, 3)]).traces.into_values().next().unwrap();
+        let summary = TraceSummary::of(&spans).unwrap();
+        assert_eq!((summary.name.as_str(), summary.has_root), ("SELECT", false));
+
+        assert_eq!(TraceSummary::of(&[]), None);
+    }
+
+    #[test]
+    fn appends_late_spans_to_existing_transaction() {
+        // root 없이 늦게 온 span은 시각만 넓히고 이름은 그대로 둔다.
+        let spans = incoming(vec![span(1, 4, Some(1), "flush", 8, 12)]).traces.into_values().next().unwrap();
+        let merged = TraceSummary::of(&spans).unwrap().merge_into(transaction("GET /", 1, 5));
+        assert_eq!(merged.start_timestamp, ActiveValue::Set(at(1)));
+        assert_eq!(merged.end_timestamp, ActiveValue::Set(at(12)));
+        assert_eq!(merged.duration_ms, ActiveValue::Set(11_000));
+        assert_eq!(merged.name, ActiveValue::Unchanged("GET /".to_string()));
+
+        // 나중에 온 root span은 임시 이름을 바꾼다.
+        let spans = incoming(vec![span(1, 1, None, "GET /", 0, 5)]).traces.into_values().next().unwrap();
+        let merged = TraceSummary::of(&spans).unwrap().merge_into(transaction("SELECT", 2, 3));
+        assert_eq!(merged.start_timestamp, ActiveValue::Set(at(0)));
+        assert_eq!(merged.name, ActiveValue::Set("GET /".to_string()));
+        assert_eq!(merged.kind, ActiveValue::Set(Some("server".to_string())));
+    }
+
+    #[test]
+    fn skips_known_and_repeated_span_ids() {
+        let spans = incoming(vec![span(1, 1, None, "GET /", 0, 5), span(1, 2, Some(1), "SELECT", 1, 2), span(1, 2, Some(1), "SELECT", 1, 2)])
+            .traces
+            .into_values()
+            .next()
+            .unwrap();
+
+        let unseen = unseen_spans(HashSet::from([vec![1u8; 8]]), spans);
+        assert_eq!(unseen.iter().map(|s| s.span_id.clone()).collect::<Vec<_>>(), [vec![2u8; 8]]);
+    }
+
+    // {"resourceSpans":[{"scopeSpans":[{"spans":[{..., "startTimeUnixNano":1745107200000000000,"endTimeUnixNano":"1745107201000000000"}]}]}]}
+    const GZIP_JSON_TRACE: [u8; 128] = [
+        0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0xab, 0x56, 0x2a, 0x4a, 0x2d, 0xce, 0x2f, 0x2d, 0x4a, 0x4e,
+        0x0d, 0x2e, 0x48, 0xcc, 0x2b, 0x56, 0xb2, 0x8a, 0xae, 0x56, 0x2a, 0x4e, 0xce, 0x2f, 0x40, 0xe6, 0xc2, 0x59, 0x25, 0x45,
+        0x89, 0xc9, 0xa9, 0x9e, 0x29, 0x4a, 0x56, 0x4a, 0x06, 0x86, 0xf8, 0xa1, 0x92, 0x0e, 0x58, 0x1b, 0x56, 0xb5, 0x40, 0xb9,
+        0xbc, 0xc4, 0xdc, 0x54, 0xa0, 0x8c, 0xbb, 0x6b, 0x88, 0x82, 0x3e, 0x48, 0x69, 0x49, 0x62, 0x51, 0x49, 0x48, 0x66, 0x6e,
+        0x6a, 0x68, 0x5e, 0x66, 0x85, 0x5f, 0x62, 0x5e, 0xbe, 0x92, 0x95, 0xa1, 0xb9, 0x89, 0xa9, 0xa1, 0x81, 0xb9, 0x91, 0x01,
+        0x02, 0xe8, 0x28, 0xa5, 0xe6, 0xa5, 0xa0, 0xaa, 0x52, 0x82, 0x2b, 0x33, 0x84, 0x2b, 0x53, 0xaa, 0x8d, 0x05, 0x43, 0x00,
+        0xc0, 0xdc, 0xd5, 0xae, 0xda, 0x00, 0x00, 0x00,
+    ];
+
+    fn first_span(req: &ExportTraceServiceRequest) -> &Span {
+        &req.resource_spans[0].scope_spans[0].spans[0]
+    }
+
+    #[test]
+    fn decodes_json_times_as_numbers_or_strings() {
+        let body = serde_json::to_vec(&json!({
+            "resourceSpans": [{
+                "scopeSpans": [{
+                    "spans": [{
+                        "traceId": "01010101010101010101010101010101",
+                        "spanId": "0101010101010101",
+                        "name": "GET /",
+                        "startTimeUnixNano": 1745107200000000000u64,
+                        "endTimeUnixNano": "1745107201000000000",
+                        "events": [{ "name": "exception", "timeUnixNano": 1745107200500000000u64 }],
+                    }],
+                }],
+            }],
+        }))
+        .unwrap();
+
+        let req = OtlpEncoding::Json.decode(&body).unwrap();
+        let span = first_span(&req);
+        assert_eq!(span.trace_id, vec![1u8; 16]);
+        assert_eq!(span.start_time_unix_nano, BASE);
+        assert_eq!(span.end_time_unix_nano, BASE + SECOND);
+        assert_eq!(span.events[0].time_unix_nano, BASE + SECOND / 2);
+
+        // 시각이 아닌 필드의 숫자는 그대로 둔다.
+        let mut value = json!({ "droppedAttributesCount": 2, "name": "1" });
+        stringify_nano_times(&mut value);
+        assert_eq!(value, json!({ "droppedAttributesCount": 2, "name": "1" }));
+
+        assert!(matches!(OtlpEncoding::Json.decode(b"{\"resourceSpans\": 1}"), Err(AppError::BadRequest(ErrorCode::InvalidEvent))));
+    }
+
+    #[actix_web::test]
+    async fn decodes_gzip_json_body() {
+        let (req, mut payload) = actix_web::test::TestRequest::post()
+            .insert_header(("content-type", "application/json"))
+            .insert_header(("content-encoding", "gzip"))
+            .set_payload(GZIP_JSON_TRACE.to_vec())
+            .to_http_parts();
+        let body = <web::Bytes as actix_web::FromRequest>::from_request(&req, &mut payload).await.unwrap();
+
+        let encoding = OtlpEncoding::of(&req);
+        assert_eq!(encoding, OtlpEncoding::Json);
+        let req = encoding.decode(&body).unwrap();
+        assert_eq!(first_span(&req).name, "GET /");
+        assert_eq!(first_span(&req).start_time_unix_nano, BASE);
+    }
+
+    #[actix_web::test]
+    async fn responds_with_export_trace_service_response() {
+        let body = |response: HttpResponse| async move { actix_web::body::to_bytes(response.into_body()).await.unwrap() };
+
+        let accepted = OtlpEncoding::Json.respond(0);
+        assert_eq!(accepted.headers().get("content-type").unwrap(), "application/json");
+        assert_eq!(body(accepted).await, "{}");
+
+        let partial: Value = serde_json::from_slice(&body(OtlpEncoding::Json.respond(2)).await).unwrap();
+        assert_eq!(partial["partialSuccess"]["rejectedSpans"], 2);
+        assert!(partial["partialSuccess"]["errorMessage"].as_str().unwrap().starts_with("2 spans rejected"));
+
+        let protobuf = OtlpEncoding::Protobuf.respond(2);
+        assert_eq!(protobuf.headers().get("content-type").unwrap(), "application/x-protobuf");
+        let decoded = ExportTraceServiceResponse::decode(body(protobuf).await).unwrap();
+        assert_eq!(decoded.partial_success.unwrap().rejected_spans, 2);
+        assert!(ExportTraceServiceResponse::decode(body(OtlpEncoding::Protobuf.respond(0)).await).unwrap().partial_success.is_none());
+    }
+}
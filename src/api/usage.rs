@@ -0,0 +1,564 @@
+use std::collections::HashMap;
+use std::env;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use actix_web::{get, put, web, HttpResponse};
+use chrono::{DateTime, Datelike, Months, TimeZone, Timelike, Utc};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Set};
+use sea_query::{Expr, Func};
+use tokio::time;
+use tracing::error;
+use crate::api::project::{check_active_project, check_project_member};
+use crate::api::project_member::check_project_owner;
+use crate::entity::project::{self, ActiveModel as ProjectActiveModel, Entity as ProjectEntity};
+use crate::entity::project_usage::{self, ActiveModel as ProjectUsageActiveModel, Entity as ProjectUsageEntity};
+use crate::model::global_error::{AppError, ErrorCode, ValidationFieldError};
+use crate::model::project::ProjectResponse;
+use crate::model::usage::{ProjectLimitsRequest, ProjectUsageResponse, UsageBucketResponse, UsageQuery, UsageTotals};
+use crate::util::rate_limit::{Limit, RateLimiter};
+
+// API 키로 찾은 프로젝트와 이번 달 수집량을 캐시하는 시간.
+// 제한 설정을 바꾸면 이 시간 안에 반영된다.
+const PROJECT_CACHE_TTL: Duration = Duration::from_secs(30);
+
+// 없는 API 키를 기억하는 시간과 최대 개수. 잘못된 키로 계속 보내도 매번 DB를 조회하지 않는다.
+const UNKNOWN_KEY_TTL: Duration = Duration::from_secs(10);
+const MAX_UNKNOWN_KEYS: usize = 10_000;
+
+// 메모리에 모은 수집량을 DB에 기록하는 간격. 서버가 내려가면 마지막 간격의 수집량은 잃는다.
+const USAGE_FLUSH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// 수집 제한 기본값. 프로젝트에 값이 없으면 이 값을 쓴다.
+#[derive(Debug, Clone, Copy)]
+pub struct IngestLimitConfig {
+    pub key_rate: u32,
+    pub key_burst: u32,
+    pub project_rate: u32,
+    pub project_burst: u32,
+}
+
+impl IngestLimitConfig {
+    pub fn from_env() -> Self {
+        Self {
+            key_rate: env_or("INGEST_KEY_RATE_LIMIT", 100),
+            key_burst: env_or("INGEST_KEY_RATE_BURST", 500),
+            project_rate: env_or("INGEST_PROJECT_RATE_LIMIT", 50),
+            project_burst: env_or("INGEST_PROJECT_RATE_BURST", 200),
+        }
+    }
+}
+
+fn env_or(name: &str, default: u32) -> u32 {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum UsageOutcome {
+    Accepted,
+    RateLimited,
+    OverQuota,
+    SpikeDropped,
+}
+
+struct CachedProject {
+    project: project::Model,
+    month_start: DateTime<Utc>,
+    month_accepted: i64,
+    loaded_at: Instant,
+}
+
+/// `/events`, `/batch-events`, `/traces` 수집 제한.
+/// API 키로 프로젝트를 찾은 뒤 월 쿼터, 키별·프로젝트별 초당 제한을 검사하고 결과를 `project_usage`에 기록한다.
+/// 수집량은 메모리에 모았다가 `run_usage_flush`가 주기적으로 기록한다.
+pub struct IngestLimiter {
+    config: IngestLimitConfig,
+    limiter: RateLimiter,
+    projects: Mutex<HashMap<String, CachedProject>>,
+    unknown_keys: Mutex<HashMap<String, Instant>>,
+    usage: Mutex<HashMap<(i32, DateTime<Utc>), UsageTotals>>,
+}
+
+impl IngestLimiter {
+    pub fn new(config: IngestLimitConfig) -> Self {
+        Self {
+            config,
+            limiter: RateLimiter::new(),
+            projects: Mutex::new(HashMap::new()),
+            unknown_keys: Mutex::new(HashMap::new()),
+            usage: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// API 키로 들어온 `count`개의 이벤트를 받아도 되는지 검사하고, 받을 수 있으면 (캐시된) 프로젝트를 돌려준다.
+    /// 버킷은 키가 확인된 뒤에 만들므로, 없는 키로 보낸 요청은 버킷을 만들지 않는다.
+    pub async fn admit(&self, db: &DatabaseConnection, api_key: &str, count: u32) -> Result<project::Model, AppError> {
+        let now = Utc::now();
+        let project = self.project(db, api_key, now).await?;
+        let project_id = project.id;
+
+        let key_bucket = format!("key:{}", api_key);
+        let project_bucket = format!("project:{}", project_id);
+        let limits = [
+            Limit { key: &key_bucket, rate: self.config.key_rate, burst: self.config.key_burst },
+            Limit {
+                key: &project_bucket,
+                rate: project.rate_limit_per_second.map_or(self.config.project_rate, |rate| rate.max(0) as u32),
+                burst: project.rate_limit_burst.map_or(self.config.project_burst, |burst| burst.max(0) as u32),
+            },
+        ];
+
+        // 쿼터와 두 버킷을 모두 확인한 뒤에만 토큰과 쿼터를 쓰므로, 거절된 요청은 어느 한도도 줄이지 않는다.
+        // 쿼터는 인스턴스마다 캐시된 값으로 검사하므로 여러 인스턴스에서는 조금 넘칠 수 있다.
+        {
+            let mut projects = self.projects.lock().unwrap_or_else(|e| e.into_inner());
+            let cached = projects.get_mut(api_key);
+            let over_quota = cached
+                .as_ref()
+                .zip(project.monthly_event_quota)
+                .is_some_and(|(cached, quota)| cached.month_accepted + count as i64 > quota);
+            if over_quota {
+                self.record_usage(project_id, UsageOutcome::OverQuota, count);
+                let retry_after = (next_month_start(now) - now).num_seconds().max(1) as u64;
+                return Err(AppError::too_many_requests(ErrorCode::QuotaExceeded, retry_after));
+            }
+
+            if let Err(retry_after) = self.limiter.check_all(&limits, count) {
+                self.record_usage(project_id, UsageOutcome::RateLimited, count);
+                return Err(AppError::too_many_requests(ErrorCode::RateLimited, retry_after_secs(retry_after)));
+            }
+
+            if let Some(cached) = cached {
+                cached.month_accepted += count as i64;
+            }
+        }
+
+        self.record_usage(project_id, UsageOutcome::Accepted, count);
+        Ok(project)
+    }
+
+    async fn project(&self, db: &DatabaseConnection, api_key: &str, now: DateTime<Utc>) -> Result<project::Model, AppError> {
+        let month_start = month_start(now);
+        {
+            let projects = self.projects.lock().unwrap_or_else(|e| e.into_inner());
+            let fresh = projects
+                .get(api_key)
+                .filter(|cached| cached.loaded_at.elapsed() < PROJECT_CACHE_TTL && cached.month_start == month_start);
+            if let Some(cached) = fresh {
+                return Ok(cached.project.clone());
+            }
+        }
+        if self.is_unknown_key(api_key) {
+            return Err(AppError::bad_request(ErrorCode::InvalidApiKey));
+        }
+
+        let project = ProjectEntity::find()
+            .filter(project::Column::ApiKey.eq(api_key))
+            .filter(project::Column::DeletedAt.is_null())
+            .one(db)
+            .await?;
+        let Some(project) = project else {
+            self.remember_unknown_key(api_key);
+            return Err(AppError::bad_request(ErrorCode::InvalidApiKey));
+        };
+        let month_accepted = usage_totals(db, project.id, month_start, None).await?.accepted;
+
+        self.projects.lock().unwrap_or_else(|e| e.into_inner()).insert(
+            api_key.to_string(),
+            CachedProject {
+                project: project.clone(),
+                month_start,
+                month_accepted,
+                loaded_at: Instant::now(),
+            },
+        );
+
+        Ok(project)
+    }
+
+    fn record_usage(&self, project_id: i32, outcome: UsageOutcome, count: u32) {
+        let mut usage = self.usage.lock().unwrap_or_else(|e| e.into_inner());
+        let totals = usage.entry((project_id, hour_bucket(Utc::now()))).or_default();
+        let count = count as i64;
+        match outcome {
+            UsageOutcome::Accepted => totals.accepted += count,
+            UsageOutcome::RateLimited => totals.rate_limited += count,
+            UsageOutcome::OverQuota => totals.over_quota += count,
+            UsageOutcome::SpikeDropped => {
+                totals.accepted -= count;
+                totals.spike_dropped += count;
+            }
+        }
+    }
+
+    /// 받은 뒤 스파이크 샘플링으로 버린 이벤트를 기록한다. 받은 수에서 빼므로 월 쿼터도 쓰지 않는다.
+    pub async fn record_spike_dropped(&self, db: &DatabaseConnection, api_key: &str, count: u32) {
+        let project_id = match self.project(db, api_key, Utc::now()).await {
+            Ok(project) => project.id,
+            Err(e) => {
+                error!("스파이크로 버린 이벤트를 기록할 프로젝트를 찾지 못함: {:?}", e);
+                return;
+            }
+        };
+
+        if let Some(cached) = self.projects.lock().unwrap_or_else(|e| e.into_inner()).get_mut(api_key) {
+            cached.month_accepted -= count as i64;
+        }
+        self.record_usage(project_id, UsageOutcome::SpikeDropped, count);
+    }
+
+    /// 모아 둔 수집량을 `USAGE_FLUSH_INTERVAL`마다 DB에 기록한다.
+    pub async fn run_usage_flush(&self, db: DatabaseConnection) {
+        let mut interval = time::interval(USAGE_FLUSH_INTERVAL);
+        loop {
+            interval.tick().await;
+            self.flush_usage(&db).await;
+        }
+    }
+
+    // 기록하지 못한 수집량은 다시 모아 두고 다음 간격에 기록한다.
+    async fn flush_usage(&self, db: &DatabaseConnection) {
+        let pending = std::mem::take(&mut *self.usage.lock().unwrap_or_else(|e| e.into_inner()));
+        for ((project_id, bucket), totals) in pending {
+            if let Err(e) = upsert_usage(db, project_id, bucket, &totals).await {
+                error!("수집량 기록 실패 (project {}): {:?}", project_id, e);
+                let mut usage = self.usage.lock().unwrap_or_else(|e| e.into_inner());
+                let pending = usage.entry((project_id, bucket)).or_default();
+                pending.accepted += totals.accepted;
+                pending.rate_limited += totals.rate_limited;
+                pending.over_quota += totals.over_quota;
+                pending.spike_dropped += totals.spike_dropped;
+            }
+        }
+    }
+
+    fn is_unknown_key(&self, api_key: &str) -> bool {
+        self.unknown_keys
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(api_key)
+            .is_some_and(|seen_at| seen_at.elapsed() < UNKNOWN_KEY_TTL)
+    }
+
+    pub(crate) fn remember_unknown_key(&self, api_key: &str) {
+        let mut unknown_keys = self.unknown_keys.lock().unwrap_or_else(|e| e.into_inner());
+        if unknown_keys.len() >= MAX_UNKNOWN_KEYS {
+            unknown_keys.retain(|_, seen_at| seen_at.elapsed() < UNKNOWN_KEY_TTL);
+        }
+        // 가득 찼으면 기억하지 않고 다음 요청에서 다시 조회한다.
+        if unknown_keys.len() < MAX_UNKNOWN_KEYS {
+            unknown_keys.insert(api_key.to_string(), Instant::now());
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{project_id}/usage",
+    summary = "프로젝트 수집량 조회",
+    params(
+        ("project_id" = i32, Path, description = "프로젝트 ID"),
+        ("startDate" = Option<String>, Query, description = "조회 시작 시각 (ISO8601, 기본값: 24시간 전)"),
+        ("endDate" = Option<String>, Query, description = "조회 종료 시각 (ISO8601, 기본값: 현재)"),
+    ),
+    responses(
+        (status = 200, description = "수집량 조회 성공", body = ProjectUsageResponse),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Project"
+)]
+#[get("/projects/{project_id}/usage")]
+pub async fn get_project_usage(
+    db: web::Data<DatabaseConnection>,
+    limiter: web::Data<IngestLimiter>,
+    path: web::Path<i32>,
+    query: web::Query<UsageQuery>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+
+    check_project_member(db.get_ref(), project_id, user_id).await?;
+    let project = check_active_project(db.get_ref(), project_id).await?;
+
+    let now = Utc::now();
+    let end = query.end_date.unwrap_or(now);
+    let start = query.start_date.unwrap_or(end - chrono::Duration::hours(24));
+
+    let buckets = ProjectUsageEntity::find()
+        .filter(project_usage::Column::ProjectId.eq(project_id))
+        .filter(project_usage::Column::Bucket.gte(hour_bucket(start)))
+        .filter(project_usage::Column::Bucket.lte(end))
+        .order_by_asc(project_usage::Column::Bucket)
+        .all(db.get_ref())
+        .await?;
+
+    let config = limiter.config;
+    Ok(HttpResponse::Ok().json(ProjectUsageResponse {
+        rate_limit_per_second: project.rate_limit_per_second.map_or(config.project_rate, |rate| rate as u32),
+        rate_limit_burst: project.rate_limit_burst.map_or(config.project_burst, |burst| burst as u32),
+        monthly_event_quota: project.monthly_event_quota,
+        current_month: usage_totals(db.get_ref(), project_id, month_start(now), None).await?,
+        buckets: buckets.into_iter().map(UsageBucketResponse::from).collect(),
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/projects/{project_id}/limits",
+    summary = "프로젝트 수집 제한 설정",
+    request_body = ProjectLimitsRequest,
+    responses(
+        (status = 200, description = "수집 제한 설정 성공", body = ProjectResponse),
+        (status = 400, description = "잘못된 요청", body = ValidationFieldError),
+        (status = 403, description = "권한 없음"),
+    ),
+    tag = "Project"
+)]
+#[put("/projects/{project_id}/limits")]
+pub async fn update_project_limits(
+    db: web::Data<DatabaseConnection>,
+    path: web::Path<i32>,
+    body: web::Json<ProjectLimitsRequest>,
+    auth_user: web::ReqData<i32>,
+) -> Result<HttpResponse, AppError> {
+    let project_id = path.into_inner();
+    let user_id = auth_user.into_inner();
+    let ProjectLimitsRequest { rate_limit_per_second, rate_limit_burst, monthly_event_quota } = body.into_inner();
+
+    let mut errors = Vec::new();
+    if rate_limit_per_second.is_some_and(|rate| rate <= 0) {
+        errors.push(ValidationFieldError {
+            field: "rateLimitPerSecond".to_string(),
+            message: "초당 제한은 1 이상이어야 합니다.".to_string(),
+        });
+    }
+    if rate_limit_burst.is_some_and(|burst| burst <= 0) {
+        errors.push(ValidationFieldError {
+            field: "rateLimitBurst".to_string(),
+            message: "버스트 크기는 1 이상이어야 합니다.".to_string(),
+        });
+    }
+    if monthly_event_quota.is_some_and(|quota| quota < 0) {
+        errors.push(ValidationFieldError {
+            field: "monthlyEventQuota".to_string(),
+            message: "월 쿼터는 0 이상이어야 합니다.".to_string(),
+        });
+    }
+    if !errors.is_empty() {
+        return Err(AppError::ValidationError(errors));
+    }
+
+    check_project_owner(db.get_ref(), project_id, user_id).await?;
+    let project = check_active_project(db.get_ref(), project_id).await?;
+
+    let mut project: ProjectActiveModel = project.into();
+    project.rate_limit_per_second = Set(rate_limit_per_second);
+    project.rate_limit_burst = Set(rate_limit_burst);
+    project.monthly_event_quota = Set(monthly_event_quota);
+    project.updated_at = Set(Some(Utc::now()));
+
+    let updated = project.update(db.get_ref()).await?;
+
+    Ok(HttpResponse::Ok().json(ProjectResponse::from(updated)))
+}
+
+async fn usage_totals(
+    db: &DatabaseConnection,
+    project_id: i32,
+    start: DateTime<Utc>,
+    end: Option<DateTime<Utc>>,
+) -> Result<UsageTotals, DbErr> {
+    let mut select = ProjectUsageEntity::find()
+        .select_only()
+        .expr(Func::coalesce([Expr::col(project_usage::Column::Accepted).sum(), Expr::val(0).into()]))
+        .expr(Func::coalesce([Expr::col(project_usage::Column::RateLimited).sum(), Expr::val(0).into()]))
+        .expr(Func::coalesce([Expr::col(project_usage::Column::OverQuota).sum(), Expr::val(0).into()]))
+        .expr(Func::coalesce([Expr::col(project_usage::Column::SpikeDropped).sum(), Expr::val(0).into()]))
+        .filter(project_usage::Column::ProjectId.eq(project_id))
+        .filter(project_usage::Column::Bucket.gte(start));
+
+    if let Some(end) = end {
+        select = select.filter(project_usage::Column::Bucket.lt(end));
+    }
+
+    let totals: Option<(i64, i64, i64, i64)> = select.into_tuple().one(db).await?;
+    let (accepted, rate_limited, over_quota, spike_dropped) = totals.unwrap_or_default();
+
+    Ok(UsageTotals { accepted, rate_limited, over_quota, spike_dropped })
+}
+
+async fn upsert_usage(
+    db: &DatabaseConnection,
+    project_id: i32,
+    bucket: DateTime<Utc>,
+    totals: &UsageTotals,
+) -> Result<(), DbErr> {
+    let usage = ProjectUsageActiveModel {
+        project_id: Set(project_id),
+        bucket: Set(bucket),
+        accepted: Set(totals.accepted),
+        rate_limited: Set(totals.rate_limited),
+        over_quota: Set(totals.over_quota),
+        spike_dropped: Set(totals.spike_dropped),
+        ..Default::default()
+    };
+
+    ProjectUsageEntity::insert(usage)
+        .on_conflict(
+            OnConflict::columns([project_usage::Column::ProjectId, project_usage::Column::Bucket])
+                .value(project_usage::Column::Accepted, Expr::col(project_usage::Column::Accepted).add(totals.accepted))
+                .value(project_usage::Column::RateLimited, Expr::col(project_usage::Column::RateLimited).add(totals.rate_limited))
+                .value(project_usage::Column::OverQuota, Expr::col(project_usage::Column::OverQuota).add(totals.over_quota))
+                .value(project_usage::Column::SpikeDropped, Expr::col(project_usage::Column::SpikeDropped).add(totals.spike_dropped))
+                .to_owned()
+        )
+        .exec_without_returning(db)
+        .await?;
+
+    Ok(())
+}
+
+fn retry_after_secs(retry_after: Duration) -> u64 {
+    retry_after.as_secs_f64().ceil().clamp(1.0, 3600.0) as u64
+}
+
+fn hour_bucket(at: DateTime<Utc>) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(at.year(), at.month(), at.day(), at.hour(), 0, 0)
+        .single()
+        .unwrap_or(at)
+}
+
+fn month_start(at: DateTime<Utc>) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(at.year(), at.month(), 1, 0, 0, 0)
+        .single()
+        .unwrap_or(at)
+}
+
+fn next_month_start(at: DateTime<Utc>) -> DateTime<Utc> {
+    month_start(at) + Months::new(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter() -> IngestLimiter {
+        IngestLimiter::new(IngestLimitConfig { key_rate: 10, key_burst: 10, project_rate: 10, project_burst: 10 })
+    }
+
+    #[tokio::test]
+    async fn unknown_key_creates_no_bucket() {
+        // 연결되지 않은 DB라 조회하면 패닉한다. 기억해 둔 없는 키는 조회 없이 거절해야 한다.
+        let db = DatabaseConnection::default();
+        let limiter = limiter();
+        limiter.remember_unknown_key("proj_unknown");
+
+        for _ in 0..3 {
+            match limiter.admit(&db, "proj_unknown", 1).await {
+                Err(AppError::BadRequest(ErrorCode::InvalidApiKey)) => {}
+                other => panic!("unexpected {:?}", other.map(|project| project.id)),
+            }
+        }
+
+        assert_eq!(limiter.limiter.len(), 0);
+    }
+
+    #[test]
+    fn forgets_unknown_keys_when_full() {
+        let limiter = limiter();
+        for i in 0..MAX_UNKNOWN_KEYS + 10 {
+            limiter.remember_unknown_key(&format!("proj_{}", i));
+        }
+
+        assert_eq!(limiter.unknown_keys.lock().unwrap().len(), MAX_UNKNOWN_KEYS);
+        assert!(limiter.is_unknown_key("proj_0"));
+        assert!(!limiter.is_unknown_key(&format!("proj_{}", MAX_UNKNOWN_KEYS)));
+    }
+
+    fn cache_project(limiter: &IngestLimiter, api_key: &str, monthly_event_quota: Option<i64>) {
+        let project = project::Model {
+            id: 7,
+            name: "replay".to_string(),
+            description: None,
+            api_key: api_key.to_string(),
+            rate_limit_per_second: Some(1),
+            rate_limit_burst: Some(3),
+            monthly_event_quota,
+            sample_rate: None,
+            scrubbing_rules: None,
+            collect_ip_address: true,
+            trusted_proxies: None,
+            created_at: Utc::now(),
+            updated_at: None,
+            deleted_at: None,
+            deleted_by: None,
+        };
+        limiter.projects.lock().unwrap().insert(
+            api_key.to_string(),
+            CachedProject { project, month_start: month_start(Utc::now()), month_accepted: 0, loaded_at: Instant::now() },
+        );
+    }
+
+    fn pending_usage(limiter: &IngestLimiter) -> (i64, i64, i64) {
+        limiter.usage.lock().unwrap().values().fold((0, 0, 0), |(a, r, o), totals| {
+            (a + totals.accepted, r + totals.rate_limited, o + totals.over_quota)
+        })
+    }
+
+    #[tokio::test]
+    async fn records_rejections_without_charging_other_limits() {
+        let db = DatabaseConnection::default();
+        let limiter = limiter();
+        cache_project(&limiter, "proj_test", Some(4));
+
+        // 프로젝트 버킷(3개)에서 막힌 요청도 거절로 기록하고, 키 버킷의 토큰은 쓰지 않는다.
+        assert!(limiter.admit(&db, "proj_test", 3).await.is_ok());
+        assert!(matches!(
+            limiter.admit(&db, "proj_test", 1).await,
+            Err(AppError::TooManyRequests(ErrorCode::RateLimited, _))
+        ));
+        assert!(limiter.limiter.check("key:proj_test", 7, 10, 10).is_ok());
+
+        // 쿼터(4개)를 넘는 요청은 버킷을 확인하기 전에 거절한다.
+        assert!(matches!(
+            limiter.admit(&db, "proj_test", 2).await,
+            Err(AppError::TooManyRequests(ErrorCode::QuotaExceeded, _))
+        ));
+
+        assert_eq!(pending_usage(&limiter), (3, 1, 2));
+    }
+
+    #[tokio::test]
+    async fn moves_spike_dropped_events_out_of_accepted() {
+        let db = DatabaseConnection::default();
+        let limiter = limiter();
+        cache_project(&limiter, "proj_test", Some(3));
+
+        assert!(limiter.admit(&db, "proj_test", 3).await.is_ok());
+        limiter.record_spike_dropped(&db, "proj_test", 2).await;
+
+        let usage = limiter.usage.lock().unwrap();
+        let totals = usage.values().next().unwrap();
+        assert_eq!((totals.accepted, totals.spike_dropped), (1, 2));
+        drop(usage);
+
+        // 버린 이벤트는 월 쿼터를 쓰지 않는다.
+        assert_eq!(limiter.projects.lock().unwrap()["proj_test"].month_accepted, 1);
+    }
+
+    #[test]
+    fn aggregates_usage_in_memory() {
+        let limiter = limiter();
+        limiter.record_usage(7, UsageOutcome::Accepted, 5);
+        limiter.record_usage(7, UsageOutcome::Accepted, 2);
+        limiter.record_usage(7, UsageOutcome::RateLimited, 1);
+        limiter.record_usage(8, UsageOutcome::OverQuota, 4);
+
+        assert_eq!(limiter.usage.lock().unwrap().len(), 2);
+        assert_eq!(pending_usage(&limiter), (7, 1, 4));
+    }
+}
@@ -0,0 +1,96 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use crate::model::alert_rule::AlertAction;
+
+/// 프로젝트마다 만들어 두는 기본 회귀 알림 규칙의 이름
+pub const DEFAULT_REGRESSION_RULE_NAME: &str = "회귀 알림";
+
+/// 프로젝트별 알림 규칙. 이벤트가 저장된 뒤 이벤트 컨슈머에서 평가된다.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "alert_rules")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub project_id: i32,
+    pub name: String,
+    #[sea_orm(default_value = true)]
+    pub enabled: bool,
+    pub condition: AlertCondition,
+    pub threshold: Option<i32>,        // event_frequency: 이벤트 수, unique_users: 사용자 수
+    pub interval_minutes: Option<i32>, // 집계 구간 (분)
+    pub filters: Option<Value>,        // AlertFilters
+    pub actions: Value,                // Vec<AlertAction>
+    pub throttle_minutes: i32,         // 한 번 발송한 뒤 다시 발송하지 않을 시간 (분)
+    pub last_triggered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, EnumIter, DeriveActiveEnum, Copy, ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(30))")]
+#[serde(rename_all = "snake_case")]
+pub enum AlertCondition {
+    #[sea_orm(string_value = "new_issue")]
+    NewIssue,
+    #[sea_orm(string_value = "regression")]
+    Regression,
+    #[sea_orm(string_value = "event_frequency")]
+    EventFrequency,
+    #[sea_orm(string_value = "unique_users")]
+    UniqueUsers,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::project::Entity",
+        from = "Column::ProjectId",
+        to = "super::project::Column::Id"
+    )]
+    Project,
+}
+
+impl Related<super::project::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Project.def()
+    }
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C: ConnectionTrait>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr> {
+        let now = Utc::now();
+        if insert {
+            self.created_at = Set(now);
+        }
+        self.updated_at = Set(now);
+        Ok(self)
+    }
+}
+
+impl ActiveModel {
+    /// 해결된 이슈가 다시 발생하면 기본 Slack webhook으로 알리는 규칙.
+    /// 규칙이 생기기 전에 보내던 회귀 알림을 이어 가며, 사용자가 끄거나 지울 수 있다.
+    pub fn default_regression(project_id: i32) -> Self {
+        let now = Utc::now();
+        Self {
+            project_id: Set(project_id),
+            name: Set(DEFAULT_REGRESSION_RULE_NAME.to_string()),
+            enabled: Set(true),
+            condition: Set(AlertCondition::Regression),
+            threshold: Set(None),
+            interval_minutes: Set(None),
+            filters: Set(None),
+            actions: Set(serde_json::to_value([AlertAction::Slack]).unwrap_or_default()),
+            throttle_minutes: Set(0),
+            last_triggered_at: Set(None),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        }
+    }
+}
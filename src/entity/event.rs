@@ -25,10 +25,33 @@ pub struct Model {
     pub os: Option<String>,
     pub ip_address: Option<String>,
     pub user_agent: Option<String>,
+    // user_agent에서 파싱한 값
+    pub browser_name: Option<String>,
+    pub browser_version: Option<String>,
+    pub os_name: Option<String>,
+    pub os_version: Option<String>,
+    pub device_type: Option<String>,
+    // 클라이언트 IP로 찾은 위치 (GeoIP)
+    pub country_code: Option<String>,
+    pub country_name: Option<String>,
+    pub region: Option<String>,
+    pub city: Option<String>,
     pub project_id: i32,
     pub issue_id: Option<i32>,
     pub reported_by: Option<i32>,
     pub additional_info: Option<Value>,
+    // SDK가 보낸 breadcrumbs / 태그 / 사용자 / 요청 / 런타임 정보. 태그는 `event_tags`에도 저장한다.
+    pub breadcrumbs: Option<Value>,
+    pub tags: Option<Value>,
+    pub user_context: Option<Value>,
+    pub request_context: Option<Value>,
+    pub contexts: Option<Value>,
+    pub frames: Option<Value>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub symbolicated_stacktrace: Option<String>,
+    pub symbolicated_frames: Option<Value>,
+    // 저장 전에 가린 필드 경로 목록
+    pub scrubbed_fields: Option<Value>,
 
     pub priority: Option<Priority>,
     pub assigned_to: Option<i32>,
@@ -169,6 +192,11 @@ impl ActiveModel {
             issue_id: Set(Some(issue_id)),
             reported_by: Set(event.user_id),
             additional_info: Set(event.additional_info.clone()),
+            breadcrumbs: Set(Some(&event.breadcrumbs).filter(|breadcrumbs| !breadcrumbs.is_empty()).and_then(|breadcrumbs| serde_json::to_value(breadcrumbs).ok())),
+            tags: Set(Some(&event.tags).filter(|tags| !tags.is_empty()).and_then(|tags| serde_json::to_value(tags).ok())),
+            user_context: Set(event.user.as_ref().and_then(|user| serde_json::to_value(user).ok())),
+            request_context: Set(event.request.as_ref().and_then(|request| serde_json::to_value(request).ok())),
+            contexts: Set(event.contexts.as_ref().and_then(|contexts| serde_json::to_value(contexts).ok())),
             status: Set(EventStatus::UNRESOLVED),
             ..Default::default()
         }
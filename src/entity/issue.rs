@@ -1,6 +1,9 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use utoipa::ToSchema;
+use crate::util::version::compare_versions;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "issues")]
@@ -9,16 +12,38 @@ pub struct Model {
     pub id: i32,
     pub title: String,
     pub group_hash: String,
-    pub status: String,  // "open", "in_progress", "resolved", "ignored"
+    pub status: IssueStatus,
     pub first_seen: DateTime<Utc>,
     pub last_seen: DateTime<Utc>,
     pub count: i32,
     pub project_id: i32,
     pub assigned_to: Option<i32>,  // 담당 사용자 ID
+    pub last_release: Option<String>,  // 지금까지 발생한 이벤트 중 가장 높은 app_version
+    // resolved_in_next_release 가 true 면 해결 시점의 last_release, 아니면 수정된 버전
+    pub resolved_in_version: Option<String>,
+    #[sea_orm(default_value = false)]
+    pub resolved_in_next_release: bool,
+    pub resolved_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, EnumIter, DeriveActiveEnum, Copy, ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[serde(rename_all = "snake_case")]
+pub enum IssueStatus {
+    #[sea_orm(string_value = "open")]
+    Open,
+    #[sea_orm(string_value = "in_progress")]
+    InProgress,
+    #[sea_orm(string_value = "resolved")]
+    Resolved,
+    #[sea_orm(string_value = "ignored")]
+    Ignored,
+    #[sea_orm(string_value = "regressed")]
+    Regressed,
+}
+
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(
@@ -37,6 +62,9 @@ pub enum Relation {
 
     #[sea_orm(has_many = "super::event::Entity")]
     Event,
+
+    #[sea_orm(has_many = "super::issue_activity::Entity")]
+    Activity,
 }
 
 impl Related<super::project::Entity> for Entity {
@@ -57,4 +85,28 @@ impl Related<super::event::Entity> for Entity {
     }
 }
 
+impl Related<super::issue_activity::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Activity.def()
+    }
+}
+
+impl Model {
+    /// 해결된 이슈에 `app_version` 이벤트가 다시 들어왔을 때 회귀로 볼지 판단한다.
+    pub fn is_regression(&self, app_version: &str) -> bool {
+        if self.status != IssueStatus::Resolved {
+            return false;
+        }
+
+        match (&self.resolved_in_version, self.resolved_in_next_release) {
+            // 해결 당시 최신 릴리즈보다 새 버전에서 발생한 경우
+            (Some(version), true) => compare_versions(app_version, version) == Ordering::Greater,
+            // 수정된 버전 이상에서 발생한 경우 (이전 버전 클라이언트의 이벤트는 무시)
+            (Some(version), false) => compare_versions(app_version, version) != Ordering::Less,
+            (None, true) => false,
+            (None, false) => true,
+        }
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::Set;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+/// 이슈 상태 변경 / 담당자 지정 / 회귀 이력
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "issue_activities")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub issue_id: i32,
+    pub project_id: i32,
+    pub kind: ActivityKind,
+    pub user_id: Option<i32>, // 시스템이 기록한 경우 None
+    pub data: Option<Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, EnumIter, DeriveActiveEnum, Copy, ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(30))")]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityKind {
+    #[sea_orm(string_value = "status_changed")]
+    StatusChanged,
+    #[sea_orm(string_value = "assigned")]
+    Assigned,
+    #[sea_orm(string_value = "regressed")]
+    Regressed,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::issue::Entity",
+        from = "Column::IssueId",
+        to = "super::issue::Column::Id"
+    )]
+    Issue,
+}
+
+impl Related<super::issue::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Issue.def()
+    }
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C: ConnectionTrait>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr> {
+        if insert {
+            self.created_at = Set(Utc::now());
+        }
+        Ok(self)
+    }
+}
+
+impl ActiveModel {
+    pub fn new(issue_id: i32, project_id: i32, kind: ActivityKind, user_id: Option<i32>, data: Option<Value>) -> Self {
+        Self {
+            issue_id: Set(issue_id),
+            project_id: Set(project_id),
+            kind: Set(kind),
+            user_id: Set(user_id),
+            data: Set(data),
+            created_at: Set(Utc::now()),
+            ..Default::default()
+        }
+    }
+}
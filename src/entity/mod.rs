@@ -1,4 +1,5 @@
 pub mod event;
+pub mod event_tag;
 pub mod user;
 pub mod project;
 pub mod issue;
@@ -6,3 +7,11 @@ pub mod project_member;
 pub mod base_time;
 pub mod transaction;
 pub mod span;
+pub mod artifact;
+pub mod issue_activity;
+pub mod alert_rule;
+pub mod notification_channel;
+pub mod notification_delivery;
+pub mod project_usage;
+pub mod saved_search;
+pub mod export_job;
\ No newline at end of file
@@ -2,6 +2,7 @@ use chrono::{Utc, DateTime};
 use sea_orm::entity::prelude::*;
 use sea_orm::Set;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::model::project::ProjectCreateRequest;
 use crate::entity::base_time::{BaseTimeFields, ActiveModelTimeBehavior};
@@ -15,6 +16,22 @@ pub struct Model {
     pub description: Option<String>,
     pub api_key: String,
 
+    // 수집 제한. 비어 있으면 서버 기본값을 쓰고, 쿼터는 제한하지 않는다.
+    pub rate_limit_per_second: Option<i32>,
+    pub rate_limit_burst: Option<i32>,
+    pub monthly_event_quota: Option<i64>,
+
+    // SDK가 이벤트를 보내기 전에 적용할 샘플링 비율(0~1). 비어 있으면 전부 보낸다.
+    pub sample_rate: Option<f64>,
+
+    // 프로젝트별 데이터 스크러빙 규칙 (`ScrubbingRules`). 비어 있으면 기본 규칙만 쓴다.
+    pub scrubbing_rules: Option<Value>,
+
+    // 이벤트에 클라이언트 IP를 남길지 여부와, `X-Forwarded-For`를 믿을 프록시 주소 범위 목록
+    #[sea_orm(default_value = true)]
+    pub collect_ip_address: bool,
+    pub trusted_proxies: Option<Value>,
+
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
     pub deleted_at: Option<DateTime<Utc>>,
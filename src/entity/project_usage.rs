@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 프로젝트별 시간 단위 수집량. (project_id, bucket) 으로 유니크하다.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "project_usage")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub project_id: i32,
+    pub bucket: DateTime<Utc>, // 집계 구간 시작 시각 (정시)
+    pub accepted: i64,
+    pub rate_limited: i64,
+    pub over_quota: i64,
+    #[sea_orm(default_value = 0)]
+    pub spike_dropped: i64, // 제한은 통과했지만 스파이크 샘플링으로 버린 수. `accepted`에는 들어가지 않는다.
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::project::Entity",
+        from = "Column::ProjectId",
+        to = "super::project::Column::Id"
+    )]
+    Project,
+}
+
+impl Related<super::project::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Project.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
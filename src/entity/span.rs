@@ -32,6 +32,22 @@ pub struct Model {
     pub http_user_agent: Option<String>,
 
     pub attributes: Option<Value>,
+    // 저장 전에 가린 attribute 경로 목록
+    pub scrubbed_fields: Option<Value>,
+
+    // server, client, internal, producer, consumer
+    pub kind: Option<String>,
+    // unset, ok, error
+    pub status_code: Option<String>,
+    pub status_message: Option<String>,
+    // [{name, timestamp, attributes}]
+    pub events: Option<Value>,
+    // [{traceId, spanId, traceState, attributes}]
+    pub links: Option<Value>,
+    pub resource_attributes: Option<Value>,
+    pub scope_name: Option<String>,
+    pub scope_version: Option<String>,
+    pub scope_attributes: Option<Value>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -15,6 +15,8 @@ pub struct Model {
     pub project_id: i32,
     pub trace_id: String,
     pub name: String,
+    // root span의 종류 (server, client, internal, producer, consumer)
+    pub kind: Option<String>,
     pub start_timestamp: DateTime<Utc>,
     pub end_timestamp: DateTime<Utc>,
     pub duration_ms: i32,
@@ -0,0 +1,108 @@
+use std::env;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use opentelemetry_proto::tonic::collector::trace::v1::trace_service_server::{TraceService, TraceServiceServer};
+use opentelemetry_proto::tonic::collector::trace::v1::{ExportTraceServiceRequest, ExportTraceServiceResponse};
+use sea_orm::DatabaseConnection;
+use tonic::codec::CompressionEncoding;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+use anyhow::{Context, Result};
+use tracing::info;
+use crate::amqp::AmqpClient;
+use crate::api::trace::{export_response, ingest_traces, API_KEY_HEADER};
+use crate::api::usage::IngestLimiter;
+use crate::model::global_error::{AppError, ErrorCode};
+
+// HTTP 수집(`PayloadConfig`)과 같은 본문 크기 제한
+const MAX_MESSAGE_SIZE: usize = 10 * 1024 * 1024;
+
+/// OTLP gRPC `TraceService/Export`. 저장은 HTTP `/traces`와 같은 `ingest_traces`를 쓰고,
+/// API 키는 `x-replay-api-key` 메타데이터나 resource attribute `replay.api_key`로 받는다.
+pub struct OtlpTraceService {
+    db: DatabaseConnection,
+    amqp: Arc<AmqpClient>,
+    limiter: Arc<IngestLimiter>,
+}
+
+#[tonic::async_trait]
+impl TraceService for OtlpTraceService {
+    async fn export(
+        &self,
+        request: Request<ExportTraceServiceRequest>,
+    ) -> Result<Response<ExportTraceServiceResponse>, Status> {
+        let header_key = request
+            .metadata()
+            .get(API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(|key| key.trim().to_string())
+            .filter(|key| !key.is_empty());
+
+        let rejected = ingest_traces(&self.db, &self.amqp, &self.limiter, header_key.as_deref(), request.into_inner())
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(export_response(rejected)))
+    }
+}
+
+/// `OTLP_GRPC_ADDR`(예: `0.0.0.0:4317`)가 있으면 gRPC 수집 서버를 띄운다. 없으면 아무것도 하지 않는다.
+/// 주소를 해석하거나 바인드하지 못하면 에러를 돌려준다.
+pub async fn start_otlp_grpc_server(db: DatabaseConnection, amqp: Arc<AmqpClient>, limiter: Arc<IngestLimiter>) -> Result<()> {
+    let Ok(addr) = env::var("OTLP_GRPC_ADDR") else {
+        return Ok(());
+    };
+    let addr: SocketAddr = addr
+        .parse()
+        .with_context(|| format!("OTLP_GRPC_ADDR를 해석할 수 없습니다: {}", addr))?;
+
+    let service = TraceServiceServer::new(OtlpTraceService { db, amqp, limiter })
+        .accept_compressed(CompressionEncoding::Gzip)
+        .max_decoding_message_size(MAX_MESSAGE_SIZE);
+
+    info!("OTLP gRPC 수집 서버 시작 중: {}", addr);
+    Server::builder()
+        .add_service(service)
+        .serve(addr)
+        .await
+        .with_context(|| format!("OTLP gRPC 수집 서버 실행 실패: {}", addr))
+}
+
+fn to_status(err: AppError) -> Status {
+    match err {
+        AppError::Unauthorized(code) | AppError::BadRequest(code @ ErrorCode::InvalidApiKey) => {
+            Status::unauthenticated(code.message())
+        }
+        AppError::BadRequest(code) => Status::invalid_argument(code.message()),
+        AppError::Forbidden(code) => Status::permission_denied(code.message()),
+        AppError::NotFound(code) => Status::not_found(code.message()),
+        // OTLP exporter는 RESOURCE_EXHAUSTED를 받으면 물러났다가 다시 보낸다.
+        AppError::TooManyRequests(code, _) => Status::resource_exhausted(code.message()),
+        AppError::InternalServerError(code) => Status::internal(code.message()),
+        AppError::ValidationError(_) => Status::invalid_argument(ErrorCode::ValidationError.message()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::Code;
+
+    #[test]
+    fn maps_app_errors_to_grpc_codes() {
+        let cases = [
+            (AppError::unauthorized(ErrorCode::InvalidApiKey), Code::Unauthenticated),
+            (AppError::bad_request(ErrorCode::InvalidApiKey), Code::Unauthenticated),
+            (AppError::bad_request(ErrorCode::InvalidEvent), Code::InvalidArgument),
+            (AppError::ValidationError(Vec::new()), Code::InvalidArgument),
+            (AppError::too_many_requests(ErrorCode::RateLimited, 3), Code::ResourceExhausted),
+            (AppError::too_many_requests(ErrorCode::QuotaExceeded, 60), Code::ResourceExhausted),
+            (AppError::internal_error(ErrorCode::DatabaseError), Code::Internal),
+        ];
+
+        for (error, code) in cases {
+            assert_eq!(to_status(error).code(), code);
+        }
+        assert_eq!(to_status(AppError::unauthorized(ErrorCode::InvalidApiKey)).message(), ErrorCode::InvalidApiKey.message());
+    }
+}
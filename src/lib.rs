@@ -8,3 +8,5 @@ pub mod auth;
 pub mod migration;
 pub mod util;
 pub mod amqp;
+pub mod notification;
+pub mod grpc;
@@ -5,26 +5,31 @@ mod entity;
 mod auth;
 mod migration;
 mod util;
+mod amqp;
+mod notification;
+mod grpc;
 
 use actix_cors::Cors;
 use actix_web::{web, App, HttpServer};
 use actix_web::http::header;
 use actix_web::middleware::from_fn;
-use actix_web::web::{scope, Data, JsonConfig};
+use actix_web::web::{scope, Data, JsonConfig, PayloadConfig};
 use db::init_db;
 use dotenv::dotenv;
 use sea_orm::{Schema, DatabaseBackend, ConnectionTrait, Statement};
 use sea_query::MysqlQueryBuilder;
-use tracing_log::log::info;
+use tracing_log::log::{error, info};
 use tracing_subscriber::EnvFilter;
 use utoipa::OpenApi;
 use utoipa_actix_web::AppExt;
 use utoipa_swagger_ui::SwaggerUi;
 use entity::{event, user};
-use rusty_replay::amqp::{AmqpClient, AmqpConfig};
 use rusty_replay::telemetry::{get_subscriber, init_subscriber};
+use crate::amqp::{AmqpClient, AmqpConfig};
+use crate::api::usage::{IngestLimitConfig, IngestLimiter};
 use crate::auth::{auth_middleware};
 use crate::migration::{Migrator, MigratorTrait};
+use crate::util::sourcemap::SourceMapCache;
 
 #[actix_web::main]
 async fn main() -> anyhow::Result<()> {
@@ -51,11 +56,51 @@ async fn main() -> anyhow::Result<()> {
     let amqp_config = AmqpConfig {
         uri: std::env::var("RABBITMQ_URI").expect("RABBITMQ_URI 환경변수 필요"),
         queue_name: std::env::var("RABBITMQ_QUEUE").expect("RABBITMQ_QUEUE 환경변수 필요"),
+        event_queue_name: std::env::var("RABBITMQ_EVENT_QUEUE").unwrap_or_else(|_| "replay_events".to_string()),
         slack_webhook: std::env::var("SLACK_WEBHOOK").expect("SLACK_WEBHOOK 환경변수 필요"),
     };
 
     let amqp_client = AmqpClient::new(amqp_config).await?;
     let amqp_data = Data::new(amqp_client);
+    let http_data = Data::new(reqwest::Client::new());
+    let limiter_data = Data::new(IngestLimiter::new(IngestLimitConfig::from_env()));
+    let source_map_data = Data::new(SourceMapCache::new());
+
+    let notification_consumer = amqp_data.clone().into_inner();
+    let notification_consumer_db = db_data.get_ref().clone();
+    tokio::spawn(async move {
+        if let Err(e) = notification_consumer.start_notification_consumer(notification_consumer_db).await {
+            error!("알림 컨슈머 종료: {:?}", e);
+        }
+    });
+
+    let event_consumer = amqp_data.clone().into_inner();
+    let event_consumer_db = db_data.get_ref().clone();
+    let event_consumer_limiter = limiter_data.clone().into_inner();
+    let event_consumer_source_maps = source_map_data.clone().into_inner();
+    tokio::spawn(async move {
+        if let Err(e) = event_consumer
+            .start_event_consumer(event_consumer_db, event_consumer_limiter, event_consumer_source_maps)
+            .await
+        {
+            error!("이벤트 컨슈머 종료: {:?}", e);
+        }
+    });
+
+    let usage_flusher = limiter_data.clone().into_inner();
+    let usage_flusher_db = db_data.get_ref().clone();
+    tokio::spawn(async move {
+        usage_flusher.run_usage_flush(usage_flusher_db).await;
+    });
+
+    let grpc_db = db_data.get_ref().clone();
+    let grpc_amqp = amqp_data.clone().into_inner();
+    let grpc_limiter = limiter_data.clone().into_inner();
+    tokio::spawn(async move {
+        if let Err(e) = grpc::start_otlp_grpc_server(grpc_db, grpc_amqp, grpc_limiter).await {
+            error!("OTLP gRPC 수집 서버 종료: {:?}", e);
+        }
+    });
 
     info!("서버 시작 중: http://127.0.0.1:8081");
     HttpServer::new(move || {
@@ -76,9 +121,13 @@ async fn main() -> anyhow::Result<()> {
 
         App::new()
             .app_data(JsonConfig::default().limit(10 * 1024 * 1024))
+            .app_data(PayloadConfig::new(10 * 1024 * 1024))
             .wrap(cors)
             .app_data(db_data.clone())
             .app_data(amqp_data.clone())
+            .app_data(http_data.clone())
+            .app_data(limiter_data.clone())
+            .app_data(source_map_data.clone())
             .service(api::health_check::health_check)
             .service(api::register)
             .service(api::login)
@@ -87,6 +136,7 @@ async fn main() -> anyhow::Result<()> {
             .service(api::report_batch_events)
             .service(api::report_event)
             .service(api::receive_traces)
+            .service(api::get_sdk_config)
             .service(
                 scope("/api")
                     .wrap(from_fn(auth_middleware))
@@ -98,15 +148,65 @@ async fn main() -> anyhow::Result<()> {
                     .service(api::get_project)
                     .service(api::delete_project)
                     .service(api::get_project_users)
+                    .service(api::update_project_ip_settings)
 
+                    .service(api::get_event_countries)
+                    .service(api::export_events)
                     .service(api::get_project_events)
                     .service(api::list_project_events)
                     .service(api::set_priority)
                     .service(api::set_assignee)
                     .service(api::set_event_status)
+                    .service(api::list_tag_facets)
+                    .service(api::get_tag_distribution)
+                    .service(api::get_project_stats)
+
+                    .service(api::create_saved_search)
+                    .service(api::list_saved_searches)
+                    .service(api::get_saved_search)
+                    .service(api::update_saved_search)
+                    .service(api::delete_saved_search)
+
+                    .service(api::export_transactions)
+                    .service(api::export_spans)
+                    .service(api::create_export_job)
+                    .service(api::list_export_jobs)
+                    .service(api::get_export_job)
+                    .service(api::download_export)
+                    .service(api::delete_export_job)
+
+                    .service(api::export_issues)
+                    .service(api::list_project_issues)
+                    .service(api::set_issue_status)
+                    .service(api::set_issue_assignee)
+                    .service(api::get_project_issue)
+                    .service(api::get_issue_activities)
+
+                    .service(api::create_alert_rule)
+                    .service(api::list_alert_rules)
+                    .service(api::get_alert_rule)
+                    .service(api::update_alert_rule)
+                    .service(api::delete_alert_rule)
+
+                    .service(api::create_notification_channel)
+                    .service(api::list_notification_channels)
+                    .service(api::update_notification_channel)
+                    .service(api::delete_notification_channel)
+                    .service(api::test_notification_channel)
+                    .service(api::list_notification_deliveries)
+                    .service(api::replay_notification_delivery)
+                    .service(api::get_project_usage)
+                    .service(api::update_project_limits)
+                    .service(api::update_project_sampling)
+                    .service(api::get_scrubbing_rules)
+                    .service(api::update_scrubbing_rules)
 
                     .service(api::get_transactions)
                     .service(api::get_transaction_spans)
+
+                    .service(api::upload_artifact)
+                    .service(api::list_artifacts)
+                    .service(api::delete_artifact)
             )
             .service(SwaggerUi::new("/swagger-ui/{_:.*}").url("/api-doc/openapi.json", ApiDoc::openapi()))
     })
@@ -132,18 +232,69 @@ async fn main() -> anyhow::Result<()> {
         crate::api::project::get_project,
         crate::api::project::delete_project,
         crate::api::project::get_project_users,
+        crate::api::project::update_project_ip_settings,
 
         crate::api::event::report_event,
         crate::api::event::report_batch_events,
+        crate::api::event::get_event_countries,
         crate::api::event::get_project_events,
         crate::api::event::list_project_events,
         crate::api::event::set_priority,
         crate::api::event::set_assignee,
         crate::api::event::set_event_status,
+        crate::api::tag::list_tag_facets,
+        crate::api::tag::get_tag_distribution,
+        crate::api::stats::get_project_stats,
+
+        crate::api::saved_search::create_saved_search,
+        crate::api::saved_search::list_saved_searches,
+        crate::api::saved_search::get_saved_search,
+        crate::api::saved_search::update_saved_search,
+        crate::api::saved_search::delete_saved_search,
+
+        crate::api::export::export_events,
+        crate::api::export::export_issues,
+        crate::api::export::export_transactions,
+        crate::api::export::export_spans,
+        crate::api::export::create_export_job,
+        crate::api::export::list_export_jobs,
+        crate::api::export::get_export_job,
+        crate::api::export::download_export,
+        crate::api::export::delete_export_job,
+
+        crate::api::issue::list_project_issues,
+        crate::api::issue::get_project_issue,
+        crate::api::issue::set_issue_status,
+        crate::api::issue::set_issue_assignee,
+        crate::api::issue::get_issue_activities,
+
+        crate::api::alert_rule::create_alert_rule,
+        crate::api::alert_rule::list_alert_rules,
+        crate::api::alert_rule::get_alert_rule,
+        crate::api::alert_rule::update_alert_rule,
+        crate::api::alert_rule::delete_alert_rule,
+
+        crate::api::notification_channel::create_notification_channel,
+        crate::api::notification_channel::list_notification_channels,
+        crate::api::notification_channel::update_notification_channel,
+        crate::api::notification_channel::delete_notification_channel,
+        crate::api::notification_channel::test_notification_channel,
+        crate::api::notification_delivery::list_notification_deliveries,
+        crate::api::notification_delivery::replay_notification_delivery,
+        crate::api::usage::get_project_usage,
+        crate::api::usage::update_project_limits,
+        crate::api::sdk_config::get_sdk_config,
+        crate::api::sdk_config::update_project_sampling,
+        crate::api::scrubbing::get_scrubbing_rules,
+        crate::api::scrubbing::update_scrubbing_rules,
 
         crate::api::trace::receive_traces,
         crate::api::trace::get_transaction_spans,
         crate::api::trace::get_transactions,
+
+        crate::api::artifact::upload_artifact,
+        crate::api::artifact::list_artifacts,
+        crate::api::artifact::delete_artifact,
     ),
 )]
 struct ApiDoc;
@@ -0,0 +1,47 @@
+use sea_orm::{EntityName, Schema};
+use sea_orm_migration::prelude::*;
+use crate::entity::project_usage::{Column, Entity};
+
+const UNIQUE_INDEX: &str = "uk_project_usage_project_bucket";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let schema = Schema::new(manager.get_database_backend());
+        manager
+            .create_table(
+                schema
+                    .create_table_from_entity(Entity)
+                    .if_not_exists()
+                    .to_owned()
+            )
+            .await?;
+
+        // 시간 단위 집계를 ON DUPLICATE KEY UPDATE 로 누적한다.
+        if !manager.has_index(Entity.table_name(), UNIQUE_INDEX).await? {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(UNIQUE_INDEX)
+                        .table(Entity)
+                        .col(Column::ProjectId)
+                        .col(Column::Bucket)
+                        .unique()
+                        .to_owned()
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Entity).to_owned())
+            .await
+    }
+}
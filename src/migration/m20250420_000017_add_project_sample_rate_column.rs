@@ -0,0 +1,21 @@
+use sea_orm_migration::prelude::*;
+use crate::entity::project::{Column, Entity};
+use crate::migration::{add_missing_columns, drop_columns};
+
+const COLUMNS: [Column; 1] = [
+    Column::SampleRate,
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        add_missing_columns(manager, Entity, &COLUMNS).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        drop_columns(manager, Entity, &COLUMNS).await
+    }
+}
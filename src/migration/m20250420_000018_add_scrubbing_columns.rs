@@ -0,0 +1,21 @@
+use sea_orm_migration::prelude::*;
+use crate::entity::{event, project, span};
+use crate::migration::{add_missing_columns, drop_columns};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        add_missing_columns(manager, project::Entity, &[project::Column::ScrubbingRules]).await?;
+        add_missing_columns(manager, event::Entity, &[event::Column::ScrubbedFields]).await?;
+        add_missing_columns(manager, span::Entity, &[span::Column::ScrubbedFields]).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        drop_columns(manager, span::Entity, &[span::Column::ScrubbedFields]).await?;
+        drop_columns(manager, event::Entity, &[event::Column::ScrubbedFields]).await?;
+        drop_columns(manager, project::Entity, &[project::Column::ScrubbingRules]).await
+    }
+}
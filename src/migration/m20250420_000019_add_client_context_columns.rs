@@ -0,0 +1,32 @@
+use sea_orm_migration::prelude::*;
+use crate::entity::{event, project};
+use crate::migration::{add_missing_columns, drop_columns};
+
+const PROJECT_COLUMNS: [project::Column; 2] = [
+    project::Column::CollectIpAddress,
+    project::Column::TrustedProxies,
+];
+
+const EVENT_COLUMNS: [event::Column; 5] = [
+    event::Column::BrowserName,
+    event::Column::BrowserVersion,
+    event::Column::OsName,
+    event::Column::OsVersion,
+    event::Column::DeviceType,
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        add_missing_columns(manager, project::Entity, &PROJECT_COLUMNS).await?;
+        add_missing_columns(manager, event::Entity, &EVENT_COLUMNS).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        drop_columns(manager, event::Entity, &EVENT_COLUMNS).await?;
+        drop_columns(manager, project::Entity, &PROJECT_COLUMNS).await
+    }
+}
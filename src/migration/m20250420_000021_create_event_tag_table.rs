@@ -0,0 +1,72 @@
+use sea_orm::{EntityName, Schema};
+use sea_orm_migration::prelude::*;
+use crate::entity::event::{Column as EventColumn, Entity as EventEntity};
+use crate::entity::event_tag::{Column, Entity};
+use crate::migration::{add_missing_columns, drop_columns};
+
+const EVENT_COLUMNS: [EventColumn; 5] = [
+    EventColumn::Breadcrumbs,
+    EventColumn::Tags,
+    EventColumn::UserContext,
+    EventColumn::RequestContext,
+    EventColumn::Contexts,
+];
+
+const PROJECT_KEY_VALUE_INDEX: &str = "idx_event_tags_project_key_value";
+const EVENT_INDEX: &str = "idx_event_tags_event";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        add_missing_columns(manager, EventEntity, &EVENT_COLUMNS).await?;
+
+        let schema = Schema::new(manager.get_database_backend());
+        manager
+            .create_table(
+                schema
+                    .create_table_from_entity(Entity)
+                    .if_not_exists()
+                    .to_owned()
+            )
+            .await?;
+
+        // 프로젝트 안에서 `key:value`로 이벤트를 찾고 키별 값 분포를 센다.
+        if !manager.has_index(Entity.table_name(), PROJECT_KEY_VALUE_INDEX).await? {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(PROJECT_KEY_VALUE_INDEX)
+                        .table(Entity)
+                        .col(Column::ProjectId)
+                        .col(Column::Key)
+                        .col(Column::Value)
+                        .to_owned()
+                )
+                .await?;
+        }
+        if !manager.has_index(Entity.table_name(), EVENT_INDEX).await? {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(EVENT_INDEX)
+                        .table(Entity)
+                        .col(Column::EventId)
+                        .to_owned()
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Entity).to_owned())
+            .await?;
+        drop_columns(manager, EventEntity, &EVENT_COLUMNS).await
+    }
+}
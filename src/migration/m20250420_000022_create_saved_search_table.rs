@@ -0,0 +1,45 @@
+use sea_orm::{EntityName, Schema};
+use sea_orm_migration::prelude::*;
+use crate::entity::saved_search::{Column, Entity};
+
+const PROJECT_USER_INDEX: &str = "idx_saved_searches_project_user";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let schema = Schema::new(manager.get_database_backend());
+        manager
+            .create_table(
+                schema
+                    .create_table_from_entity(Entity)
+                    .if_not_exists()
+                    .to_owned()
+            )
+            .await?;
+
+        if !manager.has_index(Entity.table_name(), PROJECT_USER_INDEX).await? {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(PROJECT_USER_INDEX)
+                        .table(Entity)
+                        .col(Column::ProjectId)
+                        .col(Column::UserId)
+                        .to_owned()
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Entity).to_owned())
+            .await
+    }
+}
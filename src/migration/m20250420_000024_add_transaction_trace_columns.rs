@@ -0,0 +1,48 @@
+use sea_orm::EntityName;
+use sea_orm_migration::prelude::*;
+use crate::entity::transaction::{Column, Entity};
+use crate::migration::{add_missing_columns, drop_columns};
+
+const TRANSACTION_COLUMNS: [Column; 1] = [
+    Column::Kind,
+];
+
+// 같은 trace의 span이 나중에 들어오면 이 인덱스로 transaction을 찾는다.
+// trace 하나에 transaction 하나만 생기도록 유일 인덱스로 만든다.
+const PROJECT_TRACE_INDEX: &str = "idx_transaction_project_trace";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        add_missing_columns(manager, Entity, &TRANSACTION_COLUMNS).await?;
+
+        if !manager.has_index(Entity.table_name(), PROJECT_TRACE_INDEX).await? {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(PROJECT_TRACE_INDEX)
+                        .table(Entity)
+                        .unique()
+                        .col(Column::ProjectId)
+                        .col(Column::TraceId)
+                        .to_owned()
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.has_index(Entity.table_name(), PROJECT_TRACE_INDEX).await? {
+            manager
+                .drop_index(Index::drop().name(PROJECT_TRACE_INDEX).table(Entity).to_owned())
+                .await?;
+        }
+
+        drop_columns(manager, Entity, &TRANSACTION_COLUMNS).await
+    }
+}
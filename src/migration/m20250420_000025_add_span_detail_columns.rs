@@ -0,0 +1,29 @@
+use sea_orm_migration::prelude::*;
+use crate::entity::span;
+use crate::migration::{add_missing_columns, drop_columns};
+
+const SPAN_COLUMNS: [span::Column; 9] = [
+    span::Column::Kind,
+    span::Column::StatusCode,
+    span::Column::StatusMessage,
+    span::Column::Events,
+    span::Column::Links,
+    span::Column::ResourceAttributes,
+    span::Column::ScopeName,
+    span::Column::ScopeVersion,
+    span::Column::ScopeAttributes,
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        add_missing_columns(manager, span::Entity, &SPAN_COLUMNS).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        drop_columns(manager, span::Entity, &SPAN_COLUMNS).await
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::Utc;
+use sea_orm_migration::prelude::*;
+
+// 회귀 알림은 원래 규칙 없이 항상 발송됐다. 알림 규칙으로 옮기면서 기존 프로젝트가
+// 알림을 잃지 않도록, 회귀 규칙이 없는 프로젝트에 기본 규칙을 만들어 둔다.
+// 나중에 엔티티가 바뀌어도 이 마이그레이션은 그대로 다시 돌 수 있도록 테이블과 컬럼 이름을 직접 쓴다.
+const RULE_NAME: &str = "회귀 알림";
+const REGRESSION: &str = "regression";
+const SLACK_ACTIONS: &str = r#"[{"type":"slack"}]"#;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let now = Utc::now();
+
+        let covered = Query::select()
+            .column(Alias::new("project_id"))
+            .from(Alias::new("alert_rules"))
+            .and_where(Expr::col(Alias::new("condition")).eq(REGRESSION))
+            .to_owned();
+
+        let missing = Query::select()
+            .column(Alias::new("id"))
+            .exprs([
+                Expr::val(RULE_NAME),
+                Expr::val(true),
+                Expr::val(REGRESSION),
+                Expr::val(SLACK_ACTIONS),
+                Expr::val(0),
+                Expr::val(now),
+                Expr::val(now),
+            ])
+            .from(Alias::new("projects"))
+            .and_where(Expr::col(Alias::new("deleted_at")).is_null())
+            .and_where(Expr::col(Alias::new("id")).not_in_subquery(covered))
+            .to_owned();
+
+        let insert = Query::insert()
+            .into_table(Alias::new("alert_rules"))
+            .columns([
+                Alias::new("project_id"),
+                Alias::new("name"),
+                Alias::new("enabled"),
+                Alias::new("condition"),
+                Alias::new("actions"),
+                Alias::new("throttle_minutes"),
+                Alias::new("created_at"),
+                Alias::new("updated_at"),
+            ])
+            .select_from(missing)
+            .map_err(|e| DbErr::Migration(e.to_string()))?
+            .to_owned();
+
+        manager.exec_stmt(insert).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let delete = Query::delete()
+            .from_table(Alias::new("alert_rules"))
+            .and_where(Expr::col(Alias::new("condition")).eq(REGRESSION))
+            .and_where(Expr::col(Alias::new("name")).eq(RULE_NAME))
+            .to_owned();
+
+        manager.exec_stmt(delete).await
+    }
+}
@@ -0,0 +1,19 @@
+use sea_orm_migration::prelude::*;
+use crate::entity::project_usage;
+use crate::migration::{add_missing_columns, drop_columns};
+
+const USAGE_COLUMNS: [project_usage::Column; 1] = [project_usage::Column::SpikeDropped];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        add_missing_columns(manager, project_usage::Entity, &USAGE_COLUMNS).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        drop_columns(manager, project_usage::Entity, &USAGE_COLUMNS).await
+    }
+}
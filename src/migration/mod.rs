@@ -1,4 +1,5 @@
 pub use sea_orm_migration::prelude::*;
+use sea_orm::{EntityTrait, IdenStatic as _, Schema};
 
 mod m20250420_000001_create_event_table;
 mod m20250420_000002_create_user_table;
@@ -7,6 +8,27 @@ mod m20250420_000004_create_issue_table;
 mod m20250420_01_create_project_member_table;
 mod m20250420_000005_create_transaction_table;
 mod m20250420_000006_create_span_table;
+mod m20250420_000007_create_artifact_table;
+mod m20250420_000008_add_event_symbolication_columns;
+mod m20250420_000009_add_event_frames_column;
+mod m20250420_000010_create_issue_activity_table;
+mod m20250420_000011_add_issue_resolution_columns;
+mod m20250420_000012_create_alert_rule_table;
+mod m20250420_000013_create_notification_channel_table;
+mod m20250420_000014_create_notification_delivery_table;
+mod m20250420_000015_add_project_ingest_limit_columns;
+mod m20250420_000016_create_project_usage_table;
+mod m20250420_000017_add_project_sample_rate_column;
+mod m20250420_000018_add_scrubbing_columns;
+mod m20250420_000019_add_client_context_columns;
+mod m20250420_000020_add_event_geo_columns;
+mod m20250420_000021_create_event_tag_table;
+mod m20250420_000022_create_saved_search_table;
+mod m20250420_000023_create_export_job_table;
+mod m20250420_000024_add_transaction_trace_columns;
+mod m20250420_000025_add_span_detail_columns;
+mod m20250420_000026_seed_regression_alert_rules;
+mod m20250420_000027_add_usage_spike_dropped_column;
 
 pub struct Migrator;
 
@@ -21,6 +43,75 @@ impl MigratorTrait for Migrator {
             Box::new(m20250420_000001_create_event_table::Migration),
             Box::new(m20250420_000005_create_transaction_table::Migration),
             Box::new(m20250420_000006_create_span_table::Migration),
+            Box::new(m20250420_000007_create_artifact_table::Migration),
+            Box::new(m20250420_000008_add_event_symbolication_columns::Migration),
+            Box::new(m20250420_000009_add_event_frames_column::Migration),
+            Box::new(m20250420_000010_create_issue_activity_table::Migration),
+            Box::new(m20250420_000011_add_issue_resolution_columns::Migration),
+            Box::new(m20250420_000012_create_alert_rule_table::Migration),
+            Box::new(m20250420_000013_create_notification_channel_table::Migration),
+            Box::new(m20250420_000014_create_notification_delivery_table::Migration),
+            Box::new(m20250420_000015_add_project_ingest_limit_columns::Migration),
+            Box::new(m20250420_000016_create_project_usage_table::Migration),
+            Box::new(m20250420_000017_add_project_sample_rate_column::Migration),
+            Box::new(m20250420_000018_add_scrubbing_columns::Migration),
+            Box::new(m20250420_000019_add_client_context_columns::Migration),
+            Box::new(m20250420_000020_add_event_geo_columns::Migration),
+            Box::new(m20250420_000021_create_event_tag_table::Migration),
+            Box::new(m20250420_000022_create_saved_search_table::Migration),
+            Box::new(m20250420_000023_create_export_job_table::Migration),
+            Box::new(m20250420_000024_add_transaction_trace_columns::Migration),
+            Box::new(m20250420_000025_add_span_detail_columns::Migration),
+            Box::new(m20250420_000026_seed_regression_alert_rules::Migration),
+            Box::new(m20250420_000027_add_usage_spike_dropped_column::Migration),
         ]
     }
+}
+
+/// 엔티티에 새로 추가된 컬럼을 기존 테이블에 추가한다.
+/// 새 DB는 `create_table_from_entity`로 이미 컬럼이 만들어지므로 없는 컬럼만 추가한다.
+pub async fn add_missing_columns<E>(manager: &SchemaManager<'_>, entity: E, columns: &[E::Column]) -> Result<(), DbErr>
+where
+    E: EntityTrait,
+{
+    let schema = Schema::new(manager.get_database_backend());
+
+    for column in columns {
+        if manager.has_column(entity.table_name(), column.as_str()).await? {
+            continue;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity)
+                    .add_column(schema.get_column_def::<E>(*column))
+                    .to_owned()
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+pub async fn drop_columns<E>(manager: &SchemaManager<'_>, entity: E, columns: &[E::Column]) -> Result<(), DbErr>
+where
+    E: EntityTrait,
+{
+    for column in columns {
+        if !manager.has_column(entity.table_name(), column.as_str()).await? {
+            continue;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(entity)
+                    .drop_column(*column)
+                    .to_owned()
+            )
+            .await?;
+    }
+
+    Ok(())
 }
\ No newline at end of file
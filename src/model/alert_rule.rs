@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::entity::alert_rule::{AlertCondition, Model as AlertRuleModel};
+
+/// 알림 규칙 필터. 지정한 값이 모두 일치하는 이벤트에만 규칙을 적용한다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertFilters {
+    pub environment: Option<String>,
+    pub release: Option<String>,
+    pub browser: Option<String>,         // 부분 일치, 대소문자 무시 (예: "chrome")
+    pub message_pattern: Option<String>, // 정규식
+}
+
+/// 규칙이 발동했을 때 실행할 동작
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertAction {
+    /// 서버에 설정된 기본 Slack webhook (SLACK_WEBHOOK)
+    Slack,
+    /// 프로젝트에 등록된 알림 채널
+    #[serde(rename_all = "camelCase")]
+    Channel { channel_id: i32 },
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertRuleRequest {
+    pub name: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    pub condition: AlertCondition,
+    pub threshold: Option<i32>,
+    pub interval_minutes: Option<i32>,
+    #[serde(default)]
+    pub filters: AlertFilters,
+    pub actions: Vec<AlertAction>,
+    #[serde(default = "default_throttle_minutes")]
+    pub throttle_minutes: i32,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_throttle_minutes() -> i32 {
+    30
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertRuleResponse {
+    pub id: i32,
+    pub project_id: i32,
+    pub name: String,
+    pub enabled: bool,
+    pub condition: AlertCondition,
+    pub threshold: Option<i32>,
+    pub interval_minutes: Option<i32>,
+    pub filters: AlertFilters,
+    pub actions: Vec<AlertAction>,
+    pub throttle_minutes: i32,
+    pub last_triggered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<AlertRuleModel> for AlertRuleResponse {
+    fn from(model: AlertRuleModel) -> Self {
+        Self {
+            id: model.id,
+            project_id: model.project_id,
+            name: model.name,
+            enabled: model.enabled,
+            condition: model.condition,
+            threshold: model.threshold,
+            interval_minutes: model.interval_minutes,
+            filters: model
+                .filters
+                .and_then(|value| serde_json::from_value(value).ok())
+                .unwrap_or_default(),
+            actions: serde_json::from_value(model.actions).unwrap_or_default(),
+            throttle_minutes: model.throttle_minutes,
+            last_triggered_at: model.last_triggered_at,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use crate::entity::artifact::Model as ArtifactModel;
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactUploadRequest {
+    pub app_version: String,
+    pub file_name: String, // 번들 파일 이름 또는 URL (예: https://cdn.example.com/js/main.abc123.js)
+    pub source_map: Value,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactQuery {
+    pub app_version: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactResponse {
+    pub id: i32,
+    pub project_id: i32,
+    pub app_version: String,
+    pub name: String,
+    pub size: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ArtifactModel> for ArtifactResponse {
+    fn from(model: ArtifactModel) -> Self {
+        Self {
+            id: model.id,
+            project_id: model.project_id,
+            app_version: model.app_version,
+            name: model.name,
+            size: model.size,
+            created_at: model.created_at,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 #[derive(Serialize, ToSchema)]
@@ -29,4 +29,25 @@ impl<T> PaginationResponse<T> {
             is_last,
         }
     }
-}
\ No newline at end of file
+}
+
+/// 목록 조회 방식. `cursor`는 전체 개수를 세지 않고 `(정렬 값, ID)` 기준으로 이어서 가져온다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PaginationMode {
+    #[default]
+    Offset,
+    Cursor,
+}
+
+/// 커서 방식 목록 응답. 같은 커서는 `Link` 헤더(`rel="next"`, `rel="prev"`)로도 내려간다.
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CursorPaginationResponse<T> {
+    pub content: Vec<T>,
+    pub page_size: u32,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+    pub has_next: bool,
+    pub has_prev: bool,
+}
@@ -1,10 +1,13 @@
+use std::collections::BTreeMap;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use utoipa::ToSchema;
 use crate::entity::event::{EventStatus, Model as EventModel, Priority};
+use crate::model::common::PaginationMode;
+use crate::util::user_agent::DeviceType;
 
-#[derive(Debug, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct EventReportRequest {
     pub message: String,
@@ -19,6 +22,84 @@ pub struct EventReportRequest {
     pub api_key: String, // 프로젝트 API 키
     pub user_id: Option<i32>, // 에러가 발생한 사용자 ID
     pub additional_info: Option<Value>,
+    #[serde(default)]
+    pub breadcrumbs: Vec<Breadcrumb>,
+    #[serde(default)]
+    pub tags: BTreeMap<String, String>,
+    pub user: Option<UserContext>,
+    pub request: Option<RequestContext>,
+    pub contexts: Option<EventContexts>,
+}
+
+/// 에러가 나기 전까지 SDK가 기록한 사용자 행동 / 네트워크 요청 / 콘솔 로그
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Breadcrumb {
+    pub timestamp: DateTime<Utc>,
+    pub category: String, // "navigation", "ui.click", "http", "console" ...
+    pub message: Option<String>,
+    #[serde(default)]
+    pub level: BreadcrumbLevel,
+    pub data: Option<Value>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BreadcrumbLevel {
+    Debug,
+    #[default]
+    Info,
+    Warning,
+    Error,
+    Fatal,
+}
+
+/// 에러를 겪은 최종 사용자 (서비스의 사용자이며 프로젝트 멤버가 아니다)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserContext {
+    pub id: Option<String>,
+    pub email: Option<String>,
+    pub username: Option<String>,
+    pub segment: Option<String>, // "free", "enterprise" ...
+    // 스크러빙 전에 구한 사용자 식별값(id, 없으면 email)의 해시. 값이 가려져도 사용자를 구분할 수 있다.
+    // 서버가 채워 저장하는 값이라 요청에서 받지 않고 응답에도 내보내지 않는다.
+    #[serde(default, skip_deserializing, skip_serializing_if = "Option::is_none")]
+    #[schema(ignore)]
+    pub key_hash: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestContext {
+    pub url: Option<String>,
+    pub method: Option<String>,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default)]
+    pub query: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventContexts {
+    pub device: Option<DeviceContext>,
+    pub runtime: Option<RuntimeContext>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceContext {
+    pub brand: Option<String>,
+    pub model: Option<String>,
+    pub screen_resolution: Option<String>, // "1920x1080"
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeContext {
+    pub name: String, // "node", "browser", "python" ...
+    pub version: Option<String>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -36,9 +117,27 @@ pub struct EventReportResponse {
     pub os: Option<String>,
     pub ip_address: Option<String>,
     pub user_agent: Option<String>,
+    pub browser_name: Option<String>,
+    pub browser_version: Option<String>,
+    pub os_name: Option<String>,
+    pub os_version: Option<String>,
+    pub device_type: Option<String>,
+    pub country_code: Option<String>,
+    pub country_name: Option<String>,
+    pub region: Option<String>,
+    pub city: Option<String>,
     pub project_id: i32,
     pub issue_id: Option<i32>,
     pub additional_info: Option<Value>,
+    pub breadcrumbs: Vec<Breadcrumb>,
+    pub tags: BTreeMap<String, String>,
+    pub user: Option<UserContext>,
+    pub request: Option<RequestContext>,
+    pub contexts: Option<EventContexts>,
+    pub frames: Option<Vec<StackFrame>>,
+    pub symbolicated_stacktrace: Option<String>,
+    pub symbolicated_frames: Option<Vec<SymbolicatedFrame>>,
+    pub scrubbed_fields: Vec<String>,
     pub created_at: String,
     pub updated_at: Option<String>,
 
@@ -47,6 +146,31 @@ pub struct EventReportResponse {
     pub status: EventStatus,
 }
 
+/// 스택트레이스에서 파싱한 프레임
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StackFrame {
+    pub function: Option<String>,
+    pub module: Option<String>,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+    pub in_app: bool,
+}
+
+/// 소스맵으로 복원한 원본 스택 프레임
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolicatedFrame {
+    pub function: Option<String>,
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    pub pre_context: Vec<String>,
+    pub context_line: Option<String>,
+    pub post_context: Vec<String>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct EventReportListResponse {
@@ -59,6 +183,10 @@ pub struct EventReportListResponse {
     pub issue_id: Option<i32>,
     pub browser: Option<String>,
     pub os: Option<String>,
+    pub browser_name: Option<String>,
+    pub os_name: Option<String>,
+    pub device_type: Option<String>,
+    pub country_code: Option<String>,
     pub has_replay: bool,
     pub priority: Option<Priority>,
     pub assigned_to: Option<i32>,
@@ -77,6 +205,10 @@ impl From<EventModel> for EventReportListResponse {
             issue_id: model.issue_id,
             browser: model.browser,
             os: model.os,
+            browser_name: model.browser_name,
+            os_name: model.os_name,
+            device_type: model.device_type,
+            country_code: model.country_code,
             has_replay: model.replay.is_some(),
             priority: model.priority,
             assigned_to: model.assigned_to,
@@ -106,9 +238,40 @@ impl From<EventModel> for EventReportResponse {
             os: model.os,
             ip_address: model.ip_address,
             user_agent: model.user_agent,
+            browser_name: model.browser_name,
+            browser_version: model.browser_version,
+            os_name: model.os_name,
+            os_version: model.os_version,
+            device_type: model.device_type,
+            country_code: model.country_code,
+            country_name: model.country_name,
+            region: model.region,
+            city: model.city,
             project_id: model.project_id,
             issue_id: model.issue_id,
             additional_info: model.additional_info,
+            breadcrumbs: model
+                .breadcrumbs
+                .and_then(|breadcrumbs| serde_json::from_value(breadcrumbs).ok())
+                .unwrap_or_default(),
+            tags: model
+                .tags
+                .and_then(|tags| serde_json::from_value(tags).ok())
+                .unwrap_or_default(),
+            user: model.user_context.and_then(|user| serde_json::from_value(user).ok()),
+            request: model.request_context.and_then(|request| serde_json::from_value(request).ok()),
+            contexts: model.contexts.and_then(|contexts| serde_json::from_value(contexts).ok()),
+            frames: model
+                .frames
+                .and_then(|frames| serde_json::from_value(frames).ok()),
+            symbolicated_stacktrace: model.symbolicated_stacktrace,
+            symbolicated_frames: model
+                .symbolicated_frames
+                .and_then(|frames| serde_json::from_value(frames).ok()),
+            scrubbed_fields: model
+                .scrubbed_fields
+                .and_then(|fields| serde_json::from_value(fields).ok())
+                .unwrap_or_default(),
             created_at: model.created_at.to_string(),
             updated_at: model.updated_at.map(|dt| dt.to_string()),
             priority: model.priority,
@@ -133,14 +296,45 @@ pub struct BatchEventReportResponse {
     pub events: Vec<String>,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventAcceptedResponse {
+    pub accepted: usize,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EventQuery {
     pub search: Option<String>,
+    pub query: Option<String>, // `is:unresolved browser:Firefox release:>=1.4` 형태의 검색어
+    pub saved_search_id: Option<i32>, // 저장된 검색의 검색어와 정렬을 적용한다
+    pub pagination: Option<PaginationMode>,
+    pub cursor: Option<String>, // 주면 cursor 방식으로 조회한다
     pub page: Option<u32>,
     pub page_size: Option<u32>,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
+    pub browser_name: Option<String>,
+    pub os_name: Option<String>,
+    pub device_type: Option<DeviceType>,
+    pub country_code: Option<String>,
+    pub tag: Option<String>, // `browser:Chrome,os:Windows`
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventCountryQuery {
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+}
+
+/// 국가별 이벤트 수. 위치를 찾지 못한 이벤트는 `countryCode`가 비어 있다.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventCountryCount {
+    pub country_code: Option<String>,
+    pub country_name: Option<String>,
+    pub count: i64,
 }
 
 #[derive(Debug, Serialize)]
@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::entity::export_job::{ExportFormat, ExportStatus, ExportTarget, Model as ExportJobModel};
+
+/// 내보내기 형식. 나머지 쿼리 파라미터는 각 목록 API의 필터를 그대로 쓴다.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportQuery {
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportJobQuery {
+    pub target: ExportTarget,
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportJobResponse {
+    pub id: i32,
+    pub project_id: i32,
+    pub target: ExportTarget,
+    pub format: ExportFormat,
+    pub filters: String,
+    pub status: ExportStatus,
+    pub row_count: Option<i64>,
+    pub file_size: Option<i64>,
+    pub error: Option<String>,
+    pub download_url: Option<String>, // 완료된 작업만
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl From<ExportJobModel> for ExportJobResponse {
+    fn from(model: ExportJobModel) -> Self {
+        let download_url = (model.status == ExportStatus::Completed)
+            .then(|| format!("/api/projects/{}/exports/{}/download", model.project_id, model.id));
+
+        Self {
+            id: model.id,
+            project_id: model.project_id,
+            target: model.target,
+            format: model.format,
+            filters: model.filters,
+            status: model.status,
+            row_count: model.row_count,
+            file_size: model.file_size,
+            error: model.error,
+            download_url,
+            created_at: model.created_at,
+            completed_at: model.completed_at,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use actix_web::{HttpResponse, ResponseError, http::StatusCode};
+use actix_web::{HttpResponse, ResponseError, http::{header, StatusCode}};
 use thiserror::Error;
 use std::fmt;
 use jsonwebtoken::errors::ErrorKind;
@@ -11,6 +11,7 @@ pub enum ErrorCode {
     TransactionNotFound,
     InvalidEvent,
     InvalidAssignee,
+    InvalidIssue,
     ValidationError,
     DuplicateAccountEmail,
     InvalidPassword,
@@ -29,6 +30,13 @@ pub enum ErrorCode {
     GroupNotFound,
     ProjectNotFound,
     ErrorLogNotFound,
+    ArtifactNotFound,
+    IssueNotFound,
+    AlertRuleNotFound,
+    NotificationChannelNotFound,
+    NotificationDeliveryNotFound,
+    SavedSearchNotFound,
+    ExportJobNotFound,
 
     DatabaseError,
     InternalError,
@@ -37,6 +45,13 @@ pub enum ErrorCode {
     JwtExpiredToken,
     ExpiredRefreshToken,
     MissingField,
+    InvalidSourceMap,
+    InvalidNotificationChannel,
+    NotificationFailed,
+    DeliveryNotReplayable,
+    RateLimited,
+    QuotaExceeded,
+    ExportNotReady,
 
 }
 
@@ -46,6 +61,7 @@ impl ErrorCode {
             ErrorCode::TransactionNotFound => "유효하지 않은 트랜잭션 ID입니다",
             ErrorCode::InvalidEvent => "이벤트를 찾을 수 없습니다",
             ErrorCode::InvalidAssignee => "assignee를 찾을 수 없습니다",
+            ErrorCode::InvalidIssue => "이슈를 찾을 수 없습니다",
             ErrorCode::MissingField => "필수 요청값이 누락되었습니다",
             ErrorCode::ValidationError => "요청값 유효성 검사에 실패했습니다",
             ErrorCode::DuplicateAccountEmail => "이미 등록된 이메일입니다. 로그인해주세요",
@@ -68,6 +84,20 @@ impl ErrorCode {
             ErrorCode::JwtInvalidToken => "JWT 토큰이 유효하지 않습니다",
             ErrorCode::JwtExpiredToken => "JWT 토큰이 만료되었습니다",
             ErrorCode::ExpiredRefreshToken => "refreshToken이 만료되었습니다",
+            ErrorCode::ArtifactNotFound => "유효하지 않은 소스맵 ID입니다",
+            ErrorCode::IssueNotFound => "유효하지 않은 이슈 ID입니다",
+            ErrorCode::AlertRuleNotFound => "유효하지 않은 알림 규칙 ID입니다",
+            ErrorCode::NotificationChannelNotFound => "유효하지 않은 알림 채널 ID입니다",
+            ErrorCode::NotificationDeliveryNotFound => "유효하지 않은 알림 발송 ID입니다",
+            ErrorCode::SavedSearchNotFound => "유효하지 않은 저장된 검색 ID입니다",
+            ErrorCode::ExportJobNotFound => "유효하지 않은 내보내기 작업 ID입니다",
+            ErrorCode::InvalidSourceMap => "소스맵 형식이 올바르지 않습니다",
+            ErrorCode::InvalidNotificationChannel => "알림 채널 설정이 올바르지 않습니다",
+            ErrorCode::NotificationFailed => "알림 발송에 실패했습니다",
+            ErrorCode::DeliveryNotReplayable => "실패한 알림만 재발송할 수 있습니다",
+            ErrorCode::RateLimited => "요청이 너무 많습니다. 잠시 후 다시 시도해주세요",
+            ErrorCode::QuotaExceeded => "이번 달 이벤트 쿼터를 모두 사용했습니다",
+            ErrorCode::ExportNotReady => "내보내기 파일이 아직 준비되지 않았습니다",
         }
     }
 }
@@ -95,6 +125,10 @@ pub enum AppError {
     #[error("Internal Server Error: {0:?}")]
     InternalServerError(ErrorCode),
 
+    /// 두 번째 값은 `Retry-After` 헤더로 내려줄 초
+    #[error("Too Many Requests: {0:?}")]
+    TooManyRequests(ErrorCode, u64),
+
     #[error("Validation Error")]
     ValidationError(Vec<ValidationFieldError>),
 }
@@ -119,6 +153,10 @@ impl AppError {
     pub fn internal_error(code: ErrorCode) -> Self {
         Self::InternalServerError(code)
     }
+
+    pub fn too_many_requests(code: ErrorCode, retry_after_secs: u64) -> Self {
+        Self::TooManyRequests(code, retry_after_secs)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -176,6 +214,7 @@ impl ResponseError for AppError {
             AppError::Forbidden(_) => StatusCode::FORBIDDEN,
             AppError::NotFound(_) => StatusCode::NOT_FOUND,
             AppError::InternalServerError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AppError::TooManyRequests(..) => StatusCode::TOO_MANY_REQUESTS,
             AppError::ValidationError(_) => StatusCode::BAD_REQUEST,
         }
     }
@@ -189,6 +228,14 @@ impl ResponseError for AppError {
                     errors: errors.clone(),
                 })
             }
+            AppError::TooManyRequests(code, retry_after_secs) => {
+                HttpResponse::TooManyRequests()
+                    .insert_header((header::RETRY_AFTER, retry_after_secs.to_string()))
+                    .json(ErrorResponse::General {
+                        error_code: format!("{:?}", code),
+                        message: code.message().to_string(),
+                    })
+            }
             _ => {
                 let code = match self {
                     AppError::BadRequest(c)
@@ -0,0 +1,140 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use serde_json::Value;
+use crate::entity::issue::{IssueStatus, Model as IssueModel};
+use crate::entity::issue_activity::{ActivityKind, Model as IssueActivityModel};
+use crate::model::common::PaginationMode;
+use crate::model::stats::StatsPeriod;
+use crate::model::event::{EventReportListResponse, EventReportResponse};
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueResponse {
+    pub id: i32,
+    pub project_id: i32,
+    pub title: String,
+    pub group_hash: String,
+    pub status: IssueStatus,
+    pub count: i32,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    pub assigned_to: Option<i32>,
+    pub last_release: Option<String>,
+    pub resolved_in_version: Option<String>,
+    pub resolved_in_next_release: bool,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub sparkline: Option<Vec<i64>>, // 목록 조회에서만 채운다. 오래된 구간부터의 이벤트 수
+}
+
+impl From<IssueModel> for IssueResponse {
+    fn from(model: IssueModel) -> Self {
+        Self {
+            id: model.id,
+            project_id: model.project_id,
+            title: model.title,
+            group_hash: model.group_hash,
+            status: model.status,
+            count: model.count,
+            first_seen: model.first_seen,
+            last_seen: model.last_seen,
+            assigned_to: model.assigned_to,
+            last_release: model.last_release,
+            resolved_in_version: model.resolved_in_version,
+            resolved_in_next_release: model.resolved_in_next_release,
+            resolved_at: model.resolved_at,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+            sparkline: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueDetailResponse {
+    pub issue: IssueResponse,
+    pub latest_event: Option<EventReportResponse>,
+    pub recent_events: Vec<EventReportListResponse>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum IssueSort {
+    #[default]
+    LastSeen,
+    FirstSeen,
+    Count,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueQuery {
+    pub status: Option<IssueStatus>,
+    pub assigned_to: Option<i32>,
+    pub search: Option<String>,
+    pub query: Option<String>, // `is:unresolved assigned:me age:-24h` 형태의 검색어
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub sort: Option<IssueSort>,
+    pub order: Option<SortOrder>,
+    pub pagination: Option<PaginationMode>,
+    pub cursor: Option<String>, // 주면 cursor 방식으로 조회한다
+    pub stats_period: Option<StatsPeriod>, // 스파크라인 기간
+    pub page: Option<u32>,
+    pub page_size: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueStatusDto {
+    pub issue_ids: Vec<i32>,
+    pub status: IssueStatus,
+    /// 이 버전에서 해결됨 (status 가 resolved 일 때만 사용)
+    #[serde(default)]
+    pub resolved_in_version: Option<String>,
+    /// 다음 릴리즈에서 해결됨 (status 가 resolved 일 때만 사용)
+    #[serde(default)]
+    pub resolved_in_next_release: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueAssignee {
+    pub issue_ids: Vec<i32>,
+    pub assigned_to: Option<i32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueActivityResponse {
+    pub id: i32,
+    pub issue_id: i32,
+    pub kind: ActivityKind,
+    pub user_id: Option<i32>,
+    pub data: Option<Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<IssueActivityModel> for IssueActivityResponse {
+    fn from(model: IssueActivityModel) -> Self {
+        Self {
+            id: model.id,
+            issue_id: model.issue_id,
+            kind: model.kind,
+            user_id: model.user_id,
+            data: model.data,
+            created_at: model.created_at,
+        }
+    }
+}
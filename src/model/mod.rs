@@ -5,5 +5,17 @@ pub mod project;
 pub mod transaction;
 pub mod span;
 pub mod common;
+pub mod artifact;
+pub mod issue;
+pub mod alert_rule;
+pub mod notification_channel;
+pub mod notification_delivery;
+pub mod usage;
+pub mod sdk_config;
+pub mod scrubbing;
+pub mod tag;
+pub mod saved_search;
+pub mod export;
+pub mod stats;
 
 pub use auth::{RegisterRequest, LoginRequest, AuthResponse, RefreshTokenRequest, Claims, UserResponse};
\ No newline at end of file
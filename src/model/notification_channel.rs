@@ -0,0 +1,136 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::entity::notification_channel::{ChannelKind, Model as NotificationChannelModel};
+
+// 응답에서 비밀값 대신 내려주는 값. 수정 요청에 이 값이 오면 저장된 비밀값을 유지한다.
+pub const MASKED_SECRET: &str = "********";
+
+/// 채널 종류별 설정
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChannelConfig {
+    #[serde(rename_all = "camelCase")]
+    Slack { webhook_url: String },
+    /// 본문을 `secret`으로 HMAC-SHA256 서명해서 `X-Replay-Signature` 헤더에 담는다.
+    #[serde(rename_all = "camelCase")]
+    Webhook { url: String, secret: Option<String> },
+    #[serde(rename_all = "camelCase")]
+    Discord { webhook_url: String },
+    #[serde(rename_all = "camelCase")]
+    Teams { webhook_url: String },
+    Email(EmailConfig),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailConfig {
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    #[serde(default)]
+    pub security: SmtpSecurity,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub from: String,
+    pub to: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpSecurity {
+    None,
+    #[default]
+    Starttls,
+    Tls,
+}
+
+impl ChannelConfig {
+    pub fn kind(&self) -> ChannelKind {
+        match self {
+            ChannelConfig::Slack { .. } => ChannelKind::Slack,
+            ChannelConfig::Webhook { .. } => ChannelKind::Webhook,
+            ChannelConfig::Discord { .. } => ChannelKind::Discord,
+            ChannelConfig::Teams { .. } => ChannelKind::Teams,
+            ChannelConfig::Email(_) => ChannelKind::Email,
+        }
+    }
+
+    /// 응답용으로 비밀값을 가린다.
+    pub fn masked(mut self) -> Self {
+        match &mut self {
+            ChannelConfig::Webhook { secret, .. } => mask(secret),
+            ChannelConfig::Email(email) => mask(&mut email.password),
+            _ => {}
+        }
+        self
+    }
+
+    /// 수정 요청에 가려진 비밀값이 그대로 오면 기존 값을 유지한다.
+    pub fn keep_secrets(mut self, previous: &ChannelConfig) -> Self {
+        match (&mut self, previous) {
+            (ChannelConfig::Webhook { secret, .. }, ChannelConfig::Webhook { secret: previous, .. }) => {
+                keep(secret, previous)
+            }
+            (ChannelConfig::Email(email), ChannelConfig::Email(previous)) => {
+                keep(&mut email.password, &previous.password)
+            }
+            _ => {}
+        }
+        self
+    }
+}
+
+fn mask(secret: &mut Option<String>) {
+    if secret.is_some() {
+        *secret = Some(MASKED_SECRET.to_string());
+    }
+}
+
+fn keep(secret: &mut Option<String>, previous: &Option<String>) {
+    if secret.as_deref() == Some(MASKED_SECRET) {
+        *secret = previous.clone();
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationChannelRequest {
+    pub name: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    pub config: ChannelConfig,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationChannelResponse {
+    pub id: i32,
+    pub project_id: i32,
+    pub name: String,
+    pub kind: ChannelKind,
+    pub enabled: bool,
+    pub config: Option<ChannelConfig>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<NotificationChannelModel> for NotificationChannelResponse {
+    fn from(model: NotificationChannelModel) -> Self {
+        Self {
+            id: model.id,
+            project_id: model.project_id,
+            name: model.name,
+            kind: model.kind,
+            enabled: model.enabled,
+            config: serde_json::from_value::<ChannelConfig>(model.config)
+                .ok()
+                .map(ChannelConfig::masked),
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::entity::notification_delivery::{DeliveryStatus, Model as NotificationDeliveryModel};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDeliveryQuery {
+    pub status: Option<DeliveryStatus>,
+    pub channel_id: Option<i32>,
+    pub page: Option<u32>,
+    pub page_size: Option<u32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDeliveryResponse {
+    pub id: i32,
+    pub project_id: i32,
+    pub channel_id: Option<i32>,
+    pub title: String,
+    pub text: String,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<NotificationDeliveryModel> for NotificationDeliveryResponse {
+    fn from(model: NotificationDeliveryModel) -> Self {
+        Self {
+            id: model.id,
+            project_id: model.project_id,
+            channel_id: model.channel_id,
+            title: model.title,
+            text: model.text,
+            status: model.status,
+            attempts: model.attempts,
+            last_error: model.last_error,
+            delivered_at: model.delivered_at,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
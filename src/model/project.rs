@@ -16,6 +16,14 @@ pub struct ProjectUpdateRequest {
     pub description: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectIpSettingsRequest {
+    pub collect_ip_address: bool,
+    #[serde(default)]
+    pub trusted_proxies: Vec<String>, // IP 또는 CIDR (예: 10.0.0.0/8)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectInviteRequest {
@@ -30,6 +38,12 @@ pub struct ProjectResponse {
     pub name: String,
     pub api_key: String,
     pub description: Option<String>,
+    pub rate_limit_per_second: Option<i32>,
+    pub rate_limit_burst: Option<i32>,
+    pub monthly_event_quota: Option<i64>,
+    pub sample_rate: Option<f64>,
+    pub collect_ip_address: bool,
+    pub trusted_proxies: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
 }
@@ -41,6 +55,15 @@ impl From<ProjectModel> for ProjectResponse {
             name: model.name,
             description: model.description,
             api_key: model.api_key,
+            rate_limit_per_second: model.rate_limit_per_second,
+            rate_limit_burst: model.rate_limit_burst,
+            monthly_event_quota: model.monthly_event_quota,
+            sample_rate: model.sample_rate,
+            collect_ip_address: model.collect_ip_address,
+            trusted_proxies: model
+                .trusted_proxies
+                .and_then(|proxies| serde_json::from_value(proxies).ok())
+                .unwrap_or_default(),
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::entity::saved_search::{Model as SavedSearchModel, SavedSearchSort, SavedSearchVisibility};
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchRequest {
+    pub name: String,
+    pub query: String,
+    #[serde(default)]
+    pub visibility: SavedSearchVisibility,
+    #[serde(default)]
+    pub sort: SavedSearchSort,
+    #[serde(default)]
+    pub is_pinned: bool,
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearchResponse {
+    pub id: i32,
+    pub project_id: i32,
+    pub user_id: i32,
+    pub visibility: SavedSearchVisibility,
+    pub name: String,
+    pub query: String,
+    pub sort: SavedSearchSort,
+    pub is_pinned: bool,
+    pub is_default: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<SavedSearchModel> for SavedSearchResponse {
+    fn from(model: SavedSearchModel) -> Self {
+        Self {
+            id: model.id,
+            project_id: model.project_id,
+            user_id: model.user_id,
+            visibility: model.visibility,
+            name: model.name,
+            query: model.query,
+            sort: model.sort,
+            is_pinned: model.is_pinned,
+            is_default: model.is_default,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 프로젝트별 데이터 스크러빙 규칙. `projects.scrubbing_rules`에 JSON으로 저장한다.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrubbingRules {
+    /// 기본 규칙(카드 번호, 이메일, Bearer 토큰, 비밀번호류 키) 사용 여부
+    #[serde(default = "default_use_defaults")]
+    pub use_defaults: bool,
+    /// 값에서 찾아 가릴 정규식
+    #[serde(default)]
+    pub patterns: Vec<String>,
+    /// 이 문자열이 이름에 들어간 JSON 키의 값은 통째로 가린다 (대소문자 무시)
+    #[serde(default)]
+    pub keys: Vec<String>,
+}
+
+fn default_use_defaults() -> bool {
+    true
+}
+
+impl Default for ScrubbingRules {
+    fn default() -> Self {
+        Self {
+            use_defaults: true,
+            patterns: Vec::new(),
+            keys: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SdkConfigQuery {
+    pub api_key: String,
+}
+
+/// SDK가 주기적으로 받아가는 클라이언트 설정
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SdkConfigResponse {
+    pub sample_rate: f64, // 0이면 보내지 않고, 1이면 모두 보낸다
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSamplingRequest {
+    pub sample_rate: Option<f64>, // 비우면 샘플링하지 않음
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use utoipa::ToSchema;
 use crate::entity::span;
+use crate::model::common::PaginationMode;
 use crate::model::transaction::TransactionResponse;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
@@ -25,6 +26,15 @@ pub struct SpanResponse {
     pub http_scheme: Option<String>,
     pub http_user_agent: Option<String>,
     pub attributes: Option<Value>,
+    pub kind: Option<String>,
+    pub status_code: Option<String>,
+    pub status_message: Option<String>,
+    pub events: Option<Value>,
+    pub links: Option<Value>,
+    pub resource_attributes: Option<Value>,
+    pub scope_name: Option<String>,
+    pub scope_version: Option<String>,
+    pub scope_attributes: Option<Value>,
 }
 
 impl From<span::Model> for SpanResponse {
@@ -47,6 +57,15 @@ impl From<span::Model> for SpanResponse {
             http_scheme: model.http_scheme,
             http_user_agent: model.http_user_agent,
             attributes: model.attributes,
+            kind: model.kind,
+            status_code: model.status_code,
+            status_message: model.status_message,
+            events: model.events,
+            links: model.links,
+            resource_attributes: model.resource_attributes,
+            scope_name: model.scope_name,
+            scope_version: model.scope_version,
+            scope_attributes: model.scope_attributes,
         }
     }
 }
@@ -61,6 +80,11 @@ pub struct TransactionListQuery {
     pub page: i32,
     #[serde(default = "default_size")]
     pub size: i32,
+    #[serde(rename = "projectId")]
+    pub project_id: Option<i32>, // 비우면 멤버인 모든 프로젝트
+    pub query: Option<String>, // `environment:production duration:>500ms` 형태의 검색어
+    pub pagination: Option<PaginationMode>,
+    pub cursor: Option<String>, // 주면 cursor 방식으로 조회한다
 }
 
 #[derive(Serialize, ToSchema)]
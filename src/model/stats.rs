@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::util::stats::StatsInterval;
+
+/// 집계할 값
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StatKind {
+    #[default]
+    Events,    // 저장된 이벤트 수
+    NewIssues, // 처음 발생한 이슈 수
+    Users,     // 이벤트를 겪은 사용자 수 (알림 규칙과 같은 사용자 기준)
+    Dropped,   // 수집 제한, 쿼터, 스파이크 샘플링으로 버린 이벤트 수 (1시간 단위로 기록된다)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StatsGroupBy {
+    Environment,
+    Release,
+    Issue,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsQuery {
+    #[serde(default)]
+    pub stat: StatKind,
+    #[serde(default)]
+    pub interval: StatsInterval,
+    pub start_date: Option<DateTime<Utc>>, // 기본값: 종료 24시간 전
+    pub end_date: Option<DateTime<Utc>>,   // 기본값: 지금
+    pub group_by: Option<StatsGroupBy>,
+    pub limit: Option<usize>, // 그룹 수 (많은 순, 기본값: 10)
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsPoint {
+    pub bucket: DateTime<Utc>, // 구간 시작 시각
+    pub count: i64,
+}
+
+/// 그룹 하나의 시계열. 묶지 않으면 `group`이 빈 시계열 하나만 내려간다.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSeries {
+    pub group: Option<String>,
+    pub total: i64,
+    pub points: Vec<StatsPoint>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsResponse {
+    pub stat: StatKind,
+    pub interval: StatsInterval,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub group_by: Option<StatsGroupBy>,
+    pub series: Vec<StatsSeries>,
+}
+
+/// 이슈 목록 스파크라인 기간. `24h`는 1시간, `14d`는 하루 단위 점을 그린다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+pub enum StatsPeriod {
+    #[default]
+    #[serde(rename = "24h")]
+    Day,
+    #[serde(rename = "14d")]
+    TwoWeeks,
+}
+
+impl StatsPeriod {
+    pub fn interval(&self) -> StatsInterval {
+        match self {
+            StatsPeriod::Day => StatsInterval::Hour,
+            StatsPeriod::TwoWeeks => StatsInterval::Day,
+        }
+    }
+
+    pub fn points(&self) -> i64 {
+        match self {
+            StatsPeriod::Day => 24,
+            StatsPeriod::TwoWeeks => 14,
+        }
+    }
+}
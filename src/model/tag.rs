@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagQuery {
+    pub issue_id: Option<i32>, // 비우면 프로젝트 전체
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub limit: Option<u64>, // 키마다 돌려줄 값 수
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TagValueCount {
+    pub value: String,
+    pub count: i64,
+}
+
+/// 태그 키 하나의 값 분포. `total`은 이 키가 붙은 이벤트 수, `values`는 많은 순 상위 값이다.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TagFacetResponse {
+    pub key: String,
+    pub total: i64,
+    pub distinct_values: usize,
+    pub values: Vec<TagValueCount>,
+}
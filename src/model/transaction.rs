@@ -11,6 +11,7 @@ pub struct TransactionResponse {
     pub project_id: i32,
     pub trace_id: String,
     pub name: String,
+    pub kind: Option<String>,
     pub start_timestamp: DateTime<Utc>,
     pub end_timestamp: DateTime<Utc>,
     pub duration_ms: i32,
@@ -27,6 +28,7 @@ impl From<transaction::Model> for TransactionResponse {
             project_id: model.project_id,
             trace_id: model.trace_id,
             name: model.name,
+            kind: model.kind,
             start_timestamp: model.start_timestamp,
             end_timestamp: model.end_timestamp,
             duration_ms: model.duration_ms,
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::entity::project_usage::Model as ProjectUsageModel;
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLimitsRequest {
+    pub rate_limit_per_second: Option<i32>, // 비우면 서버 기본값
+    pub rate_limit_burst: Option<i32>,      // 비우면 서버 기본값
+    pub monthly_event_quota: Option<i64>,   // 비우면 무제한
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageQuery {
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageTotals {
+    pub accepted: i64,
+    pub rate_limited: i64,
+    pub over_quota: i64,
+    pub spike_dropped: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageBucketResponse {
+    pub bucket: DateTime<Utc>,
+    pub accepted: i64,
+    pub rate_limited: i64,
+    pub over_quota: i64,
+    pub spike_dropped: i64,
+}
+
+impl From<ProjectUsageModel> for UsageBucketResponse {
+    fn from(model: ProjectUsageModel) -> Self {
+        Self {
+            bucket: model.bucket,
+            accepted: model.accepted,
+            rate_limited: model.rate_limited,
+            over_quota: model.over_quota,
+            spike_dropped: model.spike_dropped,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUsageResponse {
+    pub rate_limit_per_second: u32,
+    pub rate_limit_burst: u32,
+    pub monthly_event_quota: Option<i64>,
+    pub current_month: UsageTotals,
+    pub buckets: Vec<UsageBucketResponse>,
+}
@@ -0,0 +1,57 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+use super::{send_request, Notification, NotificationChannel};
+
+// 임베드 왼쪽 띠 색상 (빨강)
+const EMBED_COLOR: u32 = 0xE74C3C;
+
+/// Discord webhook
+pub struct DiscordChannel {
+    http: Client,
+    webhook_url: String,
+}
+
+impl DiscordChannel {
+    pub fn new(http: Client, webhook_url: String) -> Self {
+        Self { http, webhook_url }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for DiscordChannel {
+    async fn send(&self, notification: &Notification) -> Result<()> {
+        let payload = json!({
+            "embeds": [{
+                "title": notification.title,
+                "description": notification.text,
+                "color": EMBED_COLOR,
+            }]
+        });
+        send_request(self.http.post(&self.webhook_url).json(&payload)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httptest::{matchers::*, responders::status_code, Expectation, Server};
+
+    #[tokio::test]
+    async fn posts_embed_payload() {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/discord"),
+                request::body(json_decoded(eq(json!({
+                    "embeds": [{ "title": "제목", "description": "본문", "color": EMBED_COLOR }]
+                })))),
+            ])
+            .respond_with(status_code(204)),
+        );
+
+        let channel = DiscordChannel::new(Client::new(), server.url_str("/discord"));
+        channel.send(&Notification::new("제목", "본문")).await.unwrap();
+    }
+}
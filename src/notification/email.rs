@@ -0,0 +1,132 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use lettre::message::header::ContentType;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use crate::model::notification_channel::{EmailConfig, SmtpSecurity};
+use super::{Notification, NotificationChannel, HTTP_TIMEOUT};
+
+/// SMTP 메일
+pub struct EmailChannel {
+    config: EmailConfig,
+}
+
+impl EmailChannel {
+    pub fn new(config: EmailConfig) -> Self {
+        Self { config }
+    }
+
+    fn transport(&self) -> Result<AsyncSmtpTransport<Tokio1Executor>> {
+        let host = &self.config.smtp_host;
+        let mut builder = match self.config.security {
+            SmtpSecurity::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host),
+            SmtpSecurity::Starttls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)?,
+            SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(host)?,
+        }
+            .port(self.config.smtp_port)
+            .timeout(Some(HTTP_TIMEOUT));
+
+        if let (Some(username), Some(password)) = (&self.config.username, &self.config.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(builder.build())
+    }
+
+    fn message(&self, notification: &Notification) -> Result<Message> {
+        let mut builder = Message::builder()
+            .from(self.config.from.parse().context("보내는 주소가 올바르지 않습니다")?)
+            .subject(&notification.title)
+            .header(ContentType::TEXT_PLAIN);
+
+        for to in &self.config.to {
+            builder = builder.to(to.parse().with_context(|| format!("받는 주소가 올바르지 않습니다: {}", to))?);
+        }
+
+        Ok(builder.body(notification.text.clone())?)
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for EmailChannel {
+    async fn send(&self, notification: &Notification) -> Result<()> {
+        let message = self.message(notification)?;
+        self.transport()?
+            .send(message)
+            .await
+            .context("메일 전송 실패")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    // 명령마다 성공 응답만 돌려주고, DATA 본문을 그대로 넘겨주는 SMTP 서버
+    async fn mock_smtp_server() -> (u16, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let handle = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = socket.into_split();
+            let mut lines = BufReader::new(reader).lines();
+            let mut data = String::new();
+            let mut in_data = false;
+
+            writer.write_all(b"220 localhost ESMTP\r\n").await.unwrap();
+            while let Some(line) = lines.next_line().await.unwrap() {
+                if in_data {
+                    if line == "." {
+                        in_data = false;
+                        writer.write_all(b"250 OK\r\n").await.unwrap();
+                    } else {
+                        data.push_str(&line);
+                        data.push('\n');
+                    }
+                    continue;
+                }
+
+                let reply: &[u8] = match line.to_uppercase().split_whitespace().next() {
+                    Some("DATA") => {
+                        in_data = true;
+                        b"354 End data with <CR><LF>.<CR><LF>\r\n"
+                    }
+                    Some("QUIT") => {
+                        writer.write_all(b"221 Bye\r\n").await.unwrap();
+                        break;
+                    }
+                    _ => b"250 OK\r\n",
+                };
+                writer.write_all(reply).await.unwrap();
+            }
+            data
+        });
+
+        (port, handle)
+    }
+
+    #[tokio::test]
+    async fn sends_mail_over_smtp() {
+        let (port, server) = mock_smtp_server().await;
+        let channel = EmailChannel::new(EmailConfig {
+            smtp_host: "127.0.0.1".to_string(),
+            smtp_port: port,
+            security: SmtpSecurity::None,
+            username: None,
+            password: None,
+            from: "replay@example.com".to_string(),
+            to: vec!["dev@example.com".to_string()],
+        });
+
+        channel.send(&Notification::new("Alert", "Something broke")).await.unwrap();
+
+        let data = server.await.unwrap();
+        assert!(data.contains("To: dev@example.com"));
+        assert!(data.contains("Subject: Alert"));
+        assert!(data.contains("Something broke"));
+    }
+}
@@ -0,0 +1,65 @@
+use std::time::Duration;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder};
+use serde::{Deserialize, Serialize};
+use crate::model::notification_channel::ChannelConfig;
+
+mod discord;
+mod email;
+mod slack;
+mod teams;
+mod webhook;
+
+pub use discord::DiscordChannel;
+pub use email::EmailChannel;
+pub use slack::SlackChannel;
+pub use teams::TeamsChannel;
+pub use webhook::WebhookChannel;
+
+const HTTP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 채널로 보낼 알림
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Notification {
+    pub title: String,
+    pub text: String,
+}
+
+impl Notification {
+    pub fn new(title: impl Into<String>, text: impl Into<String>) -> Self {
+        Self { title: title.into(), text: text.into() }
+    }
+}
+
+/// 알림 발송 경로
+#[async_trait]
+pub trait NotificationChannel: Send + Sync {
+    async fn send(&self, notification: &Notification) -> Result<()>;
+}
+
+/// 저장된 채널 설정으로 발송 구현체를 만든다.
+pub fn channel_for(config: ChannelConfig, http: Client) -> Box<dyn NotificationChannel> {
+    match config {
+        ChannelConfig::Slack { webhook_url } => Box::new(SlackChannel::new(http, webhook_url)),
+        ChannelConfig::Webhook { url, secret } => Box::new(WebhookChannel::new(http, url, secret)),
+        ChannelConfig::Discord { webhook_url } => Box::new(DiscordChannel::new(http, webhook_url)),
+        ChannelConfig::Teams { webhook_url } => Box::new(TeamsChannel::new(http, webhook_url)),
+        ChannelConfig::Email(config) => Box::new(EmailChannel::new(config)),
+    }
+}
+
+async fn send_request(request: RequestBuilder) -> Result<()> {
+    let res = request
+        .timeout(HTTP_TIMEOUT)
+        .send()
+        .await
+        .context("알림 요청 실패")?;
+
+    if res.status().is_success() {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!("알림 전송 실패, 상태 코드: {}", res.status()))
+    }
+}
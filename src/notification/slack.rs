@@ -0,0 +1,55 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+use super::{send_request, Notification, NotificationChannel};
+
+/// Slack incoming webhook
+pub struct SlackChannel {
+    http: Client,
+    webhook_url: String,
+}
+
+impl SlackChannel {
+    pub fn new(http: Client, webhook_url: String) -> Self {
+        Self { http, webhook_url }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for SlackChannel {
+    async fn send(&self, notification: &Notification) -> Result<()> {
+        let payload = json!({ "text": format!("*{}*\n{}", notification.title, notification.text) });
+        send_request(self.http.post(&self.webhook_url).json(&payload)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httptest::{matchers::*, responders::status_code, Expectation, Server};
+
+    #[tokio::test]
+    async fn posts_text_payload() {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/slack"),
+                request::body(json_decoded(eq(json!({ "text": "*제목*\n본문" })))),
+            ])
+            .respond_with(status_code(200)),
+        );
+
+        let channel = SlackChannel::new(Client::new(), server.url_str("/slack"));
+        channel.send(&Notification::new("제목", "본문")).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn fails_on_error_status() {
+        let server = Server::run();
+        server.expect(Expectation::matching(request::method("POST")).respond_with(status_code(404)));
+
+        let channel = SlackChannel::new(Client::new(), server.url_str("/slack"));
+        assert!(channel.send(&Notification::new("제목", "본문")).await.is_err());
+    }
+}
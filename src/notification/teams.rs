@@ -0,0 +1,58 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::json;
+use super::{send_request, Notification, NotificationChannel};
+
+/// Microsoft Teams incoming webhook (MessageCard)
+pub struct TeamsChannel {
+    http: Client,
+    webhook_url: String,
+}
+
+impl TeamsChannel {
+    pub fn new(http: Client, webhook_url: String) -> Self {
+        Self { http, webhook_url }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for TeamsChannel {
+    async fn send(&self, notification: &Notification) -> Result<()> {
+        let payload = json!({
+            "@type": "MessageCard",
+            "@context": "https://schema.org/extensions",
+            "summary": notification.title,
+            "title": notification.title,
+            "text": notification.text,
+        });
+        send_request(self.http.post(&self.webhook_url).json(&payload)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httptest::{matchers::*, responders::status_code, Expectation, Server};
+
+    #[tokio::test]
+    async fn posts_message_card() {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/teams"),
+                request::body(json_decoded(eq(json!({
+                    "@type": "MessageCard",
+                    "@context": "https://schema.org/extensions",
+                    "summary": "제목",
+                    "title": "제목",
+                    "text": "본문",
+                })))),
+            ])
+            .respond_with(status_code(200)),
+        );
+
+        let channel = TeamsChannel::new(Client::new(), server.url_str("/teams"));
+        channel.send(&Notification::new("제목", "본문")).await.unwrap();
+    }
+}
@@ -0,0 +1,100 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::Client;
+use serde_json::json;
+use sha2::Sha256;
+use super::{send_request, Notification, NotificationChannel};
+
+pub const SIGNATURE_HEADER: &str = "X-Replay-Signature";
+
+/// 일반 HTTP webhook. 수신 측은 `X-Replay-Signature: sha256=<hex>` 로 본문을 검증할 수 있다.
+pub struct WebhookChannel {
+    http: Client,
+    url: String,
+    secret: Option<String>,
+}
+
+impl WebhookChannel {
+    pub fn new(http: Client, url: String, secret: Option<String>) -> Self {
+        Self { http, url, secret }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for WebhookChannel {
+    async fn send(&self, notification: &Notification) -> Result<()> {
+        let body = serde_json::to_vec(&json!({
+            "title": notification.title,
+            "text": notification.text,
+            "timestamp": Utc::now(),
+        }))?;
+
+        let mut request = self
+            .http
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+        if let Some(secret) = &self.secret {
+            request = request.header(SIGNATURE_HEADER, sign(secret, &body));
+        }
+
+        send_request(request.body(body)).await
+    }
+}
+
+fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC은 모든 키 길이를 허용한다");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use httptest::{matchers::*, responders::status_code, Expectation, Server};
+
+    #[test]
+    fn signs_body_with_hmac_sha256() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            sign("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+        );
+    }
+
+    #[tokio::test]
+    async fn signature_matches_delivered_body() {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/hook"),
+                request::body(json_decoded(|body: &serde_json::Value| body["title"] == "제목")),
+                |req: &httptest::http::Request<httptest::bytes::Bytes>| {
+                    req.headers()
+                        .get(SIGNATURE_HEADER)
+                        .is_some_and(|signature| *signature == sign("secret", req.body()).as_str())
+                },
+            ])
+            .respond_with(status_code(200)),
+        );
+
+        let channel = WebhookChannel::new(Client::new(), server.url_str("/hook"), Some("secret".to_string()));
+        channel.send(&Notification::new("제목", "본문")).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn omits_signature_without_secret() {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/hook"),
+                not(request::headers(contains(key("x-replay-signature")))),
+            ])
+            .respond_with(status_code(200)),
+        );
+
+        let channel = WebhookChannel::new(Client::new(), server.url_str("/hook"), None);
+        channel.send(&Notification::new("제목", "본문")).await.unwrap();
+    }
+}
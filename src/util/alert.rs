@@ -0,0 +1,140 @@
+use regex::Regex;
+use crate::entity::alert_rule::{AlertCondition, Model as AlertRuleModel};
+use crate::entity::event::Model as EventModel;
+use crate::entity::issue::Model as IssueModel;
+use crate::model::alert_rule::AlertFilters;
+use crate::notification::Notification;
+
+/// 이벤트가 규칙 필터를 모두 만족하는지 확인한다. 비어 있는 필터는 항상 통과한다.
+pub fn filters_match(filters: &AlertFilters, event: &EventModel) -> bool {
+    if filters.environment.as_ref().is_some_and(|environment| *environment != event.environment) {
+        return false;
+    }
+
+    if filters.release.as_ref().is_some_and(|release| *release != event.app_version) {
+        return false;
+    }
+
+    if let Some(browser) = &filters.browser {
+        let matched = event
+            .browser
+            .as_ref()
+            .is_some_and(|b| b.to_lowercase().contains(&browser.to_lowercase()));
+        if !matched {
+            return false;
+        }
+    }
+
+    if let Some(pattern) = &filters.message_pattern {
+        // 패턴은 저장할 때 검증하므로 여기서 컴파일에 실패하면 규칙을 적용하지 않는다.
+        let matched = Regex::new(pattern).is_ok_and(|re| re.is_match(&event.message));
+        if !matched {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// 규칙이 발동했을 때 보낼 알림
+pub fn alert_notification(rule: &AlertRuleModel, issue: &IssueModel, event: &EventModel) -> Notification {
+    let reason = match rule.condition {
+        AlertCondition::NewIssue => "새로운 이슈가 발생했습니다.".to_string(),
+        AlertCondition::Regression => format!("해결된 이슈가 {} 버전에서 다시 발생했습니다.", event.app_version),
+        AlertCondition::EventFrequency => format!(
+            "{}분 동안 이벤트가 {}개를 넘었습니다.",
+            rule.interval_minutes.unwrap_or_default(),
+            rule.threshold.unwrap_or_default(),
+        ),
+        AlertCondition::UniqueUsers => format!(
+            "영향받은 사용자가 {}명을 넘었습니다.",
+            rule.threshold.unwrap_or_default(),
+        ),
+    };
+
+    Notification::new(
+        format!("🚨 [{}] {}", rule.name, reason),
+        format!(
+            "이슈 #{}: {}\n프로젝트: {} / 환경: {} / 버전: {}",
+            issue.id, issue.title, event.project_id, event.environment, event.app_version,
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use crate::entity::event::EventStatus;
+
+    fn event() -> EventModel {
+        EventModel {
+            id: 1,
+            message: "TypeError: Cannot read properties of undefined (reading 'id')".to_string(),
+            stacktrace: String::new(),
+            app_version: "1.2.0".to_string(),
+            timestamp: Utc::now(),
+            group_hash: String::new(),
+            replay: None,
+            environment: "production".to_string(),
+            browser: Some("Chrome 120".to_string()),
+            os: None,
+            ip_address: None,
+            user_agent: None,
+            browser_name: None,
+            browser_version: None,
+            os_name: None,
+            os_version: None,
+            device_type: None,
+            country_code: None,
+            country_name: None,
+            region: None,
+            city: None,
+            project_id: 1,
+            issue_id: Some(1),
+            reported_by: None,
+            additional_info: None,
+            breadcrumbs: None,
+            tags: None,
+            user_context: None,
+            request_context: None,
+            contexts: None,
+            frames: None,
+            symbolicated_stacktrace: None,
+            symbolicated_frames: None,
+            scrubbed_fields: None,
+            priority: None,
+            assigned_to: None,
+            status: EventStatus::UNRESOLVED,
+            created_at: Utc::now(),
+            updated_at: None,
+            deleted_at: None,
+            deleted_by: None,
+        }
+    }
+
+    #[test]
+    fn empty_filters_match_every_event() {
+        assert!(filters_match(&AlertFilters::default(), &event()));
+    }
+
+    #[test]
+    fn all_filters_must_match() {
+        let filters = AlertFilters {
+            environment: Some("production".to_string()),
+            release: Some("1.2.0".to_string()),
+            browser: Some("chrome".to_string()),
+            message_pattern: Some(r"^TypeError:".to_string()),
+        };
+        assert!(filters_match(&filters, &event()));
+
+        let staging = AlertFilters { environment: Some("staging".to_string()), ..filters.clone() };
+        assert!(!filters_match(&staging, &event()));
+
+        let firefox = AlertFilters { browser: Some("firefox".to_string()), ..filters.clone() };
+        assert!(!filters_match(&firefox, &event()));
+
+        let reference_error = AlertFilters { message_pattern: Some("^ReferenceError".to_string()), ..filters };
+        assert!(!filters_match(&reference_error, &event()));
+    }
+}
@@ -0,0 +1,147 @@
+use std::net::IpAddr;
+use std::str::FromStr;
+
+/// 신뢰하는 프록시 주소 범위 (`10.0.0.0/8`, `::1`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpRange {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl IpRange {
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.addr, ip) {
+            (IpAddr::V4(range), IpAddr::V4(ip)) => {
+                masked(u32::from(range) as u128, u32::from(ip) as u128, 32, self.prefix)
+            }
+            (IpAddr::V6(range), IpAddr::V6(ip)) => {
+                masked(u128::from(range), u128::from(ip), 128, self.prefix)
+            }
+            (IpAddr::V6(range), IpAddr::V4(ip)) => {
+                // IPv4-mapped IPv6 범위(::ffff:10.0.0.0/104)로 적은 경우
+                masked(u128::from(range), u128::from(ip.to_ipv6_mapped()), 128, self.prefix)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn masked(range: u128, ip: u128, bits: u8, prefix: u8) -> bool {
+    if prefix == 0 {
+        return true;
+    }
+    let shift = (bits - prefix) as u32;
+    (range >> shift) == (ip >> shift)
+}
+
+impl FromStr for IpRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (addr, prefix) = match s.split_once('/') {
+            Some((addr, prefix)) => (addr, Some(prefix)),
+            None => (s, None),
+        };
+        let addr: IpAddr = addr.parse().map_err(|_| format!("잘못된 IP 주소: {}", s))?;
+        let bits = if addr.is_ipv4() { 32 } else { 128 };
+        let prefix = match prefix {
+            Some(prefix) => prefix
+                .parse::<u8>()
+                .ok()
+                .filter(|prefix| *prefix <= bits)
+                .ok_or_else(|| format!("잘못된 prefix 길이: {}", s))?,
+            None => bits,
+        };
+        Ok(Self { addr, prefix })
+    }
+}
+
+/// 실제 클라이언트 IP를 찾는다.
+/// 직접 연결한 주소가 신뢰하는 프록시일 때만 `X-Forwarded-For`를 오른쪽부터 거슬러 올라가며,
+/// 신뢰하지 않는 첫 주소를 클라이언트로 본다.
+pub fn resolve_client_ip(peer: IpAddr, forwarded_for: Option<&str>, trusted: &[IpRange]) -> IpAddr {
+    let is_trusted = |ip: IpAddr| trusted.iter().any(|range| range.contains(ip));
+    if !is_trusted(peer) {
+        return peer;
+    }
+
+    let Some(forwarded_for) = forwarded_for else {
+        return peer;
+    };
+
+    let mut client = peer;
+    for hop in forwarded_for.rsplit(',') {
+        let Some(ip) = parse_forwarded_ip(hop) else {
+            // 읽을 수 없는 값 너머는 믿을 수 없으므로 여기서 멈춘다.
+            break;
+        };
+        client = ip;
+        if !is_trusted(ip) {
+            break;
+        }
+    }
+    client
+}
+
+// `1.2.3.4`, `1.2.3.4:5678`, `[2001:db8::1]:443` 형태를 받는다.
+fn parse_forwarded_ip(hop: &str) -> Option<IpAddr> {
+    let hop = hop.trim();
+    if let Ok(ip) = hop.parse() {
+        return Some(ip);
+    }
+    if let Some(rest) = hop.strip_prefix('[') {
+        return rest.split_once(']').and_then(|(ip, _)| ip.parse().ok());
+    }
+    hop.rsplit_once(':').and_then(|(ip, _)| ip.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    fn ranges(values: &[&str]) -> Vec<IpRange> {
+        values.iter().map(|value| value.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn parses_ranges() {
+        let range: IpRange = "10.0.0.0/8".parse().unwrap();
+        assert!(range.contains(ip("10.1.2.3")));
+        assert!(!range.contains(ip("11.0.0.1")));
+
+        let range: IpRange = "2001:db8::/32".parse().unwrap();
+        assert!(range.contains(ip("2001:db8::1")));
+        assert!(!range.contains(ip("10.0.0.1")));
+
+        assert!("10.0.0.1".parse::<IpRange>().unwrap().contains(ip("10.0.0.1")));
+        assert!("10.0.0.0/33".parse::<IpRange>().is_err());
+        assert!("proxy.local".parse::<IpRange>().is_err());
+    }
+
+    #[test]
+    fn ignores_forwarded_for_from_untrusted_peer() {
+        let trusted = ranges(&["10.0.0.0/8"]);
+        assert_eq!(resolve_client_ip(ip("203.0.113.9"), Some("1.1.1.1"), &trusted), ip("203.0.113.9"));
+        assert_eq!(resolve_client_ip(ip("10.0.0.1"), None, &trusted), ip("10.0.0.1"));
+    }
+
+    #[test]
+    fn walks_forwarded_for_through_trusted_proxies() {
+        let trusted = ranges(&["10.0.0.0/8", "192.168.0.1"]);
+
+        // 클라이언트가 앞에 끼워 넣은 주소(6.6.6.6)는 무시된다.
+        let forwarded = "6.6.6.6, 198.51.100.7:51234, 192.168.0.1";
+        assert_eq!(resolve_client_ip(ip("10.0.0.1"), Some(forwarded), &trusted), ip("198.51.100.7"));
+
+        assert_eq!(
+            resolve_client_ip(ip("10.0.0.1"), Some("[2001:db8::7]:443"), &trusted),
+            ip("2001:db8::7")
+        );
+        assert_eq!(resolve_client_ip(ip("10.0.0.1"), Some("unknown, 10.0.0.2"), &trusted), ip("10.0.0.2"));
+    }
+}
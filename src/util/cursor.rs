@@ -0,0 +1,101 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use sea_query::Value;
+
+/// 커서가 가리키는 쪽. `Next`는 정렬 순서상 뒤, `Prev`는 앞 페이지를 가져온다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorDirection {
+    Next,
+    Prev,
+}
+
+/// 정렬 컬럼의 값. 시간 컬럼과 숫자 컬럼(이슈 발생 수)을 구분해 저장한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    Time(DateTime<Utc>),
+    Number(i64),
+}
+
+impl From<SortKey> for Value {
+    fn from(key: SortKey) -> Self {
+        match key {
+            SortKey::Time(time) => time.into(),
+            SortKey::Number(number) => number.into(),
+        }
+    }
+}
+
+/// 키셋 페이지네이션 위치. 마지막으로 본 행의 (정렬 값, ID)를 담아 불투명한 문자열로 주고받는다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor {
+    pub direction: CursorDirection,
+    pub key: SortKey,
+    pub id: i32,
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        let direction = match self.direction {
+            CursorDirection::Next => 'n',
+            CursorDirection::Prev => 'p',
+        };
+        let key = match self.key {
+            SortKey::Time(time) => format!("t{}", time.timestamp_micros()),
+            SortKey::Number(number) => format!("c{}", number),
+        };
+        URL_SAFE_NO_PAD.encode(format!("{}{}:{}", direction, key, self.id))
+    }
+
+    pub fn decode(raw: &str) -> Result<Self, String> {
+        let invalid = || "커서가 올바르지 않습니다.".to_string();
+
+        let bytes = URL_SAFE_NO_PAD.decode(raw.trim()).map_err(|_| invalid())?;
+        let text = String::from_utf8(bytes).map_err(|_| invalid())?;
+        let (head, id) = text.split_once(':').ok_or_else(invalid)?;
+
+        let mut chars = head.chars();
+        let direction = match chars.next() {
+            Some('n') => CursorDirection::Next,
+            Some('p') => CursorDirection::Prev,
+            _ => return Err(invalid()),
+        };
+        let kind = chars.next();
+        let value: i64 = chars.as_str().parse().map_err(|_| invalid())?;
+        let key = match kind {
+            Some('t') => SortKey::Time(DateTime::from_timestamp_micros(value).ok_or_else(invalid)?),
+            Some('c') => SortKey::Number(value),
+            _ => return Err(invalid()),
+        };
+
+        Ok(Self { direction, key, id: id.parse().map_err(|_| invalid())? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_cursors() {
+        let time = DateTime::parse_from_rfc3339("2025-04-20T12:34:56.789012Z").unwrap().with_timezone(&Utc);
+        let cursors = [
+            Cursor { direction: CursorDirection::Next, key: SortKey::Time(time), id: 42 },
+            Cursor { direction: CursorDirection::Prev, key: SortKey::Number(-7), id: 1 },
+        ];
+
+        for cursor in cursors {
+            let encoded = cursor.encode();
+            assert!(encoded.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+            assert_eq!(Cursor::decode(&encoded), Ok(cursor));
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_cursors() {
+        assert!(Cursor::decode("not base64!").is_err());
+        assert!(Cursor::decode(&URL_SAFE_NO_PAD.encode("x1:1")).is_err());
+        assert!(Cursor::decode(&URL_SAFE_NO_PAD.encode("nt1")).is_err());
+        assert!(Cursor::decode(&URL_SAFE_NO_PAD.encode("nzabc:1")).is_err());
+    }
+}
@@ -0,0 +1,54 @@
+use serde_json::Value;
+
+/// CSV 한 줄 (RFC 4180). 쉼표, 따옴표, 줄바꿈이 있는 칸은 따옴표로 감싸고 따옴표는 두 번 쓴다.
+pub fn csv_line<S: AsRef<str>>(cells: impl IntoIterator<Item = S>) -> String {
+    let mut line = cells
+        .into_iter()
+        .map(|cell| {
+            let cell = cell.as_ref();
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push_str("\r\n");
+    line
+}
+
+/// JSON 값을 CSV 칸으로 바꾼다. 빈 값은 빈 칸, 객체와 배열은 JSON 문자열로 쓴다.
+pub fn csv_cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(text)) => text.clone(),
+        Some(other) => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn quotes_cells_that_need_it() {
+        assert_eq!(csv_line(["1", "plain", ""]), "1,plain,\r\n");
+        assert_eq!(
+            csv_line(["a,b", "say \"hi\"", "line\nbreak"]),
+            "\"a,b\",\"say \"\"hi\"\"\",\"line\nbreak\"\r\n"
+        );
+    }
+
+    #[test]
+    fn converts_json_values_to_cells() {
+        let row = json!({ "id": 3, "message": "TypeError", "issueId": null, "tags": { "os": "iOS" } });
+
+        assert_eq!(csv_cell(row.get("id")), "3");
+        assert_eq!(csv_cell(row.get("message")), "TypeError");
+        assert_eq!(csv_cell(row.get("issueId")), "");
+        assert_eq!(csv_cell(row.get("missing")), "");
+        assert_eq!(csv_cell(row.get("tags")), r#"{"os":"iOS"}"#);
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::net::IpAddr;
+use maxminddb::{geoip2, Reader};
+use tracing::{info, warn};
+
+/// IP로 찾은 위치 (GeoLite2 City 기준, 이름은 영어)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GeoLocation {
+    pub country_code: Option<String>,
+    pub country_name: Option<String>,
+    pub region: Option<String>,
+    pub city: Option<String>,
+}
+
+/// 로컬 MaxMind DB 파일로 IP 위치를 찾는다. 네트워크 호출은 하지 않는다.
+pub struct GeoIp {
+    reader: Option<Reader<Vec<u8>>>,
+}
+
+impl GeoIp {
+    /// DB 파일이 없으면 위치를 찾지 않는다.
+    pub fn disabled() -> Self {
+        Self { reader: None }
+    }
+
+    /// `GEOIP_DATABASE_PATH`의 `.mmdb` 파일을 읽는다. 설정이 없거나 읽지 못하면 꺼진 상태로 만든다.
+    pub fn from_env() -> Self {
+        let Ok(path) = env::var("GEOIP_DATABASE_PATH") else {
+            return Self::disabled();
+        };
+
+        match Reader::open_readfile(&path) {
+            Ok(reader) => {
+                info!("GeoIP DB 로드됨: {} ({})", path, reader.metadata.database_type);
+                Self { reader: Some(reader) }
+            }
+            Err(e) => {
+                warn!("GeoIP DB를 열 수 없어 위치 보강을 건너뜁니다 ({}): {}", path, e);
+                Self::disabled()
+            }
+        }
+    }
+
+    pub fn lookup(&self, ip: IpAddr) -> Option<GeoLocation> {
+        let city: geoip2::City = self.reader.as_ref()?.lookup(ip).ok()?;
+
+        let location = GeoLocation {
+            country_code: city.country.as_ref().and_then(|country| country.iso_code).map(str::to_string),
+            country_name: city.country.as_ref().and_then(|country| english_name(&country.names)),
+            region: city
+                .subdivisions
+                .as_ref()
+                .and_then(|subdivisions| subdivisions.first())
+                .and_then(|subdivision| english_name(&subdivision.names)),
+            city: city.city.as_ref().and_then(|city| english_name(&city.names)),
+        };
+
+        (location != GeoLocation::default()).then_some(location)
+    }
+}
+
+fn english_name(names: &Option<BTreeMap<&str, &str>>) -> Option<String> {
+    names.as_ref()?.get("en").map(|name| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_lookup_returns_nothing() {
+        assert_eq!(GeoIp::disabled().lookup("8.8.8.8".parse().unwrap()), None);
+    }
+}
@@ -1 +1,15 @@
-pub mod slack;
\ No newline at end of file
+pub mod alert;
+pub mod client_ip;
+pub mod cursor;
+pub mod export;
+pub mod geoip;
+pub mod otlp;
+pub mod rate_limit;
+pub mod scrub;
+pub mod search;
+pub mod sourcemap;
+pub mod spike;
+pub mod stats;
+pub mod stacktrace;
+pub mod user_agent;
+pub mod version;
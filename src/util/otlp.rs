@@ -0,0 +1,138 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use opentelemetry_proto::tonic::common::v1::any_value::Value as AnyValueKind;
+use opentelemetry_proto::tonic::common::v1::{AnyValue, KeyValue};
+use serde_json::{Map, Number, Value};
+
+/// OTLP `AnyValue`를 타입을 살린 JSON으로 바꾼다.
+/// 배열과 kvlist는 중첩 JSON으로, bytes는 base64 문자열로 남긴다.
+pub fn any_value_to_json(value: &AnyValue) -> Value {
+    match &value.value {
+        Some(AnyValueKind::StringValue(s)) => Value::String(s.clone()),
+        Some(AnyValueKind::BoolValue(b)) => Value::Bool(*b),
+        Some(AnyValueKind::IntValue(i)) => Value::from(*i),
+        // NaN, 무한대는 JSON 숫자로 쓸 수 없으므로 문자열로 남긴다.
+        Some(AnyValueKind::DoubleValue(d)) => Number::from_f64(*d).map_or_else(|| Value::String(d.to_string()), Value::Number),
+        Some(AnyValueKind::ArrayValue(array)) => Value::Array(array.values.iter().map(any_value_to_json).collect()),
+        Some(AnyValueKind::KvlistValue(list)) => Value::Object(attributes_to_json(&list.values)),
+        Some(AnyValueKind::BytesValue(bytes)) => Value::String(STANDARD.encode(bytes)),
+        None => Value::Null,
+    }
+}
+
+/// attribute 목록을 키 → 값 JSON 객체로 바꾼다.
+pub fn attributes_to_json(attributes: &[KeyValue]) -> Map<String, Value> {
+    attributes
+        .iter()
+        .map(|kv| (kv.key.clone(), kv.value.as_ref().map_or(Value::Null, any_value_to_json)))
+        .collect()
+}
+
+/// `http.status_code`처럼 문자열 컬럼에 옮길 값. 배열, 객체는 옮기지 않는다.
+pub fn json_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// `exception` span event에 담긴 예외 (OpenTelemetry 시맨틱 규약의 `exception.*` attribute)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanException {
+    pub exception_type: Option<String>,
+    pub message: Option<String>,
+    pub stacktrace: Option<String>,
+}
+
+impl SpanException {
+    /// 이슈 제목으로 쓸 `TypeError: x is undefined` 형태의 메시지
+    pub fn title(&self) -> String {
+        match (&self.exception_type, &self.message) {
+            (Some(exception_type), Some(message)) => format!("{}: {}", exception_type, message),
+            (Some(text), None) | (None, Some(text)) => text.clone(),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+/// span event가 예외 기록이면 그 내용을 꺼낸다. 종류와 메시지가 모두 없으면 예외로 보지 않는다.
+pub fn span_exception(name: &str, attributes: &Map<String, Value>) -> Option<SpanException> {
+    if name != "exception" {
+        return None;
+    }
+
+    let get = |key: &str| attributes.get(key).and_then(json_to_string).filter(|value| !value.is_empty());
+    let exception = SpanException {
+        exception_type: get("exception.type"),
+        message: get("exception.message"),
+        stacktrace: get("exception.stacktrace"),
+    };
+
+    (exception.exception_type.is_some() || exception.message.is_some()).then_some(exception)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry_proto::tonic::common::v1::{ArrayValue, KeyValueList};
+    use serde_json::json;
+
+    fn any(value: AnyValueKind) -> AnyValue {
+        AnyValue { value: Some(value) }
+    }
+
+    fn kv(key: &str, value: AnyValueKind) -> KeyValue {
+        KeyValue { key: key.to_string(), value: Some(any(value)) }
+    }
+
+    #[test]
+    fn keeps_nested_values_typed() {
+        let attributes = vec![
+            kv("http.status_code", AnyValueKind::IntValue(503)),
+            kv("retry", AnyValueKind::BoolValue(true)),
+            kv("ratio", AnyValueKind::DoubleValue(0.5)),
+            kv("tags", AnyValueKind::ArrayValue(ArrayValue {
+                values: vec![any(AnyValueKind::StringValue("a".to_string())), any(AnyValueKind::IntValue(1))],
+            })),
+            kv("db", AnyValueKind::KvlistValue(KeyValueList {
+                values: vec![kv("system", AnyValueKind::StringValue("mysql".to_string()))],
+            })),
+            kv("payload", AnyValueKind::BytesValue(vec![1, 2, 3])),
+            KeyValue { key: "empty".to_string(), value: None },
+        ];
+
+        assert_eq!(
+            Value::Object(attributes_to_json(&attributes)),
+            json!({
+                "http.status_code": 503,
+                "retry": true,
+                "ratio": 0.5,
+                "tags": ["a", 1],
+                "db": { "system": "mysql" },
+                "payload": "AQID",
+                "empty": null,
+            })
+        );
+        assert_eq!(json_to_string(&json!(503)).as_deref(), Some("503"));
+        assert_eq!(json_to_string(&json!(["a"])), None);
+    }
+
+    #[test]
+    fn finds_exception_events() {
+        let attributes = json!({
+            "exception.type": "TypeError",
+            "exception.message": "x is undefined",
+            "exception.stacktrace": "at main (app.js:1:1)",
+        });
+        let attributes = attributes.as_object().unwrap();
+
+        let exception = span_exception("exception", attributes).unwrap();
+        assert_eq!(exception.title(), "TypeError: x is undefined");
+        assert_eq!(exception.stacktrace.as_deref(), Some("at main (app.js:1:1)"));
+
+        assert_eq!(span_exception("retry", attributes), None);
+        assert_eq!(span_exception("exception", &Map::new()), None);
+    }
+}
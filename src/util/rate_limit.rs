@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// 이 간격마다 다시 가득 찬 버킷을 지운다. 가득 찬 버킷은 새로 만든 버킷과 같으므로 지워도 결과가 같다.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 초당 `rate`개씩 채워지고 최대 `burst`개까지 쌓이는 토큰 버킷
+#[derive(Debug, Clone)]
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+    full_at: Option<Instant>, // 다시 가득 차는 시각. 채워지지 않는 버킷(rate 0)은 `None`
+}
+
+impl TokenBucket {
+    fn new(burst: u32, now: Instant) -> Self {
+        Self { tokens: burst as f64, updated_at: now, full_at: Some(now) }
+    }
+
+    fn is_full(&self, now: Instant) -> bool {
+        self.full_at.is_some_and(|full_at| full_at <= now)
+    }
+
+    fn refill(&mut self, rate: u32, burst: u32, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * rate as f64).min(burst as f64);
+        self.updated_at = now;
+        self.settle(rate, burst, now);
+    }
+
+    // 토큰이 모자라면 다시 시도할 수 있을 때까지 남은 시간
+    fn shortfall(&self, cost: u32, rate: u32, burst: u32) -> Option<Duration> {
+        // burst 보다 큰 요청은 영원히 통과하지 못하므로 버킷 크기만큼만 요구한다.
+        let cost = cost.min(burst) as f64;
+        if self.tokens >= cost {
+            None
+        } else if rate == 0 {
+            Some(Duration::MAX)
+        } else {
+            Some(Duration::from_secs_f64((cost - self.tokens) / rate as f64))
+        }
+    }
+
+    fn spend(&mut self, cost: u32, rate: u32, burst: u32, now: Instant) {
+        self.tokens -= cost.min(burst) as f64;
+        self.settle(rate, burst, now);
+    }
+
+    fn settle(&mut self, rate: u32, burst: u32, now: Instant) {
+        let missing = burst as f64 - self.tokens;
+        self.full_at = if missing <= 0.0 {
+            Some(now)
+        } else if rate == 0 {
+            None
+        } else {
+            Some(now + Duration::from_secs_f64(missing / rate as f64))
+        };
+    }
+}
+
+/// 요청 하나에 적용할 버킷과 그 제한
+#[derive(Debug, Clone, Copy)]
+pub struct Limit<'a> {
+    pub key: &'a str,
+    pub rate: u32,
+    pub burst: u32,
+}
+
+#[derive(Debug)]
+struct Buckets {
+    buckets: HashMap<String, TokenBucket>,
+    swept_at: Instant,
+}
+
+/// 키별 토큰 버킷. 인스턴스마다 메모리에 따로 유지된다.
+/// 다시 가득 찬 버킷은 주기적으로 지우므로 한동안 쓰이지 않은 키는 메모리에 남지 않는다.
+#[derive(Debug)]
+pub struct RateLimiter {
+    buckets: Mutex<Buckets>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self {
+            buckets: Mutex::new(Buckets { buckets: HashMap::new(), swept_at: Instant::now() }),
+        }
+    }
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `cost`만큼 토큰을 쓴다. 부족하면 다시 시도할 수 있을 때까지 남은 시간을 돌려준다.
+    pub fn check(&self, key: &str, cost: u32, rate: u32, burst: u32) -> Result<(), Duration> {
+        self.check_all(&[Limit { key, rate, burst }], cost)
+    }
+
+    /// 모든 버킷에 토큰이 충분할 때만 한꺼번에 `cost`만큼 쓴다.
+    /// 하나라도 부족하면 어느 버킷에서도 쓰지 않고, 가장 오래 기다려야 하는 시간을 돌려준다.
+    pub fn check_all(&self, limits: &[Limit], cost: u32) -> Result<(), Duration> {
+        self.check_all_at(limits, cost, Instant::now())
+    }
+
+    fn check_at(&self, key: &str, cost: u32, rate: u32, burst: u32, now: Instant) -> Result<(), Duration> {
+        self.check_all_at(&[Limit { key, rate, burst }], cost, now)
+    }
+
+    fn check_all_at(&self, limits: &[Limit], cost: u32, now: Instant) -> Result<(), Duration> {
+        let mut state = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        if now.saturating_duration_since(state.swept_at) >= SWEEP_INTERVAL {
+            state.buckets.retain(|_, bucket| !bucket.is_full(now));
+            state.swept_at = now;
+        }
+
+        let mut retry_after = None;
+        for limit in limits {
+            let bucket = state
+                .buckets
+                .entry(limit.key.to_string())
+                .or_insert_with(|| TokenBucket::new(limit.burst, now));
+            bucket.refill(limit.rate, limit.burst, now);
+            retry_after = retry_after.max(bucket.shortfall(cost, limit.rate, limit.burst));
+        }
+        if let Some(retry_after) = retry_after {
+            return Err(retry_after);
+        }
+
+        for limit in limits {
+            if let Some(bucket) = state.buckets.get_mut(limit.key) {
+                bucket.spend(cost, limit.rate, limit.burst, now);
+            }
+        }
+        Ok(())
+    }
+
+    /// 지금 메모리에 있는 버킷 수
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.buckets.lock().unwrap_or_else(|e| e.into_inner()).buckets.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows_burst_then_limits() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+
+        for _ in 0..5 {
+            assert!(limiter.check_at("project:1", 1, 1, 5, now).is_ok());
+        }
+        let retry_after = limiter.check_at("project:1", 1, 1, 5, now).unwrap_err();
+        assert_eq!(retry_after, Duration::from_secs(1));
+
+        // 다른 키는 별도의 버킷을 쓴다.
+        assert!(limiter.check_at("project:2", 1, 1, 5, now).is_ok());
+    }
+
+    #[test]
+    fn refills_over_time() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+
+        assert!(limiter.check_at("key", 10, 10, 10, now).is_ok());
+        assert!(limiter.check_at("key", 5, 10, 10, now).is_err());
+        assert!(limiter.check_at("key", 5, 10, 10, now + Duration::from_millis(500)).is_ok());
+    }
+
+    #[test]
+    fn clamps_cost_to_burst() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+
+        assert!(limiter.check_at("key", 100, 10, 20, now).is_ok());
+        assert_eq!(limiter.check_at("key", 100, 10, 20, now).unwrap_err(), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn evicts_refilled_buckets() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+
+        // `idle`은 1초 뒤에 다시 가득 차지만 `busy`는 1000초가 걸린다.
+        assert!(limiter.check_at("idle", 10, 10, 10, now).is_ok());
+        assert!(limiter.check_at("busy", 1000, 1, 1000, now).is_ok());
+        assert_eq!(limiter.len(), 2);
+
+        assert!(limiter.check_at("other", 1, 10, 10, now + SWEEP_INTERVAL).is_ok());
+        assert_eq!(limiter.len(), 2);
+        assert!(limiter.check_at("busy", 1000, 1, 1000, now + SWEEP_INTERVAL).is_err());
+    }
+
+    #[test]
+    fn keeps_buckets_that_are_still_refilling() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+
+        assert!(limiter.check_at("slow", 10, 0, 10, now).is_ok());
+        assert!(limiter.check_at("other", 1, 10, 10, now + SWEEP_INTERVAL).is_ok());
+        assert!(limiter.check_at("slow", 1, 0, 10, now + SWEEP_INTERVAL).is_err());
+    }
+
+    #[test]
+    fn charges_no_bucket_when_any_is_empty() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+        let limits = [Limit { key: "key", rate: 1, burst: 10 }, Limit { key: "project", rate: 1, burst: 2 }];
+
+        assert!(limiter.check_all_at(&limits, 2, now).is_ok());
+        assert_eq!(limiter.check_all_at(&limits, 2, now).unwrap_err(), Duration::from_secs(2));
+
+        // 프로젝트 버킷에서 거절된 요청은 키 버킷의 토큰을 쓰지 않는다.
+        assert!(limiter.check_at("key", 8, 1, 10, now).is_ok());
+        assert!(limiter.check_at("key", 1, 1, 10, now).is_err());
+    }
+}
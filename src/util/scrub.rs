@@ -0,0 +1,259 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::LazyLock;
+use regex::{Captures, Regex};
+use serde_json::Value;
+use crate::model::scrubbing::ScrubbingRules;
+
+/// 가린 값 대신 저장하는 문자열
+pub const FILTERED: &str = "[Filtered]";
+
+static CREDIT_CARD: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b[3-6](?:[ -]?\d){12,18}\b").unwrap()
+});
+static EMAIL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}").unwrap()
+});
+static BEARER_TOKEN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(bearer\s+)[A-Za-z0-9\-._~+/]+=*").unwrap()
+});
+// 쿼리스트링이나 로그 문자열 안의 `password=...`, `token: ...`
+static SECRET_ASSIGNMENT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)\b((?:password|passwd|pwd|secret|token|api[_-]?key|access[_-]?key)\s*[=:]\s*)[^\s&,;"']+"#).unwrap()
+});
+
+// 이름에 이 문자열이 들어간 키의 값은 통째로 가린다. `-`, `_`를 뺀 소문자로 비교한다.
+const DEFAULT_KEYS: [&str; 13] = [
+    "password", "passwd", "pwd", "secret", "token", "apikey", "accesskey", "privatekey",
+    "authorization", "cookie", "creditcard", "cardnumber", "cvv",
+];
+
+struct Pattern {
+    regex: Regex,
+    replacement: String,
+    luhn: bool,
+}
+
+impl Pattern {
+    fn new(regex: Regex, replacement: &str) -> Self {
+        Self { regex, replacement: replacement.to_string(), luhn: false }
+    }
+
+    fn replace(&self, value: &str) -> String {
+        self.regex
+            .replace_all(value, |caps: &Captures| {
+                if self.luhn && !luhn_valid(&caps[0]) {
+                    return caps[0].to_string();
+                }
+                let mut replaced = String::new();
+                caps.expand(&self.replacement, &mut replaced);
+                replaced
+            })
+            .into_owned()
+    }
+}
+
+/// 프로젝트 규칙으로 만든 스크러버. 가린 필드 경로는 호출하는 쪽이 넘긴 집합에 모은다.
+pub struct Scrubber {
+    patterns: Vec<Pattern>,
+    keys: Vec<String>,
+}
+
+impl Scrubber {
+    pub fn new(rules: &ScrubbingRules) -> Result<Self, regex::Error> {
+        let mut patterns = Vec::new();
+        let mut keys = Vec::new();
+
+        if rules.use_defaults {
+            patterns.push(Pattern { luhn: true, ..Pattern::new(CREDIT_CARD.clone(), FILTERED) });
+            patterns.push(Pattern::new(EMAIL.clone(), FILTERED));
+            patterns.push(Pattern::new(BEARER_TOKEN.clone(), &format!("${{1}}{}", FILTERED)));
+            patterns.push(Pattern::new(SECRET_ASSIGNMENT.clone(), &format!("${{1}}{}", FILTERED)));
+            keys.extend(DEFAULT_KEYS.iter().map(|key| key.to_string()));
+        }
+
+        for pattern in &rules.patterns {
+            // 사용자 정규식의 `$`가 치환 문법으로 해석되지 않도록 매치 전체를 가린다.
+            patterns.push(Pattern::new(Regex::new(pattern)?, FILTERED));
+        }
+        keys.extend(rules.keys.iter().map(|key| normalize_key(key)).filter(|key| !key.is_empty()));
+
+        Ok(Self { patterns, keys })
+    }
+
+    pub fn is_sensitive_key(&self, key: &str) -> bool {
+        let key = normalize_key(key);
+        self.keys.iter().any(|sensitive| key.contains(sensitive.as_str()))
+    }
+
+    /// 문자열 값에서 규칙에 걸린 부분을 가린다.
+    pub fn scrub_str(&self, path: &str, value: &mut String, redacted: &mut BTreeSet<String>) {
+        let scrubbed = self
+            .patterns
+            .iter()
+            .fold(value.clone(), |acc, pattern| pattern.replace(&acc));
+
+        if scrubbed != *value {
+            *value = scrubbed;
+            redacted.insert(path.to_string());
+        }
+    }
+
+    /// 헤더나 쿼리처럼 문자열 값만 있는 맵. 민감한 키의 값은 통째로, 나머지는 패턴으로 가린다.
+    pub fn scrub_map(&self, path: &str, map: &mut BTreeMap<String, String>, redacted: &mut BTreeSet<String>) {
+        for (key, value) in map.iter_mut() {
+            let path = format!("{}.{}", path, key);
+            if self.is_sensitive_key(key) {
+                *value = FILTERED.to_string();
+                redacted.insert(path);
+            } else {
+                self.scrub_str(&path, value, redacted);
+            }
+        }
+    }
+
+    /// JSON 값을 재귀로 돌며 민감한 키의 값은 통째로, 문자열은 패턴으로 가린다.
+    /// 배열 인덱스는 경로에서 `[]`로 묶는다.
+    pub fn scrub_json(&self, path: &str, value: &mut Value, redacted: &mut BTreeSet<String>) {
+        match value {
+            Value::String(s) => self.scrub_str(path, s, redacted),
+            Value::Array(items) => {
+                let path = format!("{}[]", path);
+                for item in items {
+                    self.scrub_json(&path, item, redacted);
+                }
+            }
+            Value::Object(map) => {
+                for (key, item) in map.iter_mut() {
+                    let path = format!("{}.{}", path, key);
+                    if self.is_sensitive_key(key) {
+                        if !item.is_null() {
+                            *item = Value::String(FILTERED.to_string());
+                            redacted.insert(path);
+                        }
+                    } else {
+                        self.scrub_json(&path, item, redacted);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn luhn_valid(candidate: &str) -> bool {
+    let digits: Vec<u32> = candidate.chars().filter_map(|c| c.to_digit(10)).collect();
+    if !(13..=19).contains(&digits.len()) {
+        return false;
+    }
+
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| if i % 2 == 1 { if d * 2 > 9 { d * 2 - 9 } else { d * 2 } } else { d })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn scrub(scrubber: &Scrubber, value: &str) -> (String, BTreeSet<String>) {
+        let mut value = value.to_string();
+        let mut redacted = BTreeSet::new();
+        scrubber.scrub_str("message", &mut value, &mut redacted);
+        (value, redacted)
+    }
+
+    #[test]
+    fn default_rules_redact_common_secrets() {
+        let scrubber = Scrubber::new(&ScrubbingRules::default()).unwrap();
+
+        let (value, redacted) = scrub(&scrubber, "payment failed for 4111 1111 1111 1111 (jane.doe@example.com)");
+        assert_eq!(value, "payment failed for [Filtered] ([Filtered])");
+        assert!(redacted.contains("message"));
+
+        let (value, _) = scrub(&scrubber, "GET /login?user=jane&password=hunter2 Authorization: Bearer abc.def-ghi");
+        assert_eq!(value, "GET /login?user=jane&password=[Filtered] Authorization: Bearer [Filtered]");
+
+        // Luhn 검사를 통과하지 못한 숫자(타임스탬프 등)는 그대로 둔다.
+        let (value, redacted) = scrub(&scrubber, "timeout after 5000000000000 ns");
+        assert_eq!(value, "timeout after 5000000000000 ns");
+        assert!(redacted.is_empty());
+    }
+
+    #[test]
+    fn scrubs_json_by_key_and_value() {
+        let scrubber = Scrubber::new(&ScrubbingRules::default()).unwrap();
+        let mut value = json!({
+            "user": { "email": "jane@example.com", "Api-Key": "k_123", "id": 7 },
+            "headers": [{ "name": "cookie", "set_cookie": "sid=1" }],
+            "access_token": null,
+        });
+        let mut redacted = BTreeSet::new();
+
+        scrubber.scrub_json("additional_info", &mut value, &mut redacted);
+
+        assert_eq!(value["user"]["email"], "[Filtered]");
+        assert_eq!(value["user"]["Api-Key"], "[Filtered]");
+        assert_eq!(value["user"]["id"], 7);
+        assert_eq!(value["headers"][0]["name"], "cookie");
+        assert_eq!(value["headers"][0]["set_cookie"], "[Filtered]");
+        assert!(value["access_token"].is_null());
+        assert_eq!(
+            redacted.into_iter().collect::<Vec<_>>(),
+            vec![
+                "additional_info.headers[].set_cookie",
+                "additional_info.user.Api-Key",
+                "additional_info.user.email",
+            ]
+        );
+    }
+
+    #[test]
+    fn scrubs_string_maps_by_key_and_value() {
+        let scrubber = Scrubber::new(&ScrubbingRules::default()).unwrap();
+        let mut headers = BTreeMap::from([
+            ("Cookie".to_string(), "sid=1".to_string()),
+            ("Referer".to_string(), "https://app.example.com/?token=abc".to_string()),
+            ("Accept".to_string(), "text/html".to_string()),
+        ]);
+        let mut redacted = BTreeSet::new();
+
+        scrubber.scrub_map("request.headers", &mut headers, &mut redacted);
+
+        assert_eq!(headers["Cookie"], "[Filtered]");
+        assert_eq!(headers["Referer"], "https://app.example.com/?token=[Filtered]");
+        assert_eq!(headers["Accept"], "text/html");
+        assert_eq!(
+            redacted.into_iter().collect::<Vec<_>>(),
+            vec!["request.headers.Cookie", "request.headers.Referer"]
+        );
+    }
+
+    #[test]
+    fn custom_rules_without_defaults() {
+        let rules = ScrubbingRules {
+            use_defaults: false,
+            patterns: vec![r"ORD-\d+".to_string()],
+            keys: vec!["Phone_Number".to_string()],
+        };
+        let scrubber = Scrubber::new(&rules).unwrap();
+
+        let (value, _) = scrub(&scrubber, "order ORD-1234 by jane@example.com");
+        assert_eq!(value, "order [Filtered] by jane@example.com");
+        assert!(scrubber.is_sensitive_key("phoneNumber"));
+        assert!(!scrubber.is_sensitive_key("password"));
+
+        assert!(Scrubber::new(&ScrubbingRules { patterns: vec!["(".to_string()], ..rules }).is_err());
+    }
+}
@@ -0,0 +1,217 @@
+use chrono::{DateTime, TimeDelta, Utc};
+
+/// 검색어 한 조각의 비교 연산자. `release:>=1.4`처럼 값 앞에 붙인다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchOp {
+    Eq,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+/// `key:value` 조건. 앞에 `!`를 붙이면 `negated`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchFilter {
+    pub negated: bool,
+    pub key: String,
+    pub op: SearchOp,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchTerm {
+    Filter(SearchFilter),
+    /// 키 없이 쓴 단어나 따옴표로 묶은 문장
+    Text { negated: bool, value: String },
+}
+
+/// `is:unresolved environment:production release:>=1.4 message:"TypeError" !assigned:me age:-24h`
+/// 같은 검색어를 조건 목록으로 나눈다. 모든 조건은 AND로 묶인다.
+pub fn parse_search(input: &str) -> Result<Vec<SearchTerm>, String> {
+    let mut terms = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let negated = chars.next_if_eq(&'!').is_some();
+
+        // 따옴표가 나오기 전의 `:`까지가 키다.
+        let mut head = String::new();
+        let mut key = None;
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() || c == '"' {
+                break;
+            }
+            chars.next();
+            if c == ':' {
+                key = Some(std::mem::take(&mut head));
+                break;
+            }
+            head.push(c);
+        }
+
+        match key {
+            Some(key) => {
+                if !is_valid_key(&key) {
+                    return Err(format!("검색 키가 올바르지 않습니다: `{}`", key));
+                }
+                let op = parse_op(&mut chars);
+                let value = read_value(&mut chars)?;
+                if value.is_empty() {
+                    return Err(format!("`{}:` 뒤에 값이 없습니다.", key));
+                }
+                terms.push(SearchTerm::Filter(SearchFilter { negated, key, op, value }));
+            }
+            None => {
+                let value = if head.is_empty() { read_value(&mut chars)? } else { head };
+                if value.is_empty() {
+                    return Err("`!` 뒤에 검색어가 없습니다.".to_string());
+                }
+                terms.push(SearchTerm::Text { negated, value });
+            }
+        }
+    }
+
+    Ok(terms)
+}
+
+/// `age:-24h`의 기준 시각(`now`에서 기간을 뺀 시각). `-`는 그 기간 안(최근), `+`는 그보다 오래된 것을 뜻한다.
+/// 돌려주는 `bool`은 최근 쪽(`-`)인지 여부다. 표현할 수 없을 만큼 긴 기간도 잘못된 기간으로 본다.
+pub fn parse_relative_age(value: &str, now: DateTime<Utc>) -> Result<(bool, DateTime<Utc>), String> {
+    let invalid = || format!("기간은 `-24h`, `+7d`처럼 써야 합니다: `{}`", value);
+
+    let (newer, rest) = match value.split_at_checked(1) {
+        Some(("-", rest)) => (true, rest),
+        Some(("+", rest)) => (false, rest),
+        _ => return Err(invalid()),
+    };
+    let unit_at = rest.find(|c: char| !c.is_ascii_digit()).ok_or_else(invalid)?;
+    let (amount, unit) = rest.split_at(unit_at);
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+
+    let duration = match unit {
+        "m" => TimeDelta::try_minutes(amount),
+        "h" => TimeDelta::try_hours(amount),
+        "d" => TimeDelta::try_days(amount),
+        "w" => TimeDelta::try_weeks(amount),
+        _ => return Err(invalid()),
+    };
+    let since = duration.and_then(|duration| now.checked_sub_signed(duration)).ok_or_else(invalid)?;
+
+    Ok((newer, since))
+}
+
+/// `tags[browser.name]` 형태의 키에서 태그 이름을 꺼낸다.
+pub fn tag_key(key: &str) -> Option<&str> {
+    key.strip_prefix("tags[")?.strip_suffix(']').filter(|tag| !tag.is_empty())
+}
+
+fn is_valid_key(key: &str) -> bool {
+    tag_key(key).is_some()
+        || (!key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+}
+
+fn parse_op(chars: &mut std::iter::Peekable<std::str::Chars>) -> SearchOp {
+    match chars.next_if(|c| *c == '>' || *c == '<') {
+        Some('>') if chars.next_if_eq(&'=').is_some() => SearchOp::Gte,
+        Some('>') => SearchOp::Gt,
+        Some('<') if chars.next_if_eq(&'=').is_some() => SearchOp::Lte,
+        Some('<') => SearchOp::Lt,
+        _ => SearchOp::Eq,
+    }
+}
+
+// 따옴표로 묶은 값은 공백을 포함할 수 있고 `\"`로 따옴표를 넣는다.
+fn read_value(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<String, String> {
+    let mut value = String::new();
+
+    if chars.next_if_eq(&'"').is_some() {
+        loop {
+            match chars.next() {
+                Some('"') => return Ok(value),
+                Some('\\') => match chars.next() {
+                    Some(c) => value.push(c),
+                    None => break,
+                },
+                Some(c) => value.push(c),
+                None => break,
+            }
+        }
+        return Err(format!("따옴표가 닫히지 않았습니다: \"{}", value));
+    }
+
+    while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+        value.push(c);
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(negated: bool, key: &str, op: SearchOp, value: &str) -> SearchTerm {
+        SearchTerm::Filter(SearchFilter { negated, key: key.to_string(), op, value: value.to_string() })
+    }
+
+    #[test]
+    fn parses_filters_operators_and_text() {
+        let terms = parse_search(
+            r#"is:unresolved environment:production  release:>=1.4 message:"TypeError: x is \"undefined\"" !assigned:me age:-24h checkout "payment failed""#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            terms,
+            vec![
+                filter(false, "is", SearchOp::Eq, "unresolved"),
+                filter(false, "environment", SearchOp::Eq, "production"),
+                filter(false, "release", SearchOp::Gte, "1.4"),
+                filter(false, "message", SearchOp::Eq, r#"TypeError: x is "undefined""#),
+                filter(true, "assigned", SearchOp::Eq, "me"),
+                filter(false, "age", SearchOp::Eq, "-24h"),
+                SearchTerm::Text { negated: false, value: "checkout".to_string() },
+                SearchTerm::Text { negated: false, value: "payment failed".to_string() },
+            ]
+        );
+
+        assert_eq!(parse_search("tags[user.plan]:pro").unwrap(), vec![filter(false, "tags[user.plan]", SearchOp::Eq, "pro")]);
+        assert_eq!(parse_search("duration:<500").unwrap(), vec![filter(false, "duration", SearchOp::Lt, "500")]);
+        assert!(parse_search("   ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_queries() {
+        assert!(parse_search("message:\"unterminated").is_err());
+        assert!(parse_search("browser:").is_err());
+        assert!(parse_search("bad-key:1").is_err());
+        assert!(parse_search("!").is_err());
+    }
+
+    #[test]
+    fn parses_relative_age() {
+        let now = DateTime::parse_from_rfc3339("2025-04-20T00:00:00Z").unwrap().with_timezone(&Utc);
+        let age = |value| parse_relative_age(value, now);
+
+        assert_eq!(age("-24h"), Ok((true, now - TimeDelta::hours(24))));
+        assert_eq!(age("+7d"), Ok((false, now - TimeDelta::days(7))));
+        assert_eq!(age("-2w"), Ok((true, now - TimeDelta::weeks(2))));
+        assert!(age("24h").is_err());
+        assert!(age("-h").is_err());
+        assert!(age("-3y").is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_ages() {
+        let now = Utc::now();
+        assert!(parse_relative_age("-999999999999999w", now).is_err());
+        assert!(parse_relative_age("-99999999999d", now).is_err());
+        assert!(parse_relative_age("+9999999999999999m", now).is_err());
+        assert!(parse_relative_age("-99999999999999999999h", now).is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use sourcemap::SourceMap;
+use crate::model::event::{StackFrame, SymbolicatedFrame};
+
+// 원본 소스에서 프레임 위아래로 보여줄 줄 수
+const CONTEXT_LINES: usize = 5;
+
+// 캐시에 둘 소스맵 원문 크기의 합. 넘으면 가장 오래 쓰지 않은 것부터 지운다.
+const MAX_CACHED_BYTES: usize = 256 * 1024 * 1024;
+// 다른 인스턴스에서 올리거나 지운 소스맵은 이 시간 안에 반영된다.
+const CACHE_TTL: Duration = Duration::from_secs(300);
+
+type SourceMapKey = (i32, String, String);
+
+struct CachedSourceMap {
+    source_map: Option<Arc<SourceMap>>, // 올라온 소스맵이 없거나 읽을 수 없으면 `None`
+    size: usize,
+    loaded_at: Instant,
+    used_at: Instant,
+}
+
+/// (프로젝트, 앱 버전, 파일 이름)별로 파싱한 소스맵. 이벤트마다 소스맵을 다시 읽고 파싱하지 않도록 한다.
+/// 소스맵이 없는 것도 기억하며, 이 인스턴스에서 올리거나 지우면 바로 비운다.
+pub struct SourceMapCache {
+    entries: Mutex<HashMap<SourceMapKey, CachedSourceMap>>,
+    max_bytes: usize,
+}
+
+impl Default for SourceMapCache {
+    fn default() -> Self {
+        Self::with_capacity(MAX_CACHED_BYTES)
+    }
+}
+
+impl SourceMapCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_capacity(max_bytes: usize) -> Self {
+        Self { entries: Mutex::new(HashMap::new()), max_bytes }
+    }
+
+    /// 캐시에 있으면 `Some`. 안의 `None`은 소스맵이 없다는 것을 기억해 둔 것이다.
+    pub fn get(&self, project_id: i32, app_version: &str, name: &str) -> Option<Option<Arc<SourceMap>>> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let key = (project_id, app_version.to_string(), name.to_string());
+        let cached = entries.get_mut(&key)?;
+        if cached.loaded_at.elapsed() >= CACHE_TTL {
+            entries.remove(&key);
+            return None;
+        }
+        cached.used_at = Instant::now();
+        Some(cached.source_map.clone())
+    }
+
+    /// `size`는 소스맵 원문 크기. 혼자서 캐시 크기를 넘는 소스맵은 기억하지 않는다.
+    pub fn insert(
+        &self,
+        project_id: i32,
+        app_version: &str,
+        name: &str,
+        source_map: Option<SourceMap>,
+        size: usize,
+    ) -> Option<Arc<SourceMap>> {
+        let source_map = source_map.map(Arc::new);
+        if size > self.max_bytes {
+            return source_map;
+        }
+
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let mut total = entries.values().map(|cached| cached.size).sum::<usize>();
+        while total + size > self.max_bytes {
+            let Some(oldest) = entries.iter().min_by_key(|(_, cached)| cached.used_at).map(|(key, _)| key.clone()) else {
+                break;
+            };
+            total -= entries.remove(&oldest).map_or(0, |cached| cached.size);
+        }
+
+        let now = Instant::now();
+        entries.insert(
+            (project_id, app_version.to_string(), name.to_string()),
+            CachedSourceMap { source_map: source_map.clone(), size, loaded_at: now, used_at: now },
+        );
+        source_map
+    }
+
+    pub fn invalidate(&self, project_id: i32, app_version: &str, name: &str) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&(project_id, app_version.to_string(), name.to_string()));
+    }
+}
+
+/// 번들 URL/경로에서 소스맵을 찾을 때 쓰는 파일 이름 (쿼리스트링, 경로, `.map` 제거)
+pub fn artifact_name(file: &str) -> String {
+    let file = file.split(['?', '#']).next().unwrap_or(file);
+    let name = file.rsplit('/').next().unwrap_or(file);
+    name.strip_suffix(".map").unwrap_or(name).to_string()
+}
+
+/// 소스맵으로 minified 프레임을 원본 위치로 되돌린다.
+pub fn symbolicate_frame(source_map: &SourceMap, frame: &StackFrame) -> Option<SymbolicatedFrame> {
+    let token = source_map.lookup_token(frame.line?.checked_sub(1)?, frame.column?.saturating_sub(1))?;
+    let file = token.get_source()?.to_string();
+    let src_line = token.get_src_line() as usize;
+
+    let (pre_context, context_line, post_context) = source_map
+        .get_source_contents(token.get_src_id())
+        .map(|contents| source_context(contents, src_line))
+        .unwrap_or_default();
+
+    Some(SymbolicatedFrame {
+        function: token
+            .get_name()
+            .map(str::to_string)
+            .or_else(|| frame.function.clone()),
+        file,
+        line: token.get_src_line() + 1,
+        column: token.get_src_col() + 1,
+        pre_context,
+        context_line,
+        post_context,
+    })
+}
+
+fn source_context(contents: &str, line: usize) -> (Vec<String>, Option<String>, Vec<String>) {
+    let lines: Vec<&str> = contents.lines().collect();
+    let Some(context_line) = lines.get(line) else {
+        return (Vec::new(), None, Vec::new());
+    };
+
+    let pre_context = lines[line.saturating_sub(CONTEXT_LINES)..line]
+        .iter()
+        .map(|l| l.to_string())
+        .collect();
+    let post_context = lines[line + 1..(line + 1 + CONTEXT_LINES).min(lines.len())]
+        .iter()
+        .map(|l| l.to_string())
+        .collect();
+
+    (pre_context, Some(context_line.to_string()), post_context)
+}
+
+/// 심볼리케이션된 프레임을 V8 형식의 스택트레이스 줄로 만든다.
+pub fn format_frame(frame: &SymbolicatedFrame) -> String {
+    match &frame.function {
+        Some(function) => format!("    at {} ({}:{}:{})", function, frame.file, frame.line, frame.column),
+        None => format!("    at {}:{}:{}", frame.file, frame.line, frame.column),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sourcemap::SourceMapBuilder;
+    use crate::util::stacktrace::parse_js_frame;
+
+    // src/main.js 를 "function add(n,r){return n+r}" 한 줄로 minify 한 소스맵
+    fn source_map() -> SourceMap {
+        let mut builder = SourceMapBuilder::new(Some("main.min.js"));
+        let src_id = builder.add_source("src/main.js");
+        builder.set_source_contents(src_id, Some("function add(a, b) {\n  return a + b;\n}\n"));
+        builder.add(0, 0, 0, 0, Some("src/main.js"), None, false);
+        builder.add(0, 9, 0, 9, Some("src/main.js"), Some("add"), false);
+        builder.add(0, 18, 1, 2, Some("src/main.js"), None, false);
+        builder.into_sourcemap()
+    }
+
+    #[test]
+    fn artifact_name_strips_path_query_and_map_suffix() {
+        assert_eq!(artifact_name("https://cdn.example.com/js/main.min.js?v=3"), "main.min.js");
+        assert_eq!(artifact_name("main.min.js.map"), "main.min.js");
+    }
+
+    #[test]
+    fn symbolicates_frame_with_source_context() {
+        let source_map = source_map();
+        let frame = parse_js_frame("at n (https://cdn.example.com/main.min.js:1:19)").unwrap();
+
+        let symbolicated = symbolicate_frame(&source_map, &frame).unwrap();
+        assert_eq!(symbolicated.file, "src/main.js");
+        assert_eq!(symbolicated.line, 2);
+        assert_eq!(symbolicated.context_line.as_deref(), Some("  return a + b;"));
+        assert_eq!(symbolicated.pre_context, vec!["function add(a, b) {".to_string()]);
+        assert_eq!(symbolicated.post_context, vec!["}".to_string()]);
+    }
+
+    #[test]
+    fn caches_parsed_maps_and_misses() {
+        let cache = SourceMapCache::new();
+        assert!(cache.get(1, "1.0.0", "main.min.js").is_none());
+
+        cache.insert(1, "1.0.0", "main.min.js", Some(source_map()), 100);
+        cache.insert(1, "1.0.0", "vendor.min.js", None, 10);
+
+        assert!(cache.get(1, "1.0.0", "main.min.js").unwrap().is_some());
+        assert!(cache.get(1, "1.0.0", "vendor.min.js").unwrap().is_none());
+        assert!(cache.get(2, "1.0.0", "main.min.js").is_none());
+
+        cache.invalidate(1, "1.0.0", "main.min.js");
+        assert!(cache.get(1, "1.0.0", "main.min.js").is_none());
+    }
+
+    #[test]
+    fn evicts_least_recently_used_maps_over_capacity() {
+        let cache = SourceMapCache::with_capacity(250);
+        cache.insert(1, "1.0.0", "a.js", Some(source_map()), 100);
+        cache.insert(1, "1.0.0", "b.js", Some(source_map()), 100);
+        assert!(cache.get(1, "1.0.0", "a.js").is_some());
+
+        // `b.js`가 가장 오래 쓰이지 않았으므로 지운다.
+        cache.insert(1, "1.0.0", "c.js", Some(source_map()), 100);
+        assert!(cache.get(1, "1.0.0", "a.js").is_some());
+        assert!(cache.get(1, "1.0.0", "b.js").is_none());
+        assert!(cache.get(1, "1.0.0", "c.js").is_some());
+
+        // 캐시보다 큰 소스맵은 기억하지 않는다.
+        assert!(cache.insert(1, "1.0.0", "huge.js", Some(source_map()), 1000).is_some());
+        assert!(cache.get(1, "1.0.0", "huge.js").is_none());
+    }
+}
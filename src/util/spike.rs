@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use std::env;
+use std::sync::Mutex;
+use chrono::{DateTime, Utc};
+
+// 분당 이벤트 수 기준선(EWMA)에 새 분을 반영하는 비율
+const BASELINE_ALPHA: f64 = 0.1;
+// 이만큼 조용했던 프로젝트는 기준선이 사실상 0이므로 더 계산하지 않는다.
+const MAX_IDLE_MINUTES: i64 = 60;
+
+/// 스파이크 보호 설정
+#[derive(Debug, Clone, Copy)]
+pub struct SpikeConfig {
+    /// 기준선의 몇 배를 넘으면 스파이크로 보는지
+    pub multiplier: f64,
+    /// 기준선과 상관없이 분당 이 수까지는 스파이크로 보지 않는다.
+    pub min_events_per_minute: u64,
+    /// 스파이크 중에도 이슈마다 분당 이 수까지는 그대로 받는다.
+    pub issue_allowance: u64,
+    /// 허용량을 넘긴 이벤트는 `sample_every`개 중 하나만 받는다.
+    pub sample_every: u64,
+}
+
+impl Default for SpikeConfig {
+    fn default() -> Self {
+        Self {
+            multiplier: 5.0,
+            min_events_per_minute: 1000,
+            issue_allowance: 100,
+            sample_every: 100,
+        }
+    }
+}
+
+impl SpikeConfig {
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            multiplier: env_or("SPIKE_PROTECTION_MULTIPLIER", default.multiplier),
+            min_events_per_minute: env_or("SPIKE_PROTECTION_MIN_EVENTS", default.min_events_per_minute),
+            issue_allowance: env_or("SPIKE_PROTECTION_ISSUE_ALLOWANCE", default.issue_allowance),
+            sample_every: env_or("SPIKE_PROTECTION_SAMPLE_EVERY", default.sample_every).max(1),
+        }
+    }
+}
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpikeDecision {
+    pub keep: bool,
+    /// 이번 이벤트로 스파이크가 시작됐는지
+    pub spike_started: bool,
+}
+
+#[derive(Debug)]
+struct ProjectWindow {
+    minute: i64,
+    count: u64,
+    baseline: f64,
+    spiking: bool,
+    issues: HashMap<String, u64>,
+}
+
+impl ProjectWindow {
+    fn new(minute: i64) -> Self {
+        Self {
+            minute,
+            count: 0,
+            baseline: 0.0,
+            spiking: false,
+            issues: HashMap::new(),
+        }
+    }
+
+    fn advance(&mut self, minute: i64, config: &SpikeConfig) {
+        if minute <= self.minute {
+            return;
+        }
+
+        // 스파이크가 기준선을 끌어올리지 않도록 지난 분의 수는 임계값까지만 반영한다.
+        let threshold = self.threshold(config);
+        self.baseline += BASELINE_ALPHA * ((self.count as f64).min(threshold) - self.baseline);
+        let idle = (minute - self.minute - 1).min(MAX_IDLE_MINUTES);
+        self.baseline *= (1.0 - BASELINE_ALPHA).powi(idle as i32);
+
+        self.minute = minute;
+        self.count = 0;
+        self.spiking = false;
+        self.issues.clear();
+    }
+
+    fn threshold(&self, config: &SpikeConfig) -> f64 {
+        (self.baseline * config.multiplier).max(config.min_events_per_minute as f64)
+    }
+}
+
+/// 프로젝트별 분당 이벤트 수를 기준선과 비교해 스파이크 중이면 이슈별로 샘플링한다.
+/// 상태는 컨슈머 인스턴스 메모리에만 있다.
+#[derive(Debug)]
+pub struct SpikeDetector {
+    config: SpikeConfig,
+    projects: Mutex<HashMap<String, ProjectWindow>>,
+}
+
+impl SpikeDetector {
+    pub fn new(config: SpikeConfig) -> Self {
+        Self {
+            config,
+            projects: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn observe(&self, project_key: &str, issue_key: &str, now: DateTime<Utc>) -> SpikeDecision {
+        let minute = now.timestamp().div_euclid(60);
+        let mut projects = self.projects.lock().unwrap_or_else(|e| e.into_inner());
+        let window = projects
+            .entry(project_key.to_string())
+            .or_insert_with(|| ProjectWindow::new(minute));
+        window.advance(minute, &self.config);
+
+        window.count += 1;
+        let was_spiking = window.spiking;
+        if window.count as f64 > window.threshold(&self.config) {
+            window.spiking = true;
+        }
+
+        let issue_count = window.issues.entry(issue_key.to_string()).or_insert(0);
+        *issue_count += 1;
+
+        let keep = !window.spiking
+            || *issue_count <= self.config.issue_allowance
+            || (*issue_count - self.config.issue_allowance).is_multiple_of(self.config.sample_every);
+
+        SpikeDecision {
+            keep,
+            spike_started: window.spiking && !was_spiking,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn config() -> SpikeConfig {
+        SpikeConfig {
+            multiplier: 2.0,
+            min_events_per_minute: 10,
+            issue_allowance: 3,
+            sample_every: 5,
+        }
+    }
+
+    #[test]
+    fn samples_issue_events_during_spike() {
+        let detector = SpikeDetector::new(config());
+        let now = Utc.with_ymd_and_hms(2025, 4, 20, 12, 0, 0).unwrap();
+
+        let decisions: Vec<_> = (0..30).map(|_| detector.observe("key", "issue", now)).collect();
+
+        // 임계값(10)까지는 모두 받는다.
+        assert!(decisions[..10].iter().all(|d| d.keep));
+        assert!(decisions[10].spike_started);
+        assert_eq!(decisions.iter().filter(|d| d.spike_started).count(), 1);
+        // 이후에는 5개 중 하나만 받는다.
+        let kept: Vec<_> = (10..30).filter(|&i| decisions[i].keep).collect();
+        assert_eq!(kept, vec![12, 17, 22, 27]);
+
+        // 같은 분이라도 처음 보는 이슈는 허용량만큼 받는다.
+        assert!(detector.observe("key", "other", now).keep);
+        // 다른 프로젝트는 영향을 받지 않는다.
+        assert!(detector.observe("other", "issue", now).keep);
+    }
+
+    #[test]
+    fn baseline_follows_steady_traffic() {
+        let detector = SpikeDetector::new(config());
+        let start = Utc.with_ymd_and_hms(2025, 4, 20, 12, 0, 0).unwrap();
+
+        // 분당 8개가 꾸준히 들어오면 기준선은 8에 가까워진다.
+        for minute in 0..60 {
+            let now = start + chrono::Duration::minutes(minute);
+            for _ in 0..8 {
+                assert!(detector.observe("key", "issue", now).keep);
+            }
+        }
+
+        let now = start + chrono::Duration::minutes(60);
+        let decisions: Vec<_> = (0..20).map(|i| detector.observe("key", &i.to_string(), now)).collect();
+        // 임계값은 기준선(약 8)의 두 배인 약 16이다.
+        let started = decisions.iter().position(|d| d.spike_started).unwrap();
+        assert!((14..=16).contains(&started), "spike started at {}", started);
+    }
+}
@@ -0,0 +1,273 @@
+use std::sync::LazyLock;
+use regex::Regex;
+use crate::model::event::StackFrame;
+
+static PYTHON_FRAME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^\s*File "(?P<file>[^"]+)", line (?P<line>\d+)(?:, in (?P<function>.+))?$"#).unwrap()
+});
+static JAVA_FRAME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*at (?:[\w.$-]+/)?(?P<symbol>[^\s(/]+)\((?P<location>[^)]*)\)$").unwrap()
+});
+static RUST_SYMBOL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*\d+:\s+(?:0x[0-9a-f]+ - )?(?P<symbol>\S.*?)(?:::h[0-9a-f]{16})?$").unwrap()
+});
+static RUST_LOCATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*at (?P<file>.+?):(?P<line>\d+)(?::(?P<column>\d+))?$").unwrap()
+});
+
+// in_app 판정에 쓰는 라이브러리/런타임 프레임 접두사
+const JAVA_SYSTEM_PACKAGES: [&str; 10] = [
+    "java.", "javax.", "jdk.", "sun.", "com.sun.", "kotlin.", "kotlinx.", "scala.", "org.springframework.", "org.apache.",
+];
+const RUST_SYSTEM_CRATES: [&str; 9] = [
+    "std::", "core::", "alloc::", "tokio::", "actix_", "futures", "backtrace::", "rust_begin_unwind", "__rust",
+];
+
+/// 원본 스택트레이스를 프레임 목록으로 파싱한다.
+///
+/// V8, Firefox/Safari, Python, Java, Rust 패닉 백트레이스를 지원하며,
+/// 런타임과 관계없이 가장 안쪽(에러가 발생한) 호출이 먼저 오도록 정렬한다.
+pub fn parse_stacktrace(stacktrace: &str) -> Vec<StackFrame> {
+    let mut frames = Vec::new();
+    let mut python_frames = Vec::new();
+    let mut lines = stacktrace.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        if let Some(frame) = parse_python_frame(line) {
+            python_frames.push(frame);
+        } else if let Some(mut frame) = parse_rust_symbol(line) {
+            // Rust 백트레이스는 다음 줄에 `at file:line:col` 위치가 온다.
+            if let Some(location) = lines.peek().and_then(|next| RUST_LOCATION.captures(next)) {
+                frame.file = Some(location["file"].to_string());
+                frame.line = location["line"].parse().ok();
+                frame.column = location.name("column").and_then(|c| c.as_str().parse().ok());
+                frame.in_app = frame.in_app && is_rust_in_app_file(&location["file"]);
+                lines.next();
+            }
+            frames.push(frame);
+        } else if let Some(frame) = parse_java_frame(line).or_else(|| parse_js_frame(line)) {
+            frames.push(frame);
+        }
+    }
+
+    // Python 트레이스백은 가장 바깥 호출부터 나열된다.
+    python_frames.reverse();
+    frames.extend(python_frames);
+    frames
+}
+
+/// V8(`at fn (file:line:col)`), Firefox/Safari(`fn@file:line:col`) 형식의 프레임을 파싱한다.
+pub fn parse_js_frame(line: &str) -> Option<StackFrame> {
+    let line = line.trim();
+
+    let (function, location) = if let Some(rest) = line.strip_prefix("at ") {
+        match (rest.rfind(" ("), rest.ends_with(')')) {
+            (Some(pos), true) => (Some(&rest[..pos]), &rest[pos + 2..rest.len() - 1]),
+            _ => (None, rest),
+        }
+    } else if let Some(pos) = line.find('@') {
+        (Some(&line[..pos]), &line[pos + 1..])
+    } else {
+        return None;
+    };
+
+    let mut parts = location.rsplitn(3, ':');
+    let column = parts.next()?.parse().ok()?;
+    let line_no = parts.next()?.parse().ok()?;
+    let file = parts.next()?.to_string();
+
+    Some(StackFrame {
+        function: function
+            .map(|f| f.trim_start_matches("async ").to_string())
+            .filter(|f| !f.is_empty()),
+        module: None,
+        in_app: is_js_in_app(&file),
+        file: Some(file),
+        line: Some(line_no),
+        column: Some(column),
+    })
+}
+
+fn parse_python_frame(line: &str) -> Option<StackFrame> {
+    let captures = PYTHON_FRAME.captures(line)?;
+    let file = captures["file"].to_string();
+
+    Some(StackFrame {
+        function: captures.name("function").map(|f| f.as_str().to_string()),
+        module: None,
+        in_app: !["site-packages", "dist-packages", "/lib/python"]
+            .iter()
+            .any(|lib| file.contains(lib)),
+        file: Some(file),
+        line: captures["line"].parse().ok(),
+        column: None,
+    })
+}
+
+fn parse_java_frame(line: &str) -> Option<StackFrame> {
+    let captures = JAVA_FRAME.captures(line)?;
+    let (module, function) = captures["symbol"].rsplit_once('.')?;
+
+    // `Foo.java:42`, `Native Method`, `Unknown Source`
+    let (file, line_no) = match captures["location"].rsplit_once(':') {
+        Some((file, line_no)) => (Some(file.to_string()), line_no.parse().ok()),
+        None => (None, None),
+    };
+
+    Some(StackFrame {
+        function: Some(function.to_string()),
+        in_app: !JAVA_SYSTEM_PACKAGES.iter().any(|package| module.starts_with(package)),
+        module: Some(module.to_string()),
+        file,
+        line: line_no,
+        column: None,
+    })
+}
+
+fn parse_rust_symbol(line: &str) -> Option<StackFrame> {
+    let captures = RUST_SYMBOL.captures(line)?;
+    let symbol = &captures["symbol"];
+    let (module, function) = match symbol.rsplit_once("::") {
+        Some((module, function)) => (Some(module.to_string()), function.to_string()),
+        None => (None, symbol.to_string()),
+    };
+
+    Some(StackFrame {
+        function: Some(function),
+        in_app: !RUST_SYSTEM_CRATES.iter().any(|krate| symbol.starts_with(krate)),
+        module,
+        file: None,
+        line: None,
+        column: None,
+    })
+}
+
+fn is_js_in_app(file: &str) -> bool {
+    !(file.contains("node_modules")
+        || file.starts_with("node:")
+        || file.starts_with("internal/")
+        || file == "<anonymous>"
+        || file == "native")
+}
+
+fn is_rust_in_app_file(file: &str) -> bool {
+    !(file.starts_with("/rustc/") || file.contains("/.cargo/registry/"))
+}
+
+/// 그룹핑에 쓰는 프레임 키. 배포마다 바뀌는 줄/컬럼 번호와 경로는 제외한다.
+pub fn grouping_key(frame: &StackFrame) -> String {
+    let file = frame
+        .file
+        .as_deref()
+        .map(|file| file.split(['?', '#']).next().unwrap_or(file))
+        .map(|file| file.rsplit(['/', '\\']).next().unwrap_or(file))
+        .unwrap_or_default();
+
+    format!(
+        "{}|{}|{}",
+        frame.module.as_deref().unwrap_or_default(),
+        frame.function.as_deref().unwrap_or_default(),
+        file,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (function, file, line, in_app)
+    type FrameSummary<'a> = (Option<&'a str>, Option<&'a str>, Option<u32>, bool);
+
+    fn summary(frames: &[StackFrame]) -> Vec<FrameSummary<'_>> {
+        frames
+            .iter()
+            .map(|f| (f.function.as_deref(), f.file.as_deref(), f.line, f.in_app))
+            .collect()
+    }
+
+    #[test]
+    fn parses_v8_stacktrace() {
+        let stack = "TypeError: Cannot read properties of undefined (reading 'id')\n    \
+            at UserCard (https://app.example.com/static/js/main.js:12:34)\n    \
+            at async loadUser (https://app.example.com/static/js/main.js:40:3)\n    \
+            at https://app.example.com/node_modules/react-dom/index.js:100:7";
+
+        let frames = parse_stacktrace(stack);
+        assert_eq!(summary(&frames), vec![
+            (Some("UserCard"), Some("https://app.example.com/static/js/main.js"), Some(12), true),
+            (Some("loadUser"), Some("https://app.example.com/static/js/main.js"), Some(40), true),
+            (None, Some("https://app.example.com/node_modules/react-dom/index.js"), Some(100), false),
+        ]);
+        assert_eq!(frames[0].column, Some(34));
+    }
+
+    #[test]
+    fn parses_firefox_and_safari_stacktrace() {
+        let stack = "render@https://app.example.com/main.js:10:5\n@https://app.example.com/main.js:2:1";
+
+        let frames = parse_stacktrace(stack);
+        assert_eq!(summary(&frames), vec![
+            (Some("render"), Some("https://app.example.com/main.js"), Some(10), true),
+            (None, Some("https://app.example.com/main.js"), Some(2), true),
+        ]);
+    }
+
+    #[test]
+    fn parses_python_traceback_innermost_first() {
+        let stack = "Traceback (most recent call last):\n  \
+            File \"/app/server.py\", line 10, in handle\n    \
+            return service.run()\n  \
+            File \"/usr/lib/python3.12/site-packages/lib/core.py\", line 5, in run\n    \
+            raise ValueError()\n\
+            ValueError";
+
+        let frames = parse_stacktrace(stack);
+        assert_eq!(summary(&frames), vec![
+            (Some("run"), Some("/usr/lib/python3.12/site-packages/lib/core.py"), Some(5), false),
+            (Some("handle"), Some("/app/server.py"), Some(10), true),
+        ]);
+    }
+
+    #[test]
+    fn parses_java_stacktrace() {
+        let stack = "java.lang.IllegalStateException: boom\n\
+            \tat com.example.order.OrderService.place(OrderService.java:42)\n\
+            \tat java.base/java.lang.Thread.run(Thread.java:834)\n\
+            \tat sun.reflect.NativeMethodAccessorImpl.invoke0(Native Method)";
+
+        let frames = parse_stacktrace(stack);
+        assert_eq!(summary(&frames), vec![
+            (Some("place"), Some("OrderService.java"), Some(42), true),
+            (Some("run"), Some("Thread.java"), Some(834), false),
+            (Some("invoke0"), None, None, false),
+        ]);
+        assert_eq!(frames[0].module.as_deref(), Some("com.example.order.OrderService"));
+    }
+
+    #[test]
+    fn parses_rust_panic_backtrace() {
+        let stack = "thread 'main' panicked at src/handler.rs:10:9:\nboom\nstack backtrace:\n   \
+            0: rust_begin_unwind\n             \
+            at /rustc/abc/library/std/src/panicking.rs:652:5\n   \
+            1: my_app::handler::run::h0123456789abcdef\n             \
+            at ./src/handler.rs:10:9\n   \
+            2: core::ops::function::FnOnce::call_once";
+
+        let frames = parse_stacktrace(stack);
+        assert_eq!(summary(&frames), vec![
+            (Some("rust_begin_unwind"), Some("/rustc/abc/library/std/src/panicking.rs"), Some(652), false),
+            (Some("run"), Some("./src/handler.rs"), Some(10), true),
+            (Some("call_once"), None, None, false),
+        ]);
+        assert_eq!(frames[1].module.as_deref(), Some("my_app::handler"));
+        assert_eq!(frames[1].column, Some(9));
+    }
+
+    #[test]
+    fn grouping_key_ignores_line_numbers_and_paths() {
+        let a = parse_js_frame("at render (https://a.example.com/js/main.js?v=1:10:5)").unwrap();
+        let b = parse_js_frame("at render (https://b.example.com/static/main.js:99:1)").unwrap();
+
+        assert_eq!(grouping_key(&a), grouping_key(&b));
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 시계열 집계 단위
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum StatsInterval {
+    Minute,
+    #[default]
+    Hour,
+    Day,
+}
+
+impl StatsInterval {
+    pub fn seconds(&self) -> i64 {
+        match self {
+            StatsInterval::Minute => 60,
+            StatsInterval::Hour => 3_600,
+            StatsInterval::Day => 86_400,
+        }
+    }
+
+    /// 이 시각이 속한 구간의 번호 (UTC 기준, 유닉스 시각 / 구간 길이)
+    pub fn bucket_of(&self, time: DateTime<Utc>) -> i64 {
+        time.timestamp().div_euclid(self.seconds())
+    }
+
+    pub fn bucket_start(&self, bucket: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(bucket * self.seconds(), 0).unwrap_or_default()
+    }
+
+    /// `start`부터 `end`까지의 구간 수. 목록을 만들기 전에 크기를 확인할 때 쓴다.
+    pub fn bucket_count(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> i64 {
+        self.bucket_of(end) - self.bucket_of(start) + 1
+    }
+
+    /// `start`와 `end`가 속한 구간까지 모두 포함한 구간 번호 목록
+    pub fn buckets(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<i64> {
+        (self.bucket_of(start)..=self.bucket_of(end)).collect()
+    }
+}
+
+/// 구간별 값을 모든 구간에 대해 채운다. 값이 없는 구간은 0이다.
+pub fn fill_series(buckets: &[i64], counts: &HashMap<i64, i64>) -> Vec<i64> {
+    buckets
+        .iter()
+        .map(|bucket| counts.get(bucket).copied().unwrap_or_default())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn aligns_buckets_to_utc_boundaries() {
+        let interval = StatsInterval::Hour;
+        let buckets = interval.buckets(at("2025-04-20T10:59:59Z"), at("2025-04-20T13:00:00Z"));
+
+        assert_eq!(buckets.len(), 4);
+        assert_eq!(interval.bucket_count(at("2025-04-20T10:59:59Z"), at("2025-04-20T13:00:00Z")), 4);
+        assert_eq!(interval.bucket_start(buckets[0]), at("2025-04-20T10:00:00Z"));
+        assert_eq!(interval.bucket_start(buckets[3]), at("2025-04-20T13:00:00Z"));
+        assert_eq!(
+            StatsInterval::Day.bucket_start(StatsInterval::Day.bucket_of(at("2025-04-20T23:30:00Z"))),
+            at("2025-04-20T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn counts_buckets_without_building_them() {
+        let count = StatsInterval::Minute.bucket_count(at("0001-01-01T00:00:00Z"), at("2025-04-20T00:00:00Z"));
+        assert!(count > 1_000_000_000);
+    }
+
+    #[test]
+    fn fills_missing_buckets_with_zero() {
+        let counts = HashMap::from([(11, 3), (13, 1), (99, 7)]);
+        assert_eq!(fill_series(&[10, 11, 12, 13], &counts), vec![0, 3, 0, 1]);
+    }
+}
@@ -0,0 +1,162 @@
+use std::sync::LazyLock;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+// 순서가 중요하다. Edge/Opera/Samsung은 UA에 Chrome과 Safari도 함께 적는다.
+static BROWSERS: LazyLock<Vec<(&'static str, Regex)>> = LazyLock::new(|| {
+    [
+        ("Edge", r"Edg(?:e|A|iOS)?/([\d.]+)"),
+        ("Opera", r"(?:OPR|Opera)/([\d.]+)"),
+        ("Samsung Internet", r"SamsungBrowser/([\d.]+)"),
+        ("Chrome", r"(?:Chrome|CriOS)/([\d.]+)"),
+        ("Firefox", r"(?:Firefox|FxiOS)/([\d.]+)"),
+        ("Safari", r"Version/([\d.]+).*Safari/"),
+        ("Internet Explorer", r"(?:MSIE |Trident/.*rv:)([\d.]+)"),
+    ]
+        .into_iter()
+        .map(|(name, pattern)| (name, Regex::new(pattern).unwrap()))
+        .collect()
+});
+static WINDOWS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"Windows NT ([\d.]+)").unwrap());
+static IOS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?:iPhone|CPU) OS ([\d_]+)").unwrap());
+static MAC_OS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"Mac OS X ([\d_.]+)").unwrap());
+static ANDROID: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"Android ([\d.]+)").unwrap());
+static BOT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)bot|crawler|spider|slurp|headless").unwrap());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceType {
+    Desktop,
+    Mobile,
+    Tablet,
+    Bot,
+}
+
+impl DeviceType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeviceType::Desktop => "desktop",
+            DeviceType::Mobile => "mobile",
+            DeviceType::Tablet => "tablet",
+            DeviceType::Bot => "bot",
+        }
+    }
+}
+
+/// User-Agent에서 뽑은 브라우저/OS/기기 정보
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserAgentInfo {
+    pub browser_name: Option<String>,
+    pub browser_version: Option<String>,
+    pub os_name: Option<String>,
+    pub os_version: Option<String>,
+    pub device_type: DeviceType,
+}
+
+pub fn parse_user_agent(user_agent: &str) -> UserAgentInfo {
+    let (browser_name, browser_version) = BROWSERS
+        .iter()
+        .find_map(|(name, regex)| regex.captures(user_agent).map(|caps| (name.to_string(), caps[1].to_string())))
+        .map_or((None, None), |(name, version)| (Some(name), Some(version)));
+
+    let (os_name, os_version) = parse_os(user_agent);
+
+    UserAgentInfo {
+        browser_name,
+        browser_version,
+        os_name,
+        os_version,
+        device_type: device_type(user_agent),
+    }
+}
+
+fn parse_os(user_agent: &str) -> (Option<String>, Option<String>) {
+    let found = |name: &str, version: Option<String>| (Some(name.to_string()), version);
+
+    if let Some(caps) = WINDOWS.captures(user_agent) {
+        let version = match &caps[1] {
+            "10.0" => "10",
+            "6.3" => "8.1",
+            "6.2" => "8",
+            "6.1" => "7",
+            other => other,
+        };
+        return found("Windows", Some(version.to_string()));
+    }
+    // iPadOS 13+ 는 데스크톱 Safari처럼 Macintosh로 보내므로 구분할 수 없다.
+    if user_agent.contains("iPhone") || user_agent.contains("iPad") || user_agent.contains("iPod") {
+        let version = IOS.captures(user_agent).map(|caps| caps[1].replace('_', "."));
+        return found("iOS", version);
+    }
+    if let Some(caps) = ANDROID.captures(user_agent) {
+        return found("Android", Some(caps[1].to_string()));
+    }
+    if let Some(caps) = MAC_OS.captures(user_agent) {
+        return found("macOS", Some(caps[1].replace('_', ".")));
+    }
+    if user_agent.contains("CrOS") {
+        return found("Chrome OS", None);
+    }
+    if user_agent.contains("Linux") {
+        return found("Linux", None);
+    }
+    (None, None)
+}
+
+fn device_type(user_agent: &str) -> DeviceType {
+    if BOT.is_match(user_agent) {
+        DeviceType::Bot
+    } else if user_agent.contains("iPad") || user_agent.contains("Tablet")
+        || (user_agent.contains("Android") && !user_agent.contains("Mobile"))
+    {
+        DeviceType::Tablet
+    } else if user_agent.contains("Mobi") || user_agent.contains("iPhone") || user_agent.contains("iPod") {
+        DeviceType::Mobile
+    } else {
+        DeviceType::Desktop
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(user_agent: &str) -> (Option<String>, Option<String>, Option<String>, Option<String>, DeviceType) {
+        let info = parse_user_agent(user_agent);
+        (info.browser_name, info.browser_version, info.os_name, info.os_version, info.device_type)
+    }
+
+    fn some(value: &str) -> Option<String> {
+        Some(value.to_string())
+    }
+
+    #[test]
+    fn parses_desktop_browsers() {
+        assert_eq!(
+            parsed("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0.0.0 Safari/537.36 Edg/124.0.2478.51"),
+            (some("Edge"), some("124.0.2478.51"), some("Windows"), some("10"), DeviceType::Desktop)
+        );
+        assert_eq!(
+            parsed("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.4 Safari/605.1.15"),
+            (some("Safari"), some("17.4"), some("macOS"), some("10.15.7"), DeviceType::Desktop)
+        );
+        assert_eq!(
+            parsed("Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:125.0) Gecko/20100101 Firefox/125.0"),
+            (some("Firefox"), some("125.0"), some("Linux"), None, DeviceType::Desktop)
+        );
+    }
+
+    #[test]
+    fn parses_mobile_devices_and_bots() {
+        assert_eq!(
+            parsed("Mozilla/5.0 (iPhone; CPU iPhone OS 17_4_1 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) CriOS/124.0.6367.88 Mobile/15E148 Safari/604.1"),
+            (some("Chrome"), some("124.0.6367.88"), some("iOS"), some("17.4.1"), DeviceType::Mobile)
+        );
+        assert_eq!(
+            parsed("Mozilla/5.0 (Linux; Android 14; SM-X710) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/24.0 Chrome/117.0.0.0 Safari/537.36"),
+            (some("Samsung Internet"), some("24.0"), some("Android"), some("14"), DeviceType::Tablet)
+        );
+        assert_eq!(parse_user_agent("Googlebot/2.1 (+http://www.google.com/bot.html)").device_type, DeviceType::Bot);
+        assert_eq!(parsed("curl/8.4.0"), (None, None, None, None, DeviceType::Desktop));
+    }
+}
@@ -0,0 +1,79 @@
+use std::cmp::Ordering;
+
+/// 앱 버전 문자열을 비교한다. (`1.10.0` > `1.9.2`, `1.2.0` > `1.2.0-beta.1`, 앞의 `v`는 무시)
+///
+/// 숫자 구간은 숫자로, 나머지는 문자열로 비교하며, semver가 아닌 버전도 최대한 순서를 맞춘다.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let (a_release, a_pre) = split_pre_release(a);
+    let (b_release, b_pre) = split_pre_release(b);
+
+    match compare_segments(a_release, b_release) {
+        Ordering::Equal => match (a_pre, b_pre) {
+            (None, None) => Ordering::Equal,
+            // 프리릴리즈는 정식 릴리즈보다 낮다.
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(a_pre), Some(b_pre)) => compare_segments(a_pre, b_pre),
+        },
+        ordering => ordering,
+    }
+}
+
+fn split_pre_release(version: &str) -> (&str, Option<&str>) {
+    let version = version.trim();
+    let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+    // 빌드 메타데이터(+build)는 순서에 영향을 주지 않는다.
+    let version = version.split('+').next().unwrap_or(version);
+
+    match version.split_once('-') {
+        Some((release, pre)) => (release, Some(pre)),
+        None => (version, None),
+    }
+}
+
+fn compare_segments(a: &str, b: &str) -> Ordering {
+    let mut a_parts = a.split('.');
+    let mut b_parts = b.split('.');
+
+    loop {
+        match (a_parts.next(), b_parts.next()) {
+            (None, None) => return Ordering::Equal,
+            (Some(a), None) => return if is_zero(a) && a_parts.all(is_zero) { Ordering::Equal } else { Ordering::Greater },
+            (None, Some(b)) => return if is_zero(b) && b_parts.all(is_zero) { Ordering::Equal } else { Ordering::Less },
+            (Some(a), Some(b)) => {
+                let ordering = match (a.parse::<u64>(), b.parse::<u64>()) {
+                    (Ok(a), Ok(b)) => a.cmp(&b),
+                    (Ok(_), Err(_)) => Ordering::Less,
+                    (Err(_), Ok(_)) => Ordering::Greater,
+                    (Err(_), Err(_)) => a.cmp(b),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
+
+fn is_zero(part: &str) -> bool {
+    part.parse::<u64>() == Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_numeric_segments_numerically() {
+        assert_eq!(compare_versions("1.10.0", "1.9.2"), Ordering::Greater);
+        assert_eq!(compare_versions("v2.0", "2.0.0"), Ordering::Equal);
+        assert_eq!(compare_versions("1.4", "1.4.1"), Ordering::Less);
+    }
+
+    #[test]
+    fn pre_release_is_lower_than_release() {
+        assert_eq!(compare_versions("1.2.0-beta.1", "1.2.0"), Ordering::Less);
+        assert_eq!(compare_versions("1.2.0-beta.2", "1.2.0-beta.10"), Ordering::Less);
+        assert_eq!(compare_versions("1.2.0+build.5", "1.2.0"), Ordering::Equal);
+    }
+}